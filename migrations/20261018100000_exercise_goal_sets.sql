@@ -0,0 +1,3 @@
+-- Goals are reached by doing goal_sets sets of the target; weighted goals may now also ask for
+-- a number of reps, stored in goal_reps.
+ALTER TABLE exercise ADD COLUMN goal_sets INTEGER CHECK (goal_sets IS NULL OR goal_sets > 0);
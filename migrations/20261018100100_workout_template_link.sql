@@ -0,0 +1,2 @@
+-- Template a workout was started from, kept as history when the template is deleted
+ALTER TABLE workout ADD COLUMN workout_template_id INTEGER REFERENCES workout_template(id) ON DELETE SET NULL;
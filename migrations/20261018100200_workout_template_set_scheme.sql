@@ -0,0 +1,4 @@
+-- Sets planned for each exercise of a template, such as 5x5 or 3xAMRAP. No planned sets means
+-- the goal's sets are used, no target means as many as possible.
+ALTER TABLE workout_template_exercise ADD COLUMN planned_sets INTEGER CHECK (planned_sets IS NULL OR planned_sets > 0);
+ALTER TABLE workout_template_exercise ADD COLUMN planned_target INTEGER CHECK (planned_target IS NULL OR planned_target > 0);
@@ -0,0 +1,13 @@
+-- Exercises of a workout were ordered through set_order (exercise position * 1000 + set number)
+-- and exercises of a template by insertion. Both now store their position, starting at 0, and
+-- set_order only numbers the sets of an exercise.
+ALTER TABLE workout_set ADD COLUMN exercise_position INTEGER NOT NULL DEFAULT 0 CHECK (exercise_position >= 0);
+UPDATE workout_set SET exercise_position = (set_order - 1) / 1000, set_order = (set_order - 1) % 1000 + 1;
+
+ALTER TABLE workout_template_exercise ADD COLUMN position INTEGER NOT NULL DEFAULT 0 CHECK (position >= 0);
+UPDATE workout_template_exercise SET position = (
+  SELECT COUNT(*)
+  FROM workout_template_exercise AS earlier
+  WHERE earlier.workout_template_id = workout_template_exercise.workout_template_id
+    AND earlier.id < workout_template_exercise.id
+);
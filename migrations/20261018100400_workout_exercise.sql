@@ -0,0 +1,52 @@
+-- Each exercise done in a workout gets its own entry, so an exercise may come back later in the
+-- same workout (supersets, finishers). Sets belong to an entry and set_order numbers them within
+-- it, replacing the workout_id, exercise_id and exercise_position columns of workout_set.
+CREATE TABLE workout_exercise (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  workout_id INTEGER NOT NULL REFERENCES workout(id) ON DELETE CASCADE,
+  exercise_id INTEGER NOT NULL REFERENCES exercise(id) ON DELETE CASCADE,
+  position INTEGER NOT NULL CHECK (position >= 0),
+  UNIQUE (workout_id, position)
+);
+
+CREATE INDEX idx_workout_exercise_exercise_id ON workout_exercise(exercise_id);
+
+-- Entries are numbered from 0 in the order of their exercise_position. Sets not written by the
+-- app may give two exercises the same position, the lowest exercise id then comes first.
+CREATE TEMPORARY TABLE legacy_entry AS
+SELECT workout_id, exercise_id, exercise_position,
+       ROW_NUMBER() OVER (PARTITION BY workout_id ORDER BY exercise_position, exercise_id) - 1 AS position
+FROM (SELECT DISTINCT workout_id, exercise_id, exercise_position FROM workout_set);
+
+INSERT INTO workout_exercise (workout_id, exercise_id, position)
+SELECT workout_id, exercise_id, position
+FROM legacy_entry
+ORDER BY workout_id, position;
+
+CREATE TABLE workout_set_by_entry (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  workout_exercise_id INTEGER NOT NULL REFERENCES workout_exercise(id) ON DELETE CASCADE,
+  set_order INTEGER NOT NULL CHECK (set_order > 0),
+  reps INTEGER CHECK (reps IS NULL OR reps > 0),
+  weight REAL CHECK (weight IS NULL OR weight > 0),
+  duration_seconds INTEGER CHECK (duration_seconds IS NULL OR duration_seconds > 0),
+  failure INTEGER NOT NULL DEFAULT 0 CHECK (failure IN (0, 1))
+);
+
+INSERT INTO workout_set_by_entry (id, workout_exercise_id, set_order, reps, weight, duration_seconds, failure)
+SELECT workout_set.id, workout_exercise.id, workout_set.set_order, workout_set.reps, workout_set.weight,
+       workout_set.duration_seconds, workout_set.failure
+FROM workout_set
+JOIN legacy_entry
+  ON legacy_entry.workout_id = workout_set.workout_id
+ AND legacy_entry.exercise_id = workout_set.exercise_id
+ AND legacy_entry.exercise_position = workout_set.exercise_position
+JOIN workout_exercise
+  ON workout_exercise.workout_id = legacy_entry.workout_id
+ AND workout_exercise.position = legacy_entry.position;
+
+DROP TABLE legacy_entry;
+DROP TABLE workout_set;
+ALTER TABLE workout_set_by_entry RENAME TO workout_set;
+
+CREATE INDEX idx_workout_set_workout_exercise_id ON workout_set(workout_exercise_id, set_order);
@@ -0,0 +1,17 @@
+-- Personal records, one row each time a workout beat the previous best of an exercise. They are
+-- derived from the sets, so the rows of an exercise are recomputed whenever one of its workouts
+-- is saved.
+CREATE TABLE personal_record (
+  id INTEGER PRIMARY KEY AUTOINCREMENT,
+  workout_id INTEGER NOT NULL REFERENCES workout(id) ON DELETE CASCADE,
+  exercise_id INTEGER NOT NULL REFERENCES exercise(id) ON DELETE CASCADE,
+  kind TEXT NOT NULL CHECK (kind IN ('heaviest_weight', 'most_reps', 'longest_hold', 'best_one_rep_max', 'session_volume')),
+  -- Rep count of a heaviest_weight record, weight of a most_reps record on a weighted exercise
+  reps INTEGER CHECK (reps IS NULL OR reps > 0),
+  weight REAL CHECK (weight IS NULL OR weight > 0),
+  value REAL NOT NULL,
+  previous REAL NOT NULL
+);
+
+CREATE INDEX idx_personal_record_workout_id ON personal_record(workout_id);
+CREATE INDEX idx_personal_record_exercise_id ON personal_record(exercise_id);
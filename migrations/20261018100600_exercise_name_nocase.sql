@@ -0,0 +1,11 @@
+-- Exercises are looked up by name ignoring case, so names must also be unique ignoring case.
+-- Names already clashing that way get the exercise id appended, the oldest one keeps its name.
+UPDATE exercise SET name = name || ' (' || id || ')'
+WHERE EXISTS (
+  SELECT 1
+  FROM exercise AS earlier
+  WHERE earlier.name = exercise.name COLLATE NOCASE
+    AND earlier.id < exercise.id
+);
+
+CREATE UNIQUE INDEX idx_exercise_name_nocase ON exercise(name COLLATE NOCASE);
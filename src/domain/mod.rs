@@ -1,2 +1,3 @@
+pub mod services;
 pub mod traits;
 pub mod types;
@@ -0,0 +1,215 @@
+use chrono::NaiveDate;
+
+use crate::domain::{
+    services::progression_status::ProgressionStatus,
+    types::{
+        exercise::{Exercise, ExerciseProgression, ExerciseType},
+        workout::{Workout, WorkoutSet},
+    },
+};
+
+/// How close the best performance since a date is to an exercise's goal.
+#[derive(Debug)]
+pub struct ExerciseProgress {
+    pub exercise: Exercise,
+    /// Best weight, reps or hold time, in the unit of the goal
+    pub best: Option<f32>,
+    pub goal: f32,
+}
+
+impl ExerciseProgress {
+    /// Looks at the sets logged from `since` onwards. Weighted sets only count with at least
+    /// the goal's reps, so a heavy single doesn't fill the bar of a five-rep goal.
+    pub fn new(exercise: Exercise, workouts: &[Workout], since: NaiveDate) -> Self {
+        let best = workouts
+            .iter()
+            .filter(|workout| workout.date >= since)
+            .flat_map(|workout| &workout.exercises)
+            .filter(|done| done.exercise.id == exercise.id)
+            .flat_map(|done| &done.sets)
+            .filter_map(|set| performance(&exercise.exercise_type, set))
+            .max_by(f32::total_cmp);
+        let goal = match exercise.exercise_type {
+            ExerciseType::Weighted { goal_weight, .. } => goal_weight,
+            ExerciseType::BodyweightReps { goal_reps, .. } => f32::from(goal_reps),
+            ExerciseType::BodyweightTime {
+                goal_duration_seconds,
+                ..
+            } => f32::from(goal_duration_seconds),
+        };
+        ExerciseProgress {
+            exercise,
+            best,
+            goal,
+        }
+    }
+
+    /// Share of the goal reached, from 0 to 1.
+    pub fn ratio(&self) -> f32 {
+        self.best
+            .map_or(0.0, |best| (best / self.goal).clamp(0.0, 1.0))
+    }
+}
+
+fn performance(exercise_type: &ExerciseType, set: &WorkoutSet) -> Option<f32> {
+    match (exercise_type, set) {
+        (ExerciseType::Weighted { goal_reps, .. }, WorkoutSet::Weighted(set))
+            if set.reps >= *goal_reps =>
+        {
+            Some(set.weight)
+        }
+        (ExerciseType::BodyweightReps { .. }, WorkoutSet::BodyweightReps(set)) => {
+            Some(f32::from(set.reps))
+        }
+        (ExerciseType::BodyweightTime { .. }, WorkoutSet::BodyweightTime(set)) => {
+            Some(f32::from(set.duration_seconds))
+        }
+        _ => None,
+    }
+}
+
+/// Position along a whole progression, along with the progress within its current step.
+#[derive(Debug)]
+pub struct ProgressionProgress {
+    pub status: ProgressionStatus,
+    /// Progress within the current step, `None` once the progression is finished
+    pub current: Option<ExerciseProgress>,
+}
+
+impl ProgressionProgress {
+    pub fn new(progression: ExerciseProgression, workouts: &[Workout], since: NaiveDate) -> Self {
+        let status = ProgressionStatus::new(progression, workouts);
+        let current = status
+            .current()
+            .map(|exercise| ExerciseProgress::new(exercise.clone(), workouts, since));
+        ProgressionProgress { status, current }
+    }
+
+    /// Share of the progression done, from 0 to 1. Every step weighs the same and the current
+    /// step counts for the share of its goal reached.
+    pub fn ratio(&self) -> f32 {
+        match (self.status.current_step, &self.current) {
+            (Some(step), Some(current)) => {
+                (step as f32 + current.ratio()) / self.status.steps.len() as f32
+            }
+            _ => 1.0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::workout::{BodyweightRepSet, WeightedSet, WorkoutExercise};
+
+    fn exercise(id: u64, exercise_type: ExerciseType) -> Exercise {
+        Exercise {
+            id,
+            name: format!("exercise {id}"),
+            exercise_type,
+        }
+    }
+
+    fn reps_goal(goal_reps: u16) -> ExerciseType {
+        ExerciseType::BodyweightReps {
+            goal_reps,
+            goal_sets: 1,
+        }
+    }
+
+    fn workout(date: &str, exercise: Exercise, sets: Vec<WorkoutSet>) -> Workout {
+        Workout {
+            id: 0,
+            date: date.parse().unwrap(),
+            mood: None,
+            template_id: None,
+            exercises: vec![WorkoutExercise { exercise, sets }],
+        }
+    }
+
+    fn reps(reps: u16) -> WorkoutSet {
+        WorkoutSet::BodyweightReps(BodyweightRepSet {
+            id: 0,
+            reps,
+            failure: false,
+        })
+    }
+
+    fn weighted(reps: u16, weight: f32) -> WorkoutSet {
+        WorkoutSet::Weighted(WeightedSet {
+            id: 0,
+            reps,
+            weight,
+            failure: false,
+        })
+    }
+
+    fn since(date: &str) -> NaiveDate {
+        date.parse().unwrap()
+    }
+
+    #[test]
+    fn exercise_progress_only_counts_recent_sets() {
+        let pushup = exercise(1, reps_goal(20));
+        let workouts = [
+            workout("2026-01-10", pushup.clone(), vec![reps(18)]),
+            workout("2026-03-10", pushup.clone(), vec![reps(10), reps(12)]),
+        ];
+
+        let progress = ExerciseProgress::new(pushup, &workouts, since("2026-03-01"));
+
+        assert_eq!(progress.best, Some(12.0));
+        assert_eq!(progress.ratio(), 0.6);
+    }
+
+    #[test]
+    fn exercise_progress_needs_goal_reps_for_weighted_sets() {
+        let squat = exercise(
+            1,
+            ExerciseType::Weighted {
+                goal_weight: 100.0,
+                goal_reps: 5,
+                goal_sets: 1,
+            },
+        );
+        let workouts = [workout(
+            "2026-03-10",
+            squat.clone(),
+            vec![weighted(1, 110.0), weighted(5, 80.0)],
+        )];
+
+        let progress = ExerciseProgress::new(squat, &workouts, since("2026-03-01"));
+
+        assert_eq!(progress.best, Some(80.0));
+    }
+
+    #[test]
+    fn exercise_progress_caps_ratio_at_goal() {
+        let pushup = exercise(1, reps_goal(10));
+        let workouts = [workout("2026-03-10", pushup.clone(), vec![reps(15)])];
+
+        let progress = ExerciseProgress::new(pushup, &workouts, since("2026-03-01"));
+
+        assert_eq!(progress.ratio(), 1.0);
+    }
+
+    #[test]
+    fn progression_progress_adds_current_step_share_to_completed_steps() {
+        let first = exercise(1, reps_goal(10));
+        let second = exercise(2, reps_goal(10));
+        let progression = ExerciseProgression {
+            name: "Pushup".to_string(),
+            progression: vec![first.clone(), second.clone(), exercise(3, reps_goal(10))],
+        };
+        let workouts = [
+            workout("2026-03-03", first, vec![reps(10)]),
+            workout("2026-03-10", second, vec![reps(5)]),
+        ];
+
+        let progress = ProgressionProgress::new(progression, &workouts, since("2026-03-01"));
+
+        assert_eq!(progress.status.current_step, Some(1));
+        assert_eq!(progress.current.as_ref().unwrap().ratio(), 0.5);
+        assert_eq!(progress.ratio(), 0.5);
+    }
+}
@@ -0,0 +1,83 @@
+use std::collections::HashMap;
+
+use crate::domain::{
+    services::goal_file::Goal,
+    traits::exercise_model::{ExerciseModel, ExerciseModelError},
+};
+
+/// Collects every exercise and progression as goals, in the order their first exercise was
+/// created, so exporting what a goal file imported lists the goals in the file's order.
+pub async fn export_goals(
+    exercise_model: &dyn ExerciseModel,
+) -> Result<Vec<Goal>, ExerciseModelError> {
+    let mut progressions_by_exercise = HashMap::new();
+    let mut progressions = Vec::new();
+    for progression in exercise_model.get_all_exercise_progressions().await? {
+        for step in &progression.progression {
+            progressions_by_exercise.insert(step.id, progressions.len());
+        }
+        progressions.push(Some(progression));
+    }
+
+    let mut goals = Vec::new();
+    for exercise in exercise_model.get_all_exercises().await? {
+        match progressions_by_exercise.get(&exercise.id) {
+            Some(&index) => {
+                if let Some(progression) = progressions[index].take() {
+                    goals.push(Goal::Progression(progression));
+                }
+            }
+            None => goals.push(Goal::Exercise(exercise)),
+        }
+    }
+    Ok(goals)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        domain::services::{
+            goal_file::{parse_goals, serialize_goals},
+            goal_import::import_goals,
+        },
+        outbound::ExerciseRepository,
+    };
+
+    const GOAL_FILE: &str = include_str!("../../../goal.yml");
+
+    #[sqlx::test]
+    async fn export_goals_after_import_reproduces_goal_file(pool: sqlx::SqlitePool) {
+        let mut repo = ExerciseRepository::from_pool(pool);
+        import_goals(&mut repo, parse_goals(GOAL_FILE).unwrap(), false)
+            .await
+            .unwrap();
+
+        let goals = export_goals(&repo).await.expect("export should succeed");
+
+        assert_eq!(serialize_goals(goals).unwrap(), GOAL_FILE);
+    }
+
+    #[sqlx::test]
+    async fn import_of_export_leaves_everything_unchanged(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_reps, goal_duration_seconds, goal_sets, progression_name, progression_order) VALUES
+             ('Knee pushup', 'bodyweight_reps', 20, NULL, 2, 'Pushup', 1),
+             ('Plank', 'bodyweight_time', NULL, 60, NULL, NULL, NULL),
+             ('Pushup', 'bodyweight_reps', 10, NULL, 3, 'Pushup', 2)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let mut repo = ExerciseRepository::from_pool(pool);
+
+        let yaml = serialize_goals(export_goals(&repo).await.unwrap()).unwrap();
+        let report = import_goals(&mut repo, parse_goals(&yaml).unwrap(), false)
+            .await
+            .unwrap();
+
+        assert!(report.created.is_empty());
+        assert!(report.changed.is_empty());
+        assert_eq!(report.unchanged, ["Pushup", "Plank"]);
+    }
+}
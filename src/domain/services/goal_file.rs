@@ -0,0 +1,504 @@
+//! The `goal.yml` format: a list of goals, each being either a weighted lift or a progression of
+//! bodyweight steps. Progressions are written from the final goal down to the easiest step, the
+//! reverse of how `ExerciseProgression` stores them. Omitted `target_reps` of weighted goals and
+//! `target_sets` mean 1.
+
+use serde::{Deserialize, Serialize};
+
+use crate::domain::types::exercise::{Exercise, ExerciseProgression, ExerciseType};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GoalEntry {
+    pub name: String,
+    pub target: GoalTarget,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub enum GoalTarget {
+    Weighted {
+        target_weight: f32,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target_reps: Option<u16>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        target_sets: Option<u16>,
+    },
+    BodyweightReps {
+        progression: Vec<GoalStep<RepsTarget>>,
+    },
+    BodyweightTime {
+        progression: Vec<GoalStep<DurationTarget>>,
+    },
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct GoalStep<T> {
+    pub name: String,
+    pub target: T,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct RepsTarget {
+    pub target_reps: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_sets: Option<u16>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+pub struct DurationTarget {
+    pub target_duration_seconds: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub target_sets: Option<u16>,
+}
+
+/// A goal file entry once mapped onto the domain.
+#[derive(Debug, PartialEq)]
+pub enum Goal {
+    Exercise(Exercise),
+    Progression(ExerciseProgression),
+}
+
+impl Goal {
+    pub fn name(&self) -> &str {
+        match self {
+            Goal::Exercise(exercise) => &exercise.name,
+            Goal::Progression(progression) => &progression.name,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub enum GoalFileError {
+    Parse(String),
+    Invalid(String),
+    /// The goals could not be written out as YAML
+    Serialize(String),
+}
+
+impl std::fmt::Display for GoalFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GoalFileError::Parse(message) => write!(f, "could not parse goal file: {message}"),
+            GoalFileError::Invalid(message) => write!(f, "invalid goal file: {message}"),
+            GoalFileError::Serialize(message) => {
+                write!(f, "could not write goal file: {message}")
+            }
+        }
+    }
+}
+
+fn new_exercise(name: String, exercise_type: ExerciseType) -> Exercise {
+    Exercise {
+        id: 0,
+        name,
+        exercise_type,
+    }
+}
+
+fn check_positive(entry: &str, step: &str, value: f32) -> Result<(), GoalFileError> {
+    if value > 0.0 {
+        Ok(())
+    } else {
+        Err(GoalFileError::Invalid(format!(
+            "'{entry}': target of '{step}' must be greater than 0"
+        )))
+    }
+}
+
+/// Builds the goal described by `name`, turning a progression made of a single step of the same
+/// name back into a plain exercise.
+fn progression_goal(name: String, mut steps: Vec<Exercise>) -> Result<Goal, GoalFileError> {
+    if steps.is_empty() {
+        return Err(GoalFileError::Invalid(format!(
+            "'{name}': a progression needs at least one step"
+        )));
+    }
+    if steps.len() == 1 && steps[0].name == name {
+        return Ok(Goal::Exercise(steps.remove(0)));
+    }
+    steps.reverse();
+    Ok(Goal::Progression(ExerciseProgression {
+        name,
+        progression: steps,
+    }))
+}
+
+impl TryFrom<GoalEntry> for Goal {
+    type Error = GoalFileError;
+
+    fn try_from(entry: GoalEntry) -> Result<Self, Self::Error> {
+        match entry.target {
+            GoalTarget::Weighted {
+                target_weight,
+                target_reps,
+                target_sets,
+            } => {
+                let goal_reps = target_reps.unwrap_or(1);
+                let goal_sets = target_sets.unwrap_or(1);
+                check_positive(&entry.name, &entry.name, target_weight)?;
+                check_positive(&entry.name, &entry.name, goal_reps.into())?;
+                check_positive(&entry.name, &entry.name, goal_sets.into())?;
+                Ok(Goal::Exercise(new_exercise(
+                    entry.name,
+                    ExerciseType::Weighted {
+                        goal_weight: target_weight,
+                        goal_reps,
+                        goal_sets,
+                    },
+                )))
+            }
+            GoalTarget::BodyweightReps { progression } => {
+                let steps = progression
+                    .into_iter()
+                    .map(|step| {
+                        let goal_sets = step.target.target_sets.unwrap_or(1);
+                        check_positive(&entry.name, &step.name, step.target.target_reps.into())?;
+                        check_positive(&entry.name, &step.name, goal_sets.into())?;
+                        Ok(new_exercise(
+                            step.name,
+                            ExerciseType::BodyweightReps {
+                                goal_reps: step.target.target_reps,
+                                goal_sets,
+                            },
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, GoalFileError>>()?;
+                progression_goal(entry.name, steps)
+            }
+            GoalTarget::BodyweightTime { progression } => {
+                let steps = progression
+                    .into_iter()
+                    .map(|step| {
+                        let goal_sets = step.target.target_sets.unwrap_or(1);
+                        check_positive(
+                            &entry.name,
+                            &step.name,
+                            step.target.target_duration_seconds.into(),
+                        )?;
+                        check_positive(&entry.name, &step.name, goal_sets.into())?;
+                        Ok(new_exercise(
+                            step.name,
+                            ExerciseType::BodyweightTime {
+                                goal_duration_seconds: step.target.target_duration_seconds,
+                                goal_sets,
+                            },
+                        ))
+                    })
+                    .collect::<Result<Vec<_>, GoalFileError>>()?;
+                progression_goal(entry.name, steps)
+            }
+        }
+    }
+}
+
+fn optional_count(count: u16) -> Option<u16> {
+    (count != 1).then_some(count)
+}
+
+impl TryFrom<Goal> for GoalEntry {
+    type Error = GoalFileError;
+
+    fn try_from(goal: Goal) -> Result<Self, Self::Error> {
+        let (name, steps) = match goal {
+            Goal::Exercise(Exercise {
+                name,
+                exercise_type:
+                    ExerciseType::Weighted {
+                        goal_weight,
+                        goal_reps,
+                        goal_sets,
+                    },
+                ..
+            }) => {
+                return Ok(GoalEntry {
+                    name,
+                    target: GoalTarget::Weighted {
+                        target_weight: goal_weight,
+                        target_reps: optional_count(goal_reps),
+                        target_sets: optional_count(goal_sets),
+                    },
+                });
+            }
+            Goal::Exercise(exercise) => (exercise.name.clone(), vec![exercise]),
+            Goal::Progression(progression) => {
+                let mut steps = progression.progression;
+                steps.reverse();
+                (progression.name, steps)
+            }
+        };
+
+        let target = match steps.first().map(|step| &step.exercise_type) {
+            Some(ExerciseType::BodyweightReps { .. }) => GoalTarget::BodyweightReps {
+                progression: steps
+                    .into_iter()
+                    .map(|step| match step.exercise_type {
+                        ExerciseType::BodyweightReps {
+                            goal_reps,
+                            goal_sets,
+                        } => Ok(GoalStep {
+                            name: step.name,
+                            target: RepsTarget {
+                                target_reps: goal_reps,
+                                target_sets: optional_count(goal_sets),
+                            },
+                        }),
+                        _ => Err(mixed_progression(&name)),
+                    })
+                    .collect::<Result<_, _>>()?,
+            },
+            Some(ExerciseType::BodyweightTime { .. }) => GoalTarget::BodyweightTime {
+                progression: steps
+                    .into_iter()
+                    .map(|step| match step.exercise_type {
+                        ExerciseType::BodyweightTime {
+                            goal_duration_seconds,
+                            goal_sets,
+                        } => Ok(GoalStep {
+                            name: step.name,
+                            target: DurationTarget {
+                                target_duration_seconds: goal_duration_seconds,
+                                target_sets: optional_count(goal_sets),
+                            },
+                        }),
+                        _ => Err(mixed_progression(&name)),
+                    })
+                    .collect::<Result<_, _>>()?,
+            },
+            Some(ExerciseType::Weighted { .. }) => {
+                return Err(GoalFileError::Invalid(format!(
+                    "'{name}': weighted exercises cannot be part of a progression"
+                )));
+            }
+            None => {
+                return Err(GoalFileError::Invalid(format!(
+                    "'{name}': a progression needs at least one step"
+                )));
+            }
+        };
+        Ok(GoalEntry { name, target })
+    }
+}
+
+fn mixed_progression(name: &str) -> GoalFileError {
+    GoalFileError::Invalid(format!(
+        "'{name}': all steps of a progression must be of the same kind"
+    ))
+}
+
+/// Parses a goal file and maps every entry onto the domain.
+pub fn parse_goals(yaml: &str) -> Result<Vec<Goal>, GoalFileError> {
+    let entries: Vec<GoalEntry> =
+        serde_yml::from_str(yaml).map_err(|e| GoalFileError::Parse(e.to_string()))?;
+    entries.into_iter().map(Goal::try_from).collect()
+}
+
+/// Writes `goals` as a goal file, entries separated by a blank line like the hand-written one.
+pub fn serialize_goals(goals: Vec<Goal>) -> Result<String, GoalFileError> {
+    let entries = goals
+        .into_iter()
+        .map(GoalEntry::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    if entries.is_empty() {
+        return Ok("[]\n".to_string());
+    }
+    let yaml =
+        serde_yml::to_string(&entries).map_err(|e| GoalFileError::Serialize(e.to_string()))?;
+
+    let mut output = String::with_capacity(yaml.len() + entries.len());
+    for line in yaml.lines() {
+        if line.starts_with("- ") && !output.is_empty() {
+            output.push('\n');
+        }
+        output.push_str(line);
+        output.push('\n');
+    }
+    Ok(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_goals_reads_the_repository_goal_file() {
+        let goals = parse_goals(include_str!("../../../goal.yml")).expect("should parse");
+
+        let names: Vec<&str> = goals.iter().map(Goal::name).collect();
+        assert_eq!(
+            names,
+            [
+                "Military press",
+                "Squat",
+                "Deadlift",
+                "Handstand pushup",
+                "Handstand"
+            ]
+        );
+        assert_eq!(
+            goals[1],
+            Goal::Exercise(new_exercise(
+                "Squat".to_string(),
+                ExerciseType::Weighted {
+                    goal_weight: 115.0,
+                    goal_reps: 1,
+                    goal_sets: 1
+                }
+            ))
+        );
+        match &goals[3] {
+            Goal::Progression(progression) => {
+                let steps: Vec<&str> = progression
+                    .progression
+                    .iter()
+                    .map(|step| step.name.as_str())
+                    .collect();
+                assert_eq!(
+                    steps,
+                    [
+                        "Pike pushup",
+                        "Elevated pike pushup",
+                        "Wall pushup",
+                        "Handstand pushup"
+                    ]
+                );
+                assert_eq!(
+                    progression.progression[3].exercise_type,
+                    ExerciseType::BodyweightReps {
+                        goal_reps: 3,
+                        goal_sets: 1
+                    }
+                );
+                assert_eq!(
+                    progression.progression[2].exercise_type,
+                    ExerciseType::BodyweightReps {
+                        goal_reps: 5,
+                        goal_sets: 3
+                    }
+                );
+            }
+            other => panic!("expected a progression, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_goals_treats_single_step_of_same_name_as_exercise() {
+        let yaml = r#"
+- name: Plank
+  target: !BodyweightTime
+    progression:
+    - name: Plank
+      target:
+        target_duration_seconds: 90
+"#;
+
+        let goals = parse_goals(yaml).expect("should parse");
+        assert_eq!(
+            goals,
+            [Goal::Exercise(new_exercise(
+                "Plank".to_string(),
+                ExerciseType::BodyweightTime {
+                    goal_duration_seconds: 90,
+                    goal_sets: 1
+                }
+            ))]
+        );
+    }
+
+    #[test]
+    fn parse_goals_reads_weighted_sets_and_reps() {
+        let yaml = r#"
+- name: Squat
+  target: !Weighted
+    target_weight: 100.0
+    target_reps: 5
+    target_sets: 5
+"#;
+
+        let goals = parse_goals(yaml).expect("should parse");
+        assert_eq!(
+            goals,
+            [Goal::Exercise(new_exercise(
+                "Squat".to_string(),
+                ExerciseType::Weighted {
+                    goal_weight: 100.0,
+                    goal_reps: 5,
+                    goal_sets: 5
+                }
+            ))]
+        );
+    }
+
+    #[test]
+    fn serialize_goals_writes_the_parsed_goal_file_back_unchanged() {
+        let goal_file = include_str!("../../../goal.yml");
+        let goals = parse_goals(goal_file).unwrap();
+
+        assert_eq!(serialize_goals(goals).unwrap(), goal_file);
+    }
+
+    #[test]
+    fn serialize_goals_writes_plain_bodyweight_exercise_as_single_step() {
+        let goals = vec![Goal::Exercise(new_exercise(
+            "Plank".to_string(),
+            ExerciseType::BodyweightTime {
+                goal_duration_seconds: 90,
+                goal_sets: 2,
+            },
+        ))];
+
+        let yaml = serialize_goals(goals).unwrap();
+        assert_eq!(
+            yaml,
+            "- name: Plank\n  target: !BodyweightTime\n    progression:\n    - name: Plank\n      target:\n        target_duration_seconds: 90\n        target_sets: 2\n"
+        );
+    }
+
+    #[test]
+    fn serialize_goals_rejects_mixed_progressions() {
+        let goals = vec![Goal::Progression(ExerciseProgression {
+            name: "Handstand".to_string(),
+            progression: vec![
+                new_exercise(
+                    "Wall walk".to_string(),
+                    ExerciseType::BodyweightReps {
+                        goal_reps: 5,
+                        goal_sets: 1,
+                    },
+                ),
+                new_exercise(
+                    "Handstand".to_string(),
+                    ExerciseType::BodyweightTime {
+                        goal_duration_seconds: 30,
+                        goal_sets: 1,
+                    },
+                ),
+            ],
+        })];
+
+        assert!(matches!(
+            serialize_goals(goals),
+            Err(GoalFileError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn parse_goals_rejects_unknown_targets() {
+        let yaml = r#"
+- name: Run
+  target: !Distance
+    target_meters: 5000
+"#;
+
+        assert!(matches!(parse_goals(yaml), Err(GoalFileError::Parse(_))));
+    }
+
+    #[test]
+    fn parse_goals_rejects_empty_progressions() {
+        let yaml = r#"
+- name: Planche
+  target: !BodyweightTime
+    progression: []
+"#;
+
+        assert!(matches!(parse_goals(yaml), Err(GoalFileError::Invalid(_))));
+    }
+}
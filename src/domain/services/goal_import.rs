@@ -0,0 +1,265 @@
+use crate::domain::{
+    services::goal_file::Goal,
+    traits::exercise_model::{ExerciseModel, ExerciseModelError},
+    types::exercise::{Exercise, ExerciseChange, ExerciseProgression},
+};
+
+/// What an import did, or would do in a dry run, to each goal, listed by goal name.
+#[derive(Debug, Default, PartialEq)]
+pub struct ImportReport {
+    pub created: Vec<String>,
+    pub changed: Vec<String>,
+    pub unchanged: Vec<String>,
+}
+
+enum Outcome {
+    Created,
+    Changed,
+    Unchanged,
+}
+
+/// Upserts `goals` through `exercise_model` in a single batch, so a failing goal leaves the
+/// database untouched. Exercises are matched by name, ignoring case, so steps that already
+/// exist keep their id and logged sets. Nothing is written when `dry_run`.
+pub async fn import_goals(
+    exercise_model: &mut dyn ExerciseModel,
+    goals: Vec<Goal>,
+    dry_run: bool,
+) -> Result<ImportReport, ExerciseModelError> {
+    let mut report = ImportReport::default();
+    let mut changes = Vec::new();
+    for goal in goals {
+        let name = goal.name().to_string();
+        let (outcome, change) = match goal {
+            Goal::Exercise(exercise) => plan_exercise(exercise_model, exercise).await?,
+            Goal::Progression(progression) => plan_progression(exercise_model, progression).await?,
+        };
+        match outcome {
+            Outcome::Created => report.created.push(name),
+            Outcome::Changed => report.changed.push(name),
+            Outcome::Unchanged => report.unchanged.push(name),
+        }
+        changes.extend(change);
+    }
+    if !dry_run && !changes.is_empty() {
+        exercise_model.save_exercise_changes(changes).await?;
+    }
+    Ok(report)
+}
+
+async fn find_exercise_by_name(
+    exercise_model: &dyn ExerciseModel,
+    name: &str,
+) -> Result<Option<Exercise>, ExerciseModelError> {
+    match exercise_model.get_exercise_by_name(name).await {
+        Ok(exercise) => Ok(Some(exercise)),
+        Err(ExerciseModelError::NotFound) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+async fn plan_exercise(
+    exercise_model: &dyn ExerciseModel,
+    exercise: Exercise,
+) -> Result<(Outcome, Option<ExerciseChange>), ExerciseModelError> {
+    match find_exercise_by_name(exercise_model, &exercise.name).await? {
+        None => Ok((Outcome::Created, Some(ExerciseChange::Add(exercise)))),
+        Some(existing)
+            if existing.name == exercise.name
+                && existing.exercise_type == exercise.exercise_type =>
+        {
+            Ok((Outcome::Unchanged, None))
+        }
+        Some(existing) => Ok((
+            Outcome::Changed,
+            Some(ExerciseChange::Update(Exercise {
+                id: existing.id,
+                ..exercise
+            })),
+        )),
+    }
+}
+
+async fn plan_progression(
+    exercise_model: &dyn ExerciseModel,
+    mut progression: ExerciseProgression,
+) -> Result<(Outcome, Option<ExerciseChange>), ExerciseModelError> {
+    let existing = match exercise_model
+        .get_exercise_progression_from_name(&progression.name)
+        .await
+    {
+        Ok(existing) => Some(existing),
+        Err(ExerciseModelError::NotFound) => None,
+        Err(e) => return Err(e),
+    };
+
+    for step in &mut progression.progression {
+        if let Some(existing_step) = find_exercise_by_name(exercise_model, &step.name).await? {
+            step.id = existing_step.id;
+        }
+    }
+
+    let outcome = match existing {
+        None => Outcome::Created,
+        Some(existing) if existing == progression => return Ok((Outcome::Unchanged, None)),
+        Some(_) => Outcome::Changed,
+    };
+    Ok((outcome, Some(ExerciseChange::SaveProgression(progression))))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{domain::services::goal_file::parse_goals, outbound::ExerciseRepository};
+
+    const GOAL_FILE: &str = include_str!("../../../goal.yml");
+
+    #[sqlx::test]
+    async fn import_goals_creates_everything_on_empty_database(pool: sqlx::SqlitePool) {
+        let mut repo = ExerciseRepository::from_pool(pool);
+
+        let report = import_goals(&mut repo, parse_goals(GOAL_FILE).unwrap(), false)
+            .await
+            .expect("import should succeed");
+
+        assert_eq!(report.created.len(), 5);
+        assert!(report.changed.is_empty());
+        let progression = repo
+            .get_exercise_progression_from_name("Handstand pushup")
+            .await
+            .expect("progression should exist");
+        assert_eq!(progression.progression[0].name, "Pike pushup");
+        assert_eq!(progression.progression[3].name, "Handstand pushup");
+        assert_eq!(repo.get_all_exercises().await.unwrap().len(), 11);
+    }
+
+    #[sqlx::test]
+    async fn import_goals_twice_leaves_everything_unchanged(pool: sqlx::SqlitePool) {
+        let mut repo = ExerciseRepository::from_pool(pool);
+        import_goals(&mut repo, parse_goals(GOAL_FILE).unwrap(), false)
+            .await
+            .unwrap();
+
+        let report = import_goals(&mut repo, parse_goals(GOAL_FILE).unwrap(), false)
+            .await
+            .expect("import should succeed");
+
+        assert!(report.created.is_empty());
+        assert!(report.changed.is_empty());
+        assert_eq!(report.unchanged.len(), 5);
+    }
+
+    #[sqlx::test]
+    async fn import_goals_dry_run_reports_without_writing(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES
+             ('squat', 'weighted', 100.0),
+             ('Deadlift', 'weighted', 140.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let mut repo = ExerciseRepository::from_pool(pool);
+
+        let report = import_goals(&mut repo, parse_goals(GOAL_FILE).unwrap(), true)
+            .await
+            .expect("dry run should succeed");
+
+        assert_eq!(
+            report.created,
+            ["Military press", "Handstand pushup", "Handstand"]
+        );
+        assert_eq!(report.changed, ["Squat"]);
+        assert_eq!(report.unchanged, ["Deadlift"]);
+        assert_eq!(repo.get_all_exercises().await.unwrap().len(), 2);
+    }
+
+    #[sqlx::test]
+    async fn import_goals_keeps_ids_of_existing_steps(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_reps) VALUES ('Pike pushup', 'bodyweight_reps', 10)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let mut repo = ExerciseRepository::from_pool(pool);
+
+        import_goals(&mut repo, parse_goals(GOAL_FILE).unwrap(), false)
+            .await
+            .expect("import should succeed");
+
+        let progression = repo
+            .get_exercise_progression_from_name("Handstand pushup")
+            .await
+            .unwrap();
+        assert_eq!(progression.progression[0].id, 1);
+        assert_eq!(
+            progression.progression[0].exercise_type,
+            crate::domain::types::exercise::ExerciseType::BodyweightReps {
+                goal_reps: 15,
+                goal_sets: 3
+            }
+        );
+    }
+
+    #[sqlx::test]
+    async fn import_goals_reuses_exercises_created_earlier_in_the_file(pool: sqlx::SqlitePool) {
+        let goals = parse_goals(
+            "- name: Pike pushup
+  target: !BodyweightReps
+    progression:
+    - name: Pike pushup
+      target:
+        target_reps: 15
+- name: Handstand pushup
+  target: !BodyweightReps
+    progression:
+    - name: Handstand pushup
+      target:
+        target_reps: 3
+    - name: Pike pushup
+      target:
+        target_reps: 15
+",
+        )
+        .unwrap();
+        let mut repo = ExerciseRepository::from_pool(pool);
+
+        import_goals(&mut repo, goals, false)
+            .await
+            .expect("import should succeed");
+
+        let progression = repo
+            .get_exercise_progression_from_name("Handstand pushup")
+            .await
+            .unwrap();
+        assert_eq!(progression.progression[0].id, 1);
+        assert_eq!(repo.get_all_exercises().await.unwrap().len(), 2);
+    }
+
+    #[sqlx::test]
+    async fn import_goals_writes_nothing_when_a_goal_fails(pool: sqlx::SqlitePool) {
+        let goals = parse_goals(
+            "- name: Squat
+  target: !Weighted
+    target_weight: 115.0
+- name: Plank
+  target: !BodyweightTime
+    progression:
+    - name: Plank hold
+      target:
+        target_duration_seconds: 60
+    - name: Plank hold
+      target:
+        target_duration_seconds: 30
+",
+        )
+        .unwrap();
+        let mut repo = ExerciseRepository::from_pool(pool);
+
+        let result = import_goals(&mut repo, goals, false).await;
+
+        assert!(matches!(result, Err(ExerciseModelError::AlreadyExists)));
+        assert!(repo.get_all_exercises().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,9 @@
+pub mod exercise_progress;
+pub mod goal_export;
+pub mod goal_file;
+pub mod goal_import;
+pub mod mood_report;
+pub mod one_rep_max;
+pub mod personal_records;
+pub mod progression_status;
+pub mod training_stats;
@@ -0,0 +1,381 @@
+use chrono::{Datelike, NaiveDate, Weekday};
+
+use crate::domain::{
+    services::{
+        exercise_progress::ExerciseProgress,
+        training_stats::{MoodAverage, StatsGrouping},
+    },
+    types::workout::{Workout, WorkoutSet},
+};
+
+/// How one workout with a mood went.
+#[derive(Debug)]
+pub struct MoodSession {
+    pub workout_id: u64,
+    pub date: NaiveDate,
+    pub mood: u8,
+    /// Share of the goal reached, from 0 to 1, averaged over the exercises of the workout
+    pub goal_reached: f32,
+    /// Share of the sets taken to failure, from 0 to 1
+    pub failure_ratio: f32,
+}
+
+impl MoodSession {
+    /// `None` for workouts without a mood or without any set, there is nothing to compare.
+    fn new(workout: &Workout) -> Option<Self> {
+        let mood = workout.mood?;
+        let sets: Vec<&WorkoutSet> = workout
+            .exercises
+            .iter()
+            .flat_map(|done| &done.sets)
+            .collect();
+        if sets.is_empty() {
+            return None;
+        }
+        let failure_sets = sets.iter().filter(|set| set.is_failure()).count();
+
+        let mut exercise_ids = Vec::new();
+        let mut goal_total = 0.0;
+        for done in &workout.exercises {
+            if exercise_ids.contains(&done.exercise.id) {
+                continue;
+            }
+            exercise_ids.push(done.exercise.id);
+            goal_total += ExerciseProgress::new(
+                done.exercise.clone(),
+                std::slice::from_ref(workout),
+                workout.date,
+            )
+            .ratio();
+        }
+
+        Some(MoodSession {
+            workout_id: workout.id,
+            date: workout.date,
+            mood,
+            goal_reached: goal_total / exercise_ids.len() as f32,
+            failure_ratio: failure_sets as f32 / sets.len() as f32,
+        })
+    }
+}
+
+/// Averages of the sessions logged with the same mood.
+#[derive(Debug, PartialEq)]
+pub struct MoodPerformance {
+    pub mood: u8,
+    pub workouts: u32,
+    pub goal_reached: f32,
+    pub failure_ratio: f32,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WeekMood {
+    /// Monday of the week, or `from` for a week cut short
+    pub start: NaiveDate,
+    pub workouts: u32,
+    pub average_mood: Option<f32>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct WeekdayMood {
+    pub weekday: Weekday,
+    pub workouts: u32,
+    pub average_mood: Option<f32>,
+}
+
+/// Whether bad days make bad sessions: the mood of the workouts from `from` to `to` set
+/// against how close they got to the goals and how many sets went to failure. Only
+/// workouts with a mood and at least one set are looked at.
+#[derive(Debug)]
+pub struct MoodReport {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Every session looked at, oldest first
+    pub sessions: Vec<MoodSession>,
+    /// One entry per mood logged at least once, lowest first
+    pub by_mood: Vec<MoodPerformance>,
+    /// Pearson correlation of the mood with the share of the goal reached, `None` without
+    /// enough variety to tell
+    pub goal_correlation: Option<f32>,
+    /// Pearson correlation of the mood with the share of failure sets
+    pub failure_correlation: Option<f32>,
+    /// Every week overlapping the dates, oldest first, including the ones without workouts
+    pub by_week: Vec<WeekMood>,
+    /// Monday to Sunday
+    pub by_weekday: Vec<WeekdayMood>,
+}
+
+impl MoodReport {
+    /// Workouts dated outside of `from`..=`to` are left out.
+    pub fn new(from: NaiveDate, to: NaiveDate, workouts: &[Workout]) -> Self {
+        let mut sessions: Vec<MoodSession> = workouts
+            .iter()
+            .filter(|workout| (from..=to).contains(&workout.date))
+            .filter_map(MoodSession::new)
+            .collect();
+        sessions.sort_by_key(|session| (session.date, session.workout_id));
+
+        let by_mood = (1..=10)
+            .filter_map(|mood| {
+                let same: Vec<&MoodSession> = sessions
+                    .iter()
+                    .filter(|session| session.mood == mood)
+                    .collect();
+                let count = same.len() as f32;
+                (!same.is_empty()).then(|| MoodPerformance {
+                    mood,
+                    workouts: same.len() as u32,
+                    goal_reached: same.iter().map(|session| session.goal_reached).sum::<f32>()
+                        / count,
+                    failure_ratio: same
+                        .iter()
+                        .map(|session| session.failure_ratio)
+                        .sum::<f32>()
+                        / count,
+                })
+            })
+            .collect();
+
+        let mut weeks: Vec<(NaiveDate, u32, MoodAverage)> = StatsGrouping::Week
+            .periods(from, to)
+            .into_iter()
+            .map(|(start, _)| (start, 0, MoodAverage::default()))
+            .collect();
+        for session in &sessions {
+            let week = StatsGrouping::Week.period_index(from, session.date);
+            if let Some((_, workouts, average)) = weeks.get_mut(week) {
+                *workouts += 1;
+                average.add(Some(session.mood));
+            }
+        }
+        let by_week = weeks
+            .into_iter()
+            .map(|(start, workouts, average)| WeekMood {
+                start,
+                workouts,
+                average_mood: average.get(),
+            })
+            .collect();
+
+        let by_weekday = (0..7)
+            .filter_map(|day| Weekday::try_from(day).ok())
+            .map(|weekday| {
+                let (workouts, average_mood) = average_mood(
+                    sessions
+                        .iter()
+                        .filter(|session| session.date.weekday() == weekday),
+                );
+                WeekdayMood {
+                    weekday,
+                    workouts,
+                    average_mood,
+                }
+            })
+            .collect();
+
+        MoodReport {
+            from,
+            to,
+            goal_correlation: correlation(
+                sessions
+                    .iter()
+                    .map(|session| (f32::from(session.mood), session.goal_reached)),
+            ),
+            failure_correlation: correlation(
+                sessions
+                    .iter()
+                    .map(|session| (f32::from(session.mood), session.failure_ratio)),
+            ),
+            sessions,
+            by_mood,
+            by_week,
+            by_weekday,
+        }
+    }
+}
+
+fn average_mood<'a>(sessions: impl Iterator<Item = &'a MoodSession>) -> (u32, Option<f32>) {
+    let mut average = MoodAverage::default();
+    let mut count = 0;
+    for session in sessions {
+        average.add(Some(session.mood));
+        count += 1;
+    }
+    (count, average.get())
+}
+
+/// Pearson correlation coefficient, from -1 to 1. `None` with fewer than 3 points or when
+/// either side never changes.
+fn correlation(points: impl Iterator<Item = (f32, f32)>) -> Option<f32> {
+    let points: Vec<(f32, f32)> = points.collect();
+    if points.len() < 3 {
+        return None;
+    }
+    let count = points.len() as f32;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f32>() / count;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f32>() / count;
+    let (mut covariance, mut variance_x, mut variance_y) = (0.0, 0.0, 0.0);
+    for (x, y) in &points {
+        covariance += (x - mean_x) * (y - mean_y);
+        variance_x += (x - mean_x).powi(2);
+        variance_y += (y - mean_y).powi(2);
+    }
+    let spread = (variance_x * variance_y).sqrt();
+    (spread > f32::EPSILON).then(|| (covariance / spread).clamp(-1.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::{
+        exercise::{Exercise, ExerciseType},
+        workout::{BodyweightRepSet, WorkoutExercise},
+    };
+
+    fn pull_up() -> Exercise {
+        Exercise {
+            id: 1,
+            name: "Pull-up".to_string(),
+            exercise_type: ExerciseType::BodyweightReps {
+                goal_reps: 10,
+                goal_sets: 3,
+            },
+        }
+    }
+
+    fn reps(reps: u16, failure: bool) -> WorkoutSet {
+        WorkoutSet::BodyweightReps(BodyweightRepSet {
+            id: 0,
+            reps,
+            failure,
+        })
+    }
+
+    fn workout(id: u64, date: &str, mood: Option<u8>, sets: Vec<WorkoutSet>) -> Workout {
+        Workout {
+            id,
+            date: date.parse().unwrap(),
+            mood,
+            template_id: None,
+            exercises: vec![WorkoutExercise {
+                exercise: pull_up(),
+                sets,
+            }],
+        }
+    }
+
+    fn date(date: &str) -> NaiveDate {
+        date.parse().unwrap()
+    }
+
+    #[test]
+    fn sessions_need_a_mood_and_sets() {
+        let workouts = [
+            workout(
+                1,
+                "2026-03-02",
+                Some(4),
+                vec![reps(5, true), reps(4, false)],
+            ),
+            workout(2, "2026-03-03", None, vec![reps(8, false)]),
+            workout(3, "2026-03-04", Some(8), vec![]),
+        ];
+
+        let report = MoodReport::new(date("2026-03-01"), date("2026-03-31"), &workouts);
+
+        assert_eq!(report.sessions.len(), 1);
+        assert_eq!(report.sessions[0].goal_reached, 0.5);
+        assert_eq!(report.sessions[0].failure_ratio, 0.5);
+        assert_eq!(report.goal_correlation, None);
+    }
+
+    #[test]
+    fn good_days_correlate_with_goals_and_fewer_failures() {
+        let workouts = [
+            workout(1, "2026-03-02", Some(3), vec![reps(4, true), reps(3, true)]),
+            workout(
+                2,
+                "2026-03-04",
+                Some(6),
+                vec![reps(7, false), reps(6, true)],
+            ),
+            workout(
+                3,
+                "2026-03-06",
+                Some(9),
+                vec![reps(10, false), reps(9, false)],
+            ),
+            workout(
+                4,
+                "2026-03-09",
+                Some(9),
+                vec![reps(10, false), reps(10, false)],
+            ),
+        ];
+
+        let report = MoodReport::new(date("2026-03-01"), date("2026-03-31"), &workouts);
+
+        assert!(report.goal_correlation.unwrap() > 0.9);
+        assert!(report.failure_correlation.unwrap() < -0.9);
+        assert_eq!(
+            report.by_mood,
+            [
+                MoodPerformance {
+                    mood: 3,
+                    workouts: 1,
+                    goal_reached: 0.4,
+                    failure_ratio: 1.0,
+                },
+                MoodPerformance {
+                    mood: 6,
+                    workouts: 1,
+                    goal_reached: 0.7,
+                    failure_ratio: 0.5,
+                },
+                MoodPerformance {
+                    mood: 9,
+                    workouts: 2,
+                    goal_reached: 1.0,
+                    failure_ratio: 0.0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn moods_are_averaged_by_week_and_weekday() {
+        let workouts = [
+            workout(1, "2026-03-02", Some(4), vec![reps(5, false)]),
+            workout(2, "2026-03-06", Some(8), vec![reps(5, false)]),
+            workout(3, "2026-03-16", Some(7), vec![reps(5, false)]),
+        ];
+
+        let report = MoodReport::new(date("2026-03-02"), date("2026-03-22"), &workouts);
+
+        assert_eq!(
+            report.by_week,
+            [
+                WeekMood {
+                    start: date("2026-03-02"),
+                    workouts: 2,
+                    average_mood: Some(6.0),
+                },
+                WeekMood {
+                    start: date("2026-03-09"),
+                    workouts: 0,
+                    average_mood: None,
+                },
+                WeekMood {
+                    start: date("2026-03-16"),
+                    workouts: 1,
+                    average_mood: Some(7.0),
+                },
+            ]
+        );
+        assert_eq!(report.by_weekday.len(), 7);
+        assert_eq!(report.by_weekday[0].weekday, Weekday::Mon);
+        assert_eq!(report.by_weekday[0].average_mood, Some(5.5));
+        assert_eq!(report.by_weekday[4].average_mood, Some(8.0));
+        assert_eq!(report.by_weekday[6].workouts, 0);
+    }
+}
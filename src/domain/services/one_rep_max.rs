@@ -0,0 +1,230 @@
+use chrono::NaiveDate;
+use serde::Deserialize;
+
+use crate::domain::types::{
+    exercise::{Exercise, ExerciseType},
+    workout::{Workout, WorkoutSet},
+};
+
+/// Formulas estimating the weight that could be lifted once from a set of several reps. They
+/// all agree on a single rep and drift apart as reps go up, past 10 reps none is reliable.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OneRepMaxFormula {
+    #[default]
+    Epley,
+    Brzycki,
+    Lombardi,
+    Lander,
+    Mayhew,
+    OConner,
+    Wathan,
+}
+
+impl OneRepMaxFormula {
+    /// Estimated one-rep max of `reps` reps at `weight`, `None` when the formula breaks down
+    /// for that many reps.
+    pub fn estimate(self, reps: u16, weight: f32) -> Option<f32> {
+        if reps == 0 {
+            return None;
+        }
+        if reps == 1 {
+            return Some(weight);
+        }
+        let r = f32::from(reps);
+        let estimate = match self {
+            OneRepMaxFormula::Epley => weight * (1.0 + r / 30.0),
+            OneRepMaxFormula::Brzycki if reps < 37 => weight * 36.0 / (37.0 - r),
+            OneRepMaxFormula::Brzycki => return None,
+            OneRepMaxFormula::Lombardi => weight * r.powf(0.1),
+            OneRepMaxFormula::Lander if reps < 37 => 100.0 * weight / (101.3 - 2.67123 * r),
+            OneRepMaxFormula::Lander => return None,
+            OneRepMaxFormula::Mayhew => 100.0 * weight / (52.2 + 41.9 * (-0.055 * r).exp()),
+            OneRepMaxFormula::OConner => weight * (1.0 + 0.025 * r),
+            OneRepMaxFormula::Wathan => 100.0 * weight / (48.8 + 53.8 * (-0.075 * r).exp()),
+        };
+        Some(estimate)
+    }
+}
+
+/// Best estimate of one workout, from its best set of the exercise.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OneRepMaxEstimate {
+    pub workout_id: u64,
+    pub date: NaiveDate,
+    pub reps: u16,
+    pub weight: f32,
+    pub estimate: f32,
+}
+
+#[derive(Debug)]
+pub struct OneRepMaxHistory {
+    pub exercise: Exercise,
+    pub formula: OneRepMaxFormula,
+    /// One entry per workout where the exercise was done, oldest first
+    pub history: Vec<OneRepMaxEstimate>,
+}
+
+impl OneRepMaxHistory {
+    /// Collects the best estimate of every workout in which `exercise` was done.
+    pub fn new(exercise: Exercise, formula: OneRepMaxFormula, workouts: &[Workout]) -> Self {
+        let mut history: Vec<OneRepMaxEstimate> = workouts
+            .iter()
+            .filter_map(|workout| {
+                workout
+                    .exercises
+                    .iter()
+                    .filter(|done| done.exercise.id == exercise.id)
+                    .flat_map(|done| &done.sets)
+                    .filter_map(|set| match set {
+                        WorkoutSet::Weighted(set) => Some(OneRepMaxEstimate {
+                            workout_id: workout.id,
+                            date: workout.date,
+                            reps: set.reps,
+                            weight: set.weight,
+                            estimate: formula.estimate(set.reps, set.weight)?,
+                        }),
+                        _ => None,
+                    })
+                    .max_by(|a, b| a.estimate.total_cmp(&b.estimate))
+            })
+            .collect();
+        history.sort_by_key(|estimate| (estimate.date, estimate.workout_id));
+
+        OneRepMaxHistory {
+            exercise,
+            formula,
+            history,
+        }
+    }
+
+    pub fn best(&self) -> Option<&OneRepMaxEstimate> {
+        self.history
+            .iter()
+            .max_by(|a, b| a.estimate.total_cmp(&b.estimate))
+    }
+
+    /// One-rep max equivalent of the exercise's goal, `None` unless the exercise is weighted.
+    pub fn goal_estimate(&self) -> Option<f32> {
+        match self.exercise.exercise_type {
+            ExerciseType::Weighted {
+                goal_weight,
+                goal_reps,
+                ..
+            } => self.formula.estimate(goal_reps, goal_weight),
+            _ => None,
+        }
+    }
+
+    /// Whether the best estimate reaches the goal, so the goal counts as reached without
+    /// testing it for real.
+    pub fn is_goal_reached(&self) -> bool {
+        match (self.best(), self.goal_estimate()) {
+            (Some(best), Some(goal)) => best.estimate >= goal,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::workout::{WeightedSet, WorkoutExercise};
+
+    fn squat(goal_weight: f32, goal_reps: u16) -> Exercise {
+        Exercise {
+            id: 1,
+            name: "Squat".to_string(),
+            exercise_type: ExerciseType::Weighted {
+                goal_weight,
+                goal_reps,
+                goal_sets: 1,
+            },
+        }
+    }
+
+    fn workout(id: u64, date: &str, sets: &[(u16, f32)]) -> Workout {
+        Workout {
+            id,
+            date: date.parse().unwrap(),
+            mood: None,
+            template_id: None,
+            exercises: vec![WorkoutExercise {
+                exercise: squat(100.0, 1),
+                sets: sets
+                    .iter()
+                    .map(|&(reps, weight)| {
+                        WorkoutSet::Weighted(WeightedSet {
+                            id: 0,
+                            reps,
+                            weight,
+                            failure: false,
+                        })
+                    })
+                    .collect(),
+            }],
+        }
+    }
+
+    #[test]
+    fn estimate_matches_known_values() {
+        let close = |formula: OneRepMaxFormula, expected: f32| {
+            let estimate = formula.estimate(5, 100.0).unwrap();
+            assert!(
+                (estimate - expected).abs() < 0.1,
+                "{formula:?}: {estimate} != {expected}"
+            );
+        };
+        close(OneRepMaxFormula::Epley, 116.7);
+        close(OneRepMaxFormula::Brzycki, 112.5);
+        close(OneRepMaxFormula::Lombardi, 117.5);
+        close(OneRepMaxFormula::OConner, 112.5);
+    }
+
+    #[test]
+    fn estimate_of_single_is_the_weight_for_every_formula() {
+        for formula in [
+            OneRepMaxFormula::Epley,
+            OneRepMaxFormula::Brzycki,
+            OneRepMaxFormula::Lombardi,
+            OneRepMaxFormula::Lander,
+            OneRepMaxFormula::Mayhew,
+            OneRepMaxFormula::OConner,
+            OneRepMaxFormula::Wathan,
+        ] {
+            assert_eq!(formula.estimate(1, 140.0), Some(140.0));
+        }
+    }
+
+    #[test]
+    fn estimate_is_none_where_formula_breaks_down() {
+        assert_eq!(OneRepMaxFormula::Brzycki.estimate(37, 50.0), None);
+        assert_eq!(OneRepMaxFormula::Epley.estimate(0, 50.0), None);
+    }
+
+    #[test]
+    fn history_keeps_best_set_of_each_workout_in_date_order() {
+        let workouts = [
+            workout(2, "2026-03-10", &[(3, 95.0)]),
+            workout(1, "2026-03-03", &[(5, 80.0), (1, 90.0), (8, 70.0)]),
+        ];
+
+        let history = OneRepMaxHistory::new(squat(100.0, 1), OneRepMaxFormula::Epley, &workouts);
+
+        let dates: Vec<_> = history.history.iter().map(|e| e.date.to_string()).collect();
+        assert_eq!(dates, ["2026-03-03", "2026-03-10"]);
+        assert_eq!(history.history[0].reps, 5);
+        assert_eq!(history.best().unwrap().workout_id, 2);
+    }
+
+    #[test]
+    fn is_goal_reached_compares_estimates_instead_of_true_singles() {
+        let workouts = [workout(1, "2026-03-03", &[(5, 90.0)])];
+
+        let reached = OneRepMaxHistory::new(squat(100.0, 1), OneRepMaxFormula::Epley, &workouts);
+        let missed = OneRepMaxHistory::new(squat(100.0, 3), OneRepMaxFormula::Epley, &workouts);
+
+        assert!(reached.is_goal_reached());
+        assert!(!missed.is_goal_reached());
+    }
+}
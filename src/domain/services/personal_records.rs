@@ -0,0 +1,210 @@
+use crate::domain::{
+    services::one_rep_max::OneRepMaxFormula,
+    types::{
+        personal_record::{NewPersonalRecord, RecordKind},
+        workout::{ExerciseHistorySet, WorkoutSet},
+    },
+};
+
+/// Walks the sets of `exercise_id`, oldest workout first, and returns a record each time a
+/// workout beat the best so far. The first value of a kind only sets the bar, there is nothing
+/// to beat yet.
+pub fn find_records(exercise_id: u64, history: &[ExerciseHistorySet]) -> Vec<NewPersonalRecord> {
+    let mut ordered: Vec<&ExerciseHistorySet> = history.iter().collect();
+    ordered.sort_by_key(|done| (done.date, done.workout_id));
+
+    let mut bests: Vec<(RecordKind, f32)> = Vec::new();
+    let mut records = Vec::new();
+    for workout in ordered.chunk_by(|a, b| a.workout_id == b.workout_id) {
+        let workout_id = workout[0].workout_id;
+        for (kind, value) in workout_bests(workout.iter().map(|done| &done.set)) {
+            match bests.iter_mut().find(|(best_kind, _)| *best_kind == kind) {
+                Some((_, best)) if value > *best => {
+                    records.push(NewPersonalRecord {
+                        workout_id,
+                        exercise_id,
+                        kind,
+                        value,
+                        previous: *best,
+                    });
+                    *best = value;
+                }
+                Some(_) => {}
+                None => bests.push((kind, value)),
+            }
+        }
+    }
+    records
+}
+
+/// Best value of every kind reached by the sets of one workout.
+fn workout_bests<'a>(sets: impl Iterator<Item = &'a WorkoutSet>) -> Vec<(RecordKind, f32)> {
+    let mut bests = Vec::new();
+    let mut volume = None;
+    for set in sets {
+        let work = match set {
+            WorkoutSet::Weighted(set) => {
+                let reps = f32::from(set.reps);
+                raise(
+                    &mut bests,
+                    RecordKind::HeaviestWeight { reps: set.reps },
+                    set.weight,
+                );
+                raise(
+                    &mut bests,
+                    RecordKind::MostReps {
+                        weight: Some(set.weight),
+                    },
+                    reps,
+                );
+                if let Some(estimate) = OneRepMaxFormula::default().estimate(set.reps, set.weight) {
+                    raise(&mut bests, RecordKind::BestOneRepMax, estimate);
+                }
+                set.weight * reps
+            }
+            WorkoutSet::BodyweightReps(set) => {
+                let reps = f32::from(set.reps);
+                raise(&mut bests, RecordKind::MostReps { weight: None }, reps);
+                reps
+            }
+            WorkoutSet::BodyweightTime(set) => {
+                let seconds = f32::from(set.duration_seconds);
+                raise(&mut bests, RecordKind::LongestHold, seconds);
+                seconds
+            }
+        };
+        volume = Some(volume.unwrap_or(0.0) + work);
+    }
+    if let Some(volume) = volume {
+        bests.push((RecordKind::SessionVolume, volume));
+    }
+    bests
+}
+
+fn raise(bests: &mut Vec<(RecordKind, f32)>, kind: RecordKind, value: f32) {
+    match bests.iter_mut().find(|(best_kind, _)| *best_kind == kind) {
+        Some((_, best)) => *best = best.max(value),
+        None => bests.push((kind, value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::workout::{BodyweightTimeSet, WeightedSet};
+
+    fn weighted(reps: u16, weight: f32) -> WorkoutSet {
+        WorkoutSet::Weighted(WeightedSet {
+            id: 0,
+            reps,
+            weight,
+            failure: false,
+        })
+    }
+
+    fn hold(duration_seconds: u16) -> WorkoutSet {
+        WorkoutSet::BodyweightTime(BodyweightTimeSet {
+            id: 0,
+            duration_seconds,
+            failure: false,
+        })
+    }
+
+    /// Sets of one exercise, given per workout as `(workout id, date, sets)`.
+    fn history<const N: usize>(
+        workouts: [(u64, &str, Vec<WorkoutSet>); N],
+    ) -> Vec<ExerciseHistorySet> {
+        workouts
+            .into_iter()
+            .flat_map(|(workout_id, date, sets)| {
+                let date = date.parse().unwrap();
+                sets.into_iter().map(move |set| ExerciseHistorySet {
+                    workout_id,
+                    date,
+                    set,
+                })
+            })
+            .collect()
+    }
+
+    fn kinds(records: &[NewPersonalRecord]) -> Vec<(u64, RecordKind)> {
+        records
+            .iter()
+            .map(|record| (record.workout_id, record.kind))
+            .collect()
+    }
+
+    #[test]
+    fn first_workout_sets_the_bar_without_records() {
+        let sets = history([(1, "2026-03-01", vec![weighted(5, 80.0)])]);
+
+        assert!(find_records(1, &sets).is_empty());
+    }
+
+    #[test]
+    fn heavier_set_for_the_same_reps_is_a_record() {
+        let sets = history([
+            (2, "2026-03-08", vec![weighted(5, 85.0), weighted(3, 85.0)]),
+            (1, "2026-03-01", vec![weighted(5, 80.0), weighted(3, 90.0)]),
+        ]);
+
+        let records = find_records(1, &sets);
+
+        let heaviest: Vec<&NewPersonalRecord> = records
+            .iter()
+            .filter(|record| matches!(record.kind, RecordKind::HeaviestWeight { .. }))
+            .collect();
+        assert_eq!(heaviest.len(), 1);
+        assert_eq!(heaviest[0].workout_id, 2);
+        assert_eq!(heaviest[0].kind, RecordKind::HeaviestWeight { reps: 5 });
+        assert_eq!((heaviest[0].value, heaviest[0].previous), (85.0, 80.0));
+    }
+
+    #[test]
+    fn more_reps_at_a_weight_beats_the_estimate_and_volume_too() {
+        let sets = history([
+            (1, "2026-03-01", vec![weighted(5, 80.0)]),
+            (2, "2026-03-08", vec![weighted(8, 80.0)]),
+        ]);
+
+        let records = find_records(1, &sets);
+
+        assert_eq!(
+            kinds(&records),
+            [
+                (2, RecordKind::MostReps { weight: Some(80.0) }),
+                (2, RecordKind::BestOneRepMax),
+                (2, RecordKind::SessionVolume),
+            ]
+        );
+        assert_eq!(records[2].value, 640.0);
+        assert_eq!(records[2].previous, 400.0);
+    }
+
+    #[test]
+    fn holds_beat_the_longest_hold_and_the_session_total() {
+        let sets = history([
+            (1, "2026-03-01", vec![hold(40)]),
+            (2, "2026-03-08", vec![hold(45), hold(30)]),
+        ]);
+
+        let records = find_records(2, &sets);
+
+        assert_eq!(
+            kinds(&records),
+            [(2, RecordKind::LongestHold), (2, RecordKind::SessionVolume)]
+        );
+        assert_eq!(records[1].exercise_id, 2);
+        assert_eq!(records[1].value, 75.0);
+    }
+
+    #[test]
+    fn equal_value_is_not_a_record() {
+        let sets = history([
+            (1, "2026-03-01", vec![weighted(5, 80.0)]),
+            (2, "2026-03-08", vec![weighted(5, 80.0)]),
+        ]);
+
+        assert!(find_records(1, &sets).is_empty());
+    }
+}
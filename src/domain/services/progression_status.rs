@@ -0,0 +1,167 @@
+use chrono::NaiveDate;
+
+use crate::domain::types::{
+    exercise::{Exercise, ExerciseProgression},
+    workout::Workout,
+};
+
+/// Where the user stands in a progression, worked out from the logged workouts.
+#[derive(Debug)]
+pub struct ProgressionStatus {
+    pub name: String,
+    pub steps: Vec<StepStatus>,
+    /// Index of the step to work on, `None` once the final step is complete
+    pub current_step: Option<usize>,
+}
+
+#[derive(Debug)]
+pub struct StepStatus {
+    pub exercise: Exercise,
+    /// Date of the first workout whose sets met the step's goal
+    pub completed_on: Option<NaiveDate>,
+}
+
+impl ProgressionStatus {
+    /// A step is complete once a single workout meets its goal. The current step is the one
+    /// following the hardest complete step, so skipping ahead leaves easier steps behind.
+    pub fn new(progression: ExerciseProgression, workouts: &[Workout]) -> Self {
+        let steps: Vec<StepStatus> = progression
+            .progression
+            .into_iter()
+            .map(|exercise| StepStatus {
+                completed_on: first_workout_meeting_goal(&exercise, workouts),
+                exercise,
+            })
+            .collect();
+
+        let next_step = steps
+            .iter()
+            .rposition(|step| step.completed_on.is_some())
+            .map_or(0, |last_completed| last_completed + 1);
+        let current_step = (next_step < steps.len()).then_some(next_step);
+
+        ProgressionStatus {
+            name: progression.name,
+            steps,
+            current_step,
+        }
+    }
+
+    pub fn current(&self) -> Option<&Exercise> {
+        self.current_step.map(|index| &self.steps[index].exercise)
+    }
+}
+
+fn first_workout_meeting_goal(exercise: &Exercise, workouts: &[Workout]) -> Option<NaiveDate> {
+    workouts
+        .iter()
+        .filter(|workout| {
+            let sets = workout
+                .exercises
+                .iter()
+                .filter(|done| done.exercise.id == exercise.id)
+                .flat_map(|done| &done.sets);
+            exercise.exercise_type.is_goal_met(sets)
+        })
+        .map(|workout| workout.date)
+        .min()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::{
+        exercise::ExerciseType,
+        workout::{BodyweightRepSet, WorkoutExercise, WorkoutSet},
+    };
+
+    fn step(id: u64, name: &str, goal_reps: u16) -> Exercise {
+        Exercise {
+            id,
+            name: name.to_string(),
+            exercise_type: ExerciseType::BodyweightReps {
+                goal_reps,
+                goal_sets: 2,
+            },
+        }
+    }
+
+    fn pushup_progression() -> ExerciseProgression {
+        ExerciseProgression {
+            name: "Handstand pushup".to_string(),
+            progression: vec![
+                step(1, "Pike pushup", 15),
+                step(2, "Wall pushup", 5),
+                step(3, "Handstand pushup", 3),
+            ],
+        }
+    }
+
+    fn workout(date: &str, exercise: Exercise, reps: &[u16]) -> Workout {
+        Workout {
+            id: 0,
+            date: date.parse().unwrap(),
+            mood: None,
+            template_id: None,
+            exercises: vec![WorkoutExercise {
+                exercise,
+                sets: reps
+                    .iter()
+                    .map(|&reps| {
+                        WorkoutSet::BodyweightReps(BodyweightRepSet {
+                            id: 0,
+                            reps,
+                            failure: false,
+                        })
+                    })
+                    .collect(),
+            }],
+        }
+    }
+
+    #[test]
+    fn new_starts_at_first_step_without_workouts() {
+        let status = ProgressionStatus::new(pushup_progression(), &[]);
+
+        assert_eq!(status.current_step, Some(0));
+        assert!(status.steps.iter().all(|step| step.completed_on.is_none()));
+    }
+
+    #[test]
+    fn new_completes_step_on_first_workout_meeting_goal() {
+        let workouts = [
+            workout("2026-03-10", step(1, "Pike pushup", 15), &[15, 16]),
+            workout("2026-03-03", step(1, "Pike pushup", 15), &[15, 12]),
+            workout("2026-03-07", step(1, "Pike pushup", 15), &[15, 15]),
+        ];
+
+        let status = ProgressionStatus::new(pushup_progression(), &workouts);
+
+        assert_eq!(status.steps[0].completed_on, "2026-03-07".parse().ok());
+        assert_eq!(status.current_step, Some(1));
+        assert_eq!(status.current().unwrap().name, "Wall pushup");
+    }
+
+    #[test]
+    fn new_moves_past_skipped_steps() {
+        let workouts = [workout("2026-03-03", step(2, "Wall pushup", 5), &[6, 5])];
+
+        let status = ProgressionStatus::new(pushup_progression(), &workouts);
+
+        assert_eq!(status.current_step, Some(2));
+    }
+
+    #[test]
+    fn new_has_no_current_step_once_final_step_is_complete() {
+        let workouts = [workout(
+            "2026-03-03",
+            step(3, "Handstand pushup", 3),
+            &[3, 3],
+        )];
+
+        let status = ProgressionStatus::new(pushup_progression(), &workouts);
+
+        assert_eq!(status.current_step, None);
+        assert!(status.current().is_none());
+    }
+}
@@ -0,0 +1,563 @@
+use chrono::{Datelike, Days, Months, NaiveDate};
+use serde::Deserialize;
+
+use crate::domain::types::{
+    exercise::Exercise,
+    workout::{Workout, WorkoutSet},
+};
+
+/// How many periods the stats cover when no start date is given.
+const DEFAULT_PERIODS: u32 = 12;
+/// Most periods the stats may cover, five years of weeks.
+pub const MAX_PERIODS: usize = 260;
+
+/// Length of the periods training stats are grouped by.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StatsGrouping {
+    /// Weeks starting on Monday
+    #[default]
+    Week,
+    Month,
+}
+
+impl StatsGrouping {
+    /// First day of the period containing `date`.
+    pub fn period_start(self, date: NaiveDate) -> NaiveDate {
+        match self {
+            StatsGrouping::Week => {
+                date - Days::new(u64::from(date.weekday().num_days_from_monday()))
+            }
+            StatsGrouping::Month => date.with_day(1).unwrap_or(date),
+        }
+    }
+
+    /// First day of the period following the one starting on `start`.
+    fn next_period(self, start: NaiveDate) -> Option<NaiveDate> {
+        match self {
+            StatsGrouping::Week => start.checked_add_days(Days::new(7)),
+            StatsGrouping::Month => start.checked_add_months(Months::new(1)),
+        }
+    }
+
+    /// Start of the last 12 periods, the final one being the period containing `to`.
+    pub fn default_from(self, to: NaiveDate) -> NaiveDate {
+        let start = self.period_start(to);
+        let earlier = match self {
+            StatsGrouping::Week => {
+                start.checked_sub_days(Days::new(7 * u64::from(DEFAULT_PERIODS - 1)))
+            }
+            StatsGrouping::Month => start.checked_sub_months(Months::new(DEFAULT_PERIODS - 1)),
+        };
+        earlier.unwrap_or(start)
+    }
+
+    /// Checks that `from` is not after `to` and that the dates span at most `MAX_PERIODS`
+    /// periods, so the stats stay cheap to build and to draw.
+    pub fn check_range(self, from: NaiveDate, to: NaiveDate) -> Result<(), String> {
+        if from > to {
+            return Err("The start date must not be after the end date".to_string());
+        }
+        if self.period_index(from, to) >= MAX_PERIODS {
+            let unit = match self {
+                StatsGrouping::Week => "weeks",
+                StatsGrouping::Month => "months",
+            };
+            return Err(format!("The dates must span at most {MAX_PERIODS} {unit}"));
+        }
+        Ok(())
+    }
+
+    /// Index of the period containing `date` among the periods returned by `periods(from, ..)`.
+    pub fn period_index(self, from: NaiveDate, date: NaiveDate) -> usize {
+        let periods = match self {
+            StatsGrouping::Week => {
+                (self.period_start(date) - self.period_start(from)).num_days() / 7
+            }
+            StatsGrouping::Month => {
+                let months =
+                    |date: NaiveDate| i64::from(date.year()) * 12 + i64::from(date.month0());
+                months(date) - months(from)
+            }
+        };
+        usize::try_from(periods).unwrap_or(0)
+    }
+
+    /// First and last day of every period overlapping `from`..=`to`, oldest first. The first
+    /// and last periods are cut short to stay within the dates.
+    pub fn periods(self, from: NaiveDate, to: NaiveDate) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut periods = Vec::new();
+        let mut start = Some(self.period_start(from));
+        while let Some(period_start) = start.filter(|start| *start <= to) {
+            let next = self.next_period(period_start);
+            let end = next
+                .and_then(|next| next.pred_opt())
+                .map_or(to, |end| end.min(to));
+            periods.push((period_start.max(from), end));
+            start = next;
+        }
+        periods
+    }
+}
+
+/// Work done over a set of workouts, split by kind of set since kilograms, reps and seconds
+/// don't add up.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct TrainingVolume {
+    /// Reps × weight of the weighted sets, in kg
+    pub tonnage: f32,
+    /// Reps of the bodyweight rep sets
+    pub bodyweight_reps: u32,
+    /// Seconds held during the bodyweight time sets
+    pub time_under_tension_seconds: u32,
+    pub sets: u32,
+    pub failure_sets: u32,
+}
+
+impl TrainingVolume {
+    fn add_set(&mut self, set: &WorkoutSet) {
+        match set {
+            WorkoutSet::Weighted(set) => self.tonnage += f32::from(set.reps) * set.weight,
+            WorkoutSet::BodyweightReps(set) => self.bodyweight_reps += u32::from(set.reps),
+            WorkoutSet::BodyweightTime(set) => {
+                self.time_under_tension_seconds += u32::from(set.duration_seconds)
+            }
+        }
+        self.sets += 1;
+        if set.is_failure() {
+            self.failure_sets += 1;
+        }
+    }
+
+    fn add_workout(&mut self, workout: &Workout) {
+        for set in workout.exercises.iter().flat_map(|done| &done.sets) {
+            self.add_set(set);
+        }
+    }
+}
+
+/// Running average of the moods of the workouts that have one.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MoodAverage {
+    total: u32,
+    count: u32,
+}
+
+impl MoodAverage {
+    pub fn add(&mut self, mood: Option<u8>) {
+        if let Some(mood) = mood {
+            self.total += u32::from(mood);
+            self.count += 1;
+        }
+    }
+
+    pub fn get(self) -> Option<f32> {
+        (self.count > 0).then(|| self.total as f32 / self.count as f32)
+    }
+}
+
+#[derive(Debug)]
+pub struct PeriodStats {
+    pub start: NaiveDate,
+    /// Last day of the period, cut short by the end of the stats
+    pub end: NaiveDate,
+    pub workouts: u32,
+    pub volume: TrainingVolume,
+    /// Average mood of the workouts that recorded one, `None` if none did
+    pub average_mood: Option<f32>,
+}
+
+#[derive(Debug)]
+pub struct ExerciseStats {
+    pub exercise: Exercise,
+    /// Number of workouts the exercise was done in
+    pub workouts: u32,
+    pub volume: TrainingVolume,
+}
+
+/// Training volume and mood from `from` to `to`, both included, overall, per period and per
+/// exercise.
+#[derive(Debug)]
+pub struct TrainingStats {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub group_by: StatsGrouping,
+    pub workouts: u32,
+    pub volume: TrainingVolume,
+    pub average_mood: Option<f32>,
+    /// Every period overlapping the dates, oldest first, including the ones without workouts
+    pub periods: Vec<PeriodStats>,
+    /// Every exercise done, by name
+    pub exercises: Vec<ExerciseStats>,
+}
+
+impl TrainingStats {
+    /// Workouts dated outside of `from`..=`to` are left out.
+    pub fn new(
+        from: NaiveDate,
+        to: NaiveDate,
+        group_by: StatsGrouping,
+        workouts: &[Workout],
+    ) -> Self {
+        let workouts: Vec<&Workout> = workouts
+            .iter()
+            .filter(|workout| (from..=to).contains(&workout.date))
+            .collect();
+
+        let mut volume = TrainingVolume::default();
+        let mut mood = MoodAverage::default();
+        for workout in &workouts {
+            volume.add_workout(workout);
+            mood.add(workout.mood);
+        }
+
+        TrainingStats {
+            from,
+            to,
+            group_by,
+            workouts: workouts.len() as u32,
+            volume,
+            average_mood: mood.get(),
+            periods: period_stats(from, to, group_by, &workouts),
+            exercises: exercise_stats(&workouts),
+        }
+    }
+}
+
+/// Buckets the workouts into their period in a single pass over them.
+fn period_stats(
+    from: NaiveDate,
+    to: NaiveDate,
+    group_by: StatsGrouping,
+    workouts: &[&Workout],
+) -> Vec<PeriodStats> {
+    let mut periods: Vec<(PeriodStats, MoodAverage)> = group_by
+        .periods(from, to)
+        .into_iter()
+        .map(|(start, end)| {
+            let stats = PeriodStats {
+                start,
+                end,
+                workouts: 0,
+                volume: TrainingVolume::default(),
+                average_mood: None,
+            };
+            (stats, MoodAverage::default())
+        })
+        .collect();
+    for workout in workouts {
+        if let Some((stats, mood)) = periods.get_mut(group_by.period_index(from, workout.date)) {
+            stats.volume.add_workout(workout);
+            stats.workouts += 1;
+            mood.add(workout.mood);
+        }
+    }
+    periods
+        .into_iter()
+        .map(|(stats, mood)| PeriodStats {
+            average_mood: mood.get(),
+            ..stats
+        })
+        .collect()
+}
+
+fn exercise_stats(workouts: &[&Workout]) -> Vec<ExerciseStats> {
+    let mut exercises: Vec<ExerciseStats> = Vec::new();
+    for workout in workouts {
+        let mut seen = Vec::new();
+        for done in &workout.exercises {
+            let index = match exercises
+                .iter()
+                .position(|stats| stats.exercise.id == done.exercise.id)
+            {
+                Some(index) => index,
+                None => {
+                    exercises.push(ExerciseStats {
+                        exercise: done.exercise.clone(),
+                        workouts: 0,
+                        volume: TrainingVolume::default(),
+                    });
+                    exercises.len() - 1
+                }
+            };
+            let stats = &mut exercises[index];
+            if !seen.contains(&done.exercise.id) {
+                seen.push(done.exercise.id);
+                stats.workouts += 1;
+            }
+            for set in &done.sets {
+                stats.volume.add_set(set);
+            }
+        }
+    }
+    exercises.sort_by(|a, b| a.exercise.name.cmp(&b.exercise.name));
+    exercises
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::{
+        exercise::ExerciseType,
+        workout::{BodyweightRepSet, BodyweightTimeSet, WeightedSet, WorkoutExercise},
+    };
+
+    fn squat() -> Exercise {
+        Exercise {
+            id: 1,
+            name: "Squat".to_string(),
+            exercise_type: ExerciseType::Weighted {
+                goal_weight: 100.0,
+                goal_reps: 5,
+                goal_sets: 3,
+            },
+        }
+    }
+
+    fn pull_up() -> Exercise {
+        Exercise {
+            id: 2,
+            name: "Pull-up".to_string(),
+            exercise_type: ExerciseType::BodyweightReps {
+                goal_reps: 10,
+                goal_sets: 3,
+            },
+        }
+    }
+
+    fn plank() -> Exercise {
+        Exercise {
+            id: 3,
+            name: "Plank".to_string(),
+            exercise_type: ExerciseType::BodyweightTime {
+                goal_duration_seconds: 60,
+                goal_sets: 1,
+            },
+        }
+    }
+
+    fn weighted(reps: u16, weight: f32, failure: bool) -> WorkoutSet {
+        WorkoutSet::Weighted(WeightedSet {
+            id: 0,
+            reps,
+            weight,
+            failure,
+        })
+    }
+
+    fn reps(reps: u16, failure: bool) -> WorkoutSet {
+        WorkoutSet::BodyweightReps(BodyweightRepSet {
+            id: 0,
+            reps,
+            failure,
+        })
+    }
+
+    fn hold(duration_seconds: u16) -> WorkoutSet {
+        WorkoutSet::BodyweightTime(BodyweightTimeSet {
+            id: 0,
+            duration_seconds,
+            failure: false,
+        })
+    }
+
+    fn workout(
+        id: u64,
+        date: &str,
+        mood: Option<u8>,
+        exercises: Vec<(Exercise, Vec<WorkoutSet>)>,
+    ) -> Workout {
+        Workout {
+            id,
+            date: date.parse().unwrap(),
+            mood,
+            template_id: None,
+            exercises: exercises
+                .into_iter()
+                .map(|(exercise, sets)| WorkoutExercise { exercise, sets })
+                .collect(),
+        }
+    }
+
+    fn date(date: &str) -> NaiveDate {
+        date.parse().unwrap()
+    }
+
+    #[test]
+    fn volume_adds_up_each_kind_of_set_separately() {
+        let workouts = [workout(
+            1,
+            "2026-03-03",
+            Some(7),
+            vec![
+                (
+                    squat(),
+                    vec![weighted(5, 100.0, false), weighted(3, 100.0, true)],
+                ),
+                (pull_up(), vec![reps(8, false), reps(6, true)]),
+                (plank(), vec![hold(60), hold(45)]),
+            ],
+        )];
+
+        let stats = TrainingStats::new(
+            date("2026-03-01"),
+            date("2026-03-31"),
+            StatsGrouping::Month,
+            &workouts,
+        );
+
+        assert_eq!(
+            stats.volume,
+            TrainingVolume {
+                tonnage: 800.0,
+                bodyweight_reps: 14,
+                time_under_tension_seconds: 105,
+                sets: 6,
+                failure_sets: 2,
+            }
+        );
+        assert_eq!(stats.average_mood, Some(7.0));
+    }
+
+    #[test]
+    fn weeks_start_on_monday_and_include_empty_ones() {
+        let workouts = [
+            workout(
+                1,
+                "2026-03-02",
+                Some(6),
+                vec![(squat(), vec![weighted(5, 80.0, false)])],
+            ),
+            workout(
+                2,
+                "2026-03-08",
+                None,
+                vec![(squat(), vec![weighted(5, 90.0, false)])],
+            ),
+            workout(
+                3,
+                "2026-03-18",
+                Some(9),
+                vec![(squat(), vec![weighted(5, 100.0, false)])],
+            ),
+        ];
+
+        let stats = TrainingStats::new(
+            date("2026-03-04"),
+            date("2026-03-18"),
+            StatsGrouping::Week,
+            &workouts,
+        );
+
+        let periods: Vec<(String, String, f32)> = stats
+            .periods
+            .iter()
+            .map(|period| {
+                (
+                    period.start.to_string(),
+                    period.end.to_string(),
+                    period.volume.tonnage,
+                )
+            })
+            .collect();
+        assert_eq!(
+            periods,
+            [
+                ("2026-03-04".to_string(), "2026-03-08".to_string(), 450.0),
+                ("2026-03-09".to_string(), "2026-03-15".to_string(), 0.0),
+                ("2026-03-16".to_string(), "2026-03-18".to_string(), 500.0),
+            ]
+        );
+        assert_eq!(stats.workouts, 2);
+        assert_eq!(stats.periods[0].average_mood, None);
+        assert_eq!(stats.periods[1].workouts, 0);
+        assert_eq!(stats.average_mood, Some(9.0));
+    }
+
+    #[test]
+    fn exercise_stats_count_each_workout_once() {
+        let workouts = [
+            workout(
+                1,
+                "2026-03-02",
+                None,
+                vec![
+                    (squat(), vec![weighted(5, 80.0, false)]),
+                    (pull_up(), vec![reps(10, false)]),
+                    (squat(), vec![weighted(3, 90.0, true)]),
+                ],
+            ),
+            workout(
+                2,
+                "2026-03-05",
+                None,
+                vec![(squat(), vec![weighted(5, 85.0, false)])],
+            ),
+        ];
+
+        let stats = TrainingStats::new(
+            date("2026-03-01"),
+            date("2026-03-31"),
+            StatsGrouping::Month,
+            &workouts,
+        );
+
+        let names: Vec<&str> = stats
+            .exercises
+            .iter()
+            .map(|stats| stats.exercise.name.as_str())
+            .collect();
+        assert_eq!(names, ["Pull-up", "Squat"]);
+        assert_eq!(stats.exercises[1].workouts, 2);
+        assert_eq!(stats.exercises[1].volume.tonnage, 1095.0);
+        assert_eq!(stats.exercises[1].volume.failure_sets, 1);
+    }
+
+    #[test]
+    fn default_from_covers_twelve_periods() {
+        assert_eq!(
+            StatsGrouping::Week.default_from(date("2026-03-18")),
+            date("2025-12-29")
+        );
+        assert_eq!(
+            StatsGrouping::Month.default_from(date("2026-03-18")),
+            date("2025-04-01")
+        );
+    }
+
+    #[test]
+    fn period_index_counts_periods_from_the_start() {
+        let from = date("2026-01-28");
+        let index = |group_by: StatsGrouping, day| group_by.period_index(from, date(day));
+
+        assert_eq!(index(StatsGrouping::Week, "2026-02-01"), 0);
+        assert_eq!(index(StatsGrouping::Week, "2026-02-02"), 1);
+        assert_eq!(index(StatsGrouping::Month, "2026-01-31"), 0);
+        assert_eq!(index(StatsGrouping::Month, "2027-03-01"), 14);
+        let periods = StatsGrouping::Week.periods(from, date("2026-03-31"));
+        assert_eq!(index(StatsGrouping::Week, "2026-03-31"), periods.len() - 1);
+    }
+
+    #[test]
+    fn check_range_rejects_reversed_and_overlong_ranges() {
+        assert!(
+            StatsGrouping::Week
+                .check_range(date("2026-03-02"), date("2026-03-01"))
+                .is_err()
+        );
+        assert!(
+            StatsGrouping::Week
+                .check_range(date("2021-03-08"), date("2026-03-01"))
+                .is_ok()
+        );
+        assert_eq!(
+            StatsGrouping::Week.check_range(date("2021-03-01"), date("2026-03-01")),
+            Err("The dates must span at most 260 weeks".to_string())
+        );
+        assert!(
+            StatsGrouping::Month
+                .check_range(date("2010-01-01"), date("2026-03-01"))
+                .is_ok()
+        );
+    }
+}
@@ -1,16 +1,23 @@
-use crate::domain::types::exercise::{Exercise, ExerciseProgression};
+use crate::domain::types::exercise::{Exercise, ExerciseChange, ExerciseProgression};
 
 #[derive(Debug)]
 pub enum ExerciseModelError {
     NotFound,
+    /// An exercise with the same name already exists
+    AlreadyExists,
     DatabaseError(String),
 }
 
 #[async_trait::async_trait]
 pub trait ExerciseModel: Send + Sync {
     async fn get_exercise_by_id(&self, id: u64) -> Result<Exercise, ExerciseModelError>;
+    /// Looks an exercise up by name, ignoring case.
+    async fn get_exercise_by_name(&self, name: &str) -> Result<Exercise, ExerciseModelError>;
     async fn get_all_exercises(&self) -> Result<Vec<Exercise>, ExerciseModelError>;
-    async fn add_exercise(&mut self, exercise: Exercise) -> Result<(), ExerciseModelError>;
+    /// Loads every exercise in `ids` at once, skipping ids without an exercise.
+    async fn get_exercises_by_ids(&self, ids: &[u64]) -> Result<Vec<Exercise>, ExerciseModelError>;
+    /// Inserts `exercise`, ignoring its `id`, and returns the id it was stored under.
+    async fn add_exercise(&mut self, exercise: Exercise) -> Result<u64, ExerciseModelError>;
     async fn update_exercise(&mut self, exercise: Exercise) -> Result<(), ExerciseModelError>;
     async fn delete_exercise(&mut self, id: u64) -> Result<(), ExerciseModelError>;
 
@@ -29,6 +36,8 @@ pub trait ExerciseModel: Send + Sync {
         &mut self,
         progression: ExerciseProgression,
     ) -> Result<(), ExerciseModelError>;
+    /// Replaces the steps of the progression called `progression.name`. Steps with an `id` of 0
+    /// are created, and exercises no longer listed are detached from the progression.
     async fn update_exercise_progression(
         &mut self,
         progression: ExerciseProgression,
@@ -37,4 +46,9 @@ pub trait ExerciseModel: Send + Sync {
         &mut self,
         progression: ExerciseProgression,
     ) -> Result<(), ExerciseModelError>;
+    /// Applies the changes in order as a whole: when one of them fails, none is kept.
+    async fn save_exercise_changes(
+        &mut self,
+        changes: Vec<ExerciseChange>,
+    ) -> Result<(), ExerciseModelError>;
 }
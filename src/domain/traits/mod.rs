@@ -1,3 +1,4 @@
 pub mod exercise_model;
+pub mod personal_record_model;
 pub mod workout_model;
 pub mod workout_template_model;
@@ -0,0 +1,22 @@
+use crate::domain::types::personal_record::PersonalRecord;
+
+#[derive(Debug)]
+pub enum PersonalRecordModelError {
+    DatabaseError(String),
+}
+
+/// Reads the records kept up to date by `WorkoutModel` whenever a workout is saved.
+#[async_trait::async_trait]
+pub trait PersonalRecordModel: Send + Sync {
+    /// Up to `limit` records, newest workout first, skipping the `offset` newest ones.
+    async fn get_latest_records(
+        &self,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<PersonalRecord>, PersonalRecordModelError>;
+    /// Records set during the workout, in no particular order.
+    async fn get_workout_records(
+        &self,
+        workout_id: u64,
+    ) -> Result<Vec<PersonalRecord>, PersonalRecordModelError>;
+}
@@ -1,4 +1,6 @@
-use crate::domain::types::workout::{NewWorkout, Workout};
+use chrono::NaiveDate;
+
+use crate::domain::types::workout::{ExerciseHistorySet, NewWorkout, Workout, WorkoutSet};
 
 #[derive(Debug)]
 pub enum WorkoutModelError {
@@ -8,9 +10,48 @@ pub enum WorkoutModelError {
 
 #[async_trait::async_trait]
 pub trait WorkoutModel: Send + Sync {
+    /// Creating, updating or deleting a workout also recomputes the personal records of its
+    /// exercises, so the workout and its records are saved or rolled back together. The
+    /// recompute reads every set of those exercises, so a save gets slower as their history
+    /// grows.
     async fn create_workout(&mut self, workout: NewWorkout) -> Result<u64, WorkoutModelError>;
     async fn get_workout(&self, workout_id: u64) -> Result<Workout, WorkoutModelError>;
     async fn get_all_workouts(&self) -> Result<Vec<Workout>, WorkoutModelError>;
+    /// Up to `limit` workouts, newest first, skipping the `offset` newest ones.
+    async fn get_latest_workouts(
+        &self,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<Workout>, WorkoutModelError>;
+    /// Workouts dated from `from` to `to`, both included, oldest first.
+    async fn get_workouts_between(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<Vec<Workout>, WorkoutModelError>;
+    /// Workouts in which at least one of the exercises was done, oldest first.
+    async fn get_workouts_with_exercises(
+        &self,
+        exercise_ids: &[u64],
+    ) -> Result<Vec<Workout>, WorkoutModelError>;
+    /// Every set logged for the exercise, newest workout first and in the order they were done
+    /// within a workout. Returns up to `limit` sets, skipping the first `offset`.
+    async fn get_exercise_history(
+        &self,
+        exercise_id: u64,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<ExerciseHistorySet>, WorkoutModelError>;
+    /// Sets logged for the exercise in the latest workout dated before `before`, in the order
+    /// they were done. Empty when the exercise was never done before that date.
+    async fn get_last_sets_for_exercise(
+        &self,
+        exercise_id: u64,
+        before: NaiveDate,
+    ) -> Result<Vec<WorkoutSet>, WorkoutModelError>;
     async fn update_workout(&mut self, workout: Workout) -> Result<(), WorkoutModelError>;
     async fn delete_workout(&mut self, workout_id: u64) -> Result<(), WorkoutModelError>;
+    /// Recomputes the personal records of every exercise, for workouts logged before records
+    /// were detected.
+    async fn refresh_all_records(&mut self) -> Result<(), WorkoutModelError>;
 }
@@ -3,6 +3,8 @@ use crate::domain::types::workout_template::{NewWorkoutTemplate, WorkoutTemplate
 #[derive(Debug)]
 pub enum WorkoutTemplateModelError {
     NotFound,
+    /// A template with the same name already exists
+    AlreadyExists,
     DatabaseError(String),
 }
 
@@ -19,6 +21,7 @@ pub trait WorkoutTemplateModel: Send + Sync {
     async fn get_all_workout_templates(
         &self,
     ) -> Result<Vec<WorkoutTemplate>, WorkoutTemplateModelError>;
+    /// Renames the template and replaces its exercises, keeping the order of `template.exercises`.
     async fn update_workout_template(
         &mut self,
         template: WorkoutTemplate,
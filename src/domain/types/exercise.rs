@@ -1,21 +1,157 @@
+use crate::domain::types::workout::WorkoutSet;
+
+#[derive(Debug, Clone, PartialEq)]
 pub struct ExerciseProgression {
     pub name: String,
     pub progression: Vec<Exercise>,
 }
 
+#[derive(Debug, Clone, PartialEq)]
 pub struct Exercise {
     pub id: u64,
     pub name: String,
     pub exercise_type: ExerciseType,
 }
 
+/// One write of a batch saved with `ExerciseModel::save_exercise_changes`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExerciseChange {
+    /// Inserts the exercise, ignoring its `id`
+    Add(Exercise),
+    /// Updates the name and goal of the exercise with this `id`
+    Update(Exercise),
+    /// Saves the progression like `ExerciseModel::update_exercise_progression`. A step with an
+    /// `id` of 0 named like an exercise added earlier in the batch reuses that exercise.
+    SaveProgression(ExerciseProgression),
+}
+
 pub struct NewExercise {
     pub name: String,
     pub exercise_type: ExerciseType,
 }
 
+/// The kind of exercise along with the goal to reach: `goal_sets` sets of the given target.
+#[derive(Debug, Clone, PartialEq)]
 pub enum ExerciseType {
-    Weighted { goal_weight: f32 },
-    BodyweightReps { goal_reps: u16 },
-    BodyweightTime { goal_duration_seconds: u16 },
+    Weighted {
+        goal_weight: f32,
+        goal_reps: u16,
+        goal_sets: u16,
+    },
+    BodyweightReps {
+        goal_reps: u16,
+        goal_sets: u16,
+    },
+    BodyweightTime {
+        goal_duration_seconds: u16,
+        goal_sets: u16,
+    },
+}
+
+impl ExerciseType {
+    pub fn goal_sets(&self) -> u16 {
+        match self {
+            ExerciseType::Weighted { goal_sets, .. }
+            | ExerciseType::BodyweightReps { goal_sets, .. }
+            | ExerciseType::BodyweightTime { goal_sets, .. } => *goal_sets,
+        }
+    }
+
+    /// Whether a single set reaches the goal's target. Sets of another kind never do.
+    pub fn is_qualifying_set(&self, set: &WorkoutSet) -> bool {
+        match (self, set) {
+            (
+                ExerciseType::Weighted {
+                    goal_weight,
+                    goal_reps,
+                    ..
+                },
+                WorkoutSet::Weighted(set),
+            ) => set.weight >= *goal_weight && set.reps >= *goal_reps,
+            (ExerciseType::BodyweightReps { goal_reps, .. }, WorkoutSet::BodyweightReps(set)) => {
+                set.reps >= *goal_reps
+            }
+            (
+                ExerciseType::BodyweightTime {
+                    goal_duration_seconds,
+                    ..
+                },
+                WorkoutSet::BodyweightTime(set),
+            ) => set.duration_seconds >= *goal_duration_seconds,
+            _ => false,
+        }
+    }
+
+    /// Whether the sets logged for this exercise in one workout reach the goal, that is at
+    /// least `goal_sets` of them hit the target.
+    pub fn is_goal_met<'a>(&self, sets: impl IntoIterator<Item = &'a WorkoutSet>) -> bool {
+        let qualifying_sets = sets
+            .into_iter()
+            .filter(|set| self.is_qualifying_set(set))
+            .count();
+        qualifying_sets >= self.goal_sets() as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::workout::{BodyweightRepSet, BodyweightTimeSet, WeightedSet};
+
+    fn weighted(reps: u16, weight: f32) -> WorkoutSet {
+        WorkoutSet::Weighted(WeightedSet {
+            id: 0,
+            reps,
+            weight,
+            failure: false,
+        })
+    }
+
+    fn reps(reps: u16) -> WorkoutSet {
+        WorkoutSet::BodyweightReps(BodyweightRepSet {
+            id: 0,
+            reps,
+            failure: false,
+        })
+    }
+
+    #[test]
+    fn is_goal_met_needs_enough_qualifying_sets() {
+        let wall_pushup = ExerciseType::BodyweightReps {
+            goal_reps: 5,
+            goal_sets: 3,
+        };
+
+        assert!(!wall_pushup.is_goal_met(&[reps(5), reps(6), reps(4)]));
+        assert!(wall_pushup.is_goal_met(&[reps(5), reps(6), reps(4), reps(5)]));
+    }
+
+    #[test]
+    fn is_goal_met_checks_weight_and_reps_of_weighted_sets() {
+        let squat = ExerciseType::Weighted {
+            goal_weight: 100.0,
+            goal_reps: 5,
+            goal_sets: 2,
+        };
+
+        assert!(!squat.is_goal_met(&[weighted(5, 100.0), weighted(3, 110.0)]));
+        assert!(squat.is_goal_met(&[weighted(5, 100.0), weighted(6, 102.5)]));
+    }
+
+    #[test]
+    fn is_goal_met_ignores_sets_of_another_kind() {
+        let handstand = ExerciseType::BodyweightTime {
+            goal_duration_seconds: 30,
+            goal_sets: 1,
+        };
+
+        assert!(!handstand.is_goal_met(&[reps(60)]));
+        assert!(
+            handstand.is_goal_met(&[WorkoutSet::BodyweightTime(BodyweightTimeSet {
+                id: 0,
+                duration_seconds: 31,
+                failure: true,
+            })])
+        );
+    }
 }
@@ -1,3 +1,4 @@
 pub mod exercise;
+pub mod personal_record;
 pub mod workout;
 pub mod workout_template;
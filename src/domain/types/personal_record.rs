@@ -0,0 +1,40 @@
+use chrono::NaiveDate;
+
+use crate::domain::types::exercise::Exercise;
+
+/// What a personal record beat. Records compare like with like, so the heaviest weight is kept
+/// per rep count and the most reps per weight.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RecordKind {
+    /// Heaviest weight lifted for exactly `reps` reps
+    HeaviestWeight { reps: u16 },
+    /// Most reps done in one set at `weight`, `None` for bodyweight exercises
+    MostReps { weight: Option<f32> },
+    /// Longest set of a timed exercise, in seconds
+    LongestHold,
+    /// Best one-rep max estimated from a single set
+    BestOneRepMax,
+    /// Most work done in one workout: weight × reps, reps or seconds depending on the sets
+    SessionVolume,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PersonalRecord {
+    pub id: u64,
+    pub workout_id: u64,
+    pub date: NaiveDate,
+    pub exercise: Exercise,
+    pub kind: RecordKind,
+    pub value: f32,
+    /// Best value before this record
+    pub previous: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewPersonalRecord {
+    pub workout_id: u64,
+    pub exercise_id: u64,
+    pub kind: RecordKind,
+    pub value: f32,
+    pub previous: f32,
+}
@@ -1,12 +1,14 @@
 use chrono::NaiveDate;
 
-use crate::domain::types::exercise::Exercise;
+use crate::domain::types::exercise::{Exercise, ExerciseType};
 
 pub struct Workout {
     pub id: u64,
     pub date: NaiveDate,
     /// Mood on a scale of 1-10, where 1 is very bad and 10 is very good
     pub mood: Option<u8>,
+    /// Template the workout was started from, if any
+    pub template_id: Option<u64>,
     pub exercises: Vec<WorkoutExercise>,
 }
 
@@ -14,6 +16,8 @@ pub struct NewWorkout {
     pub date: NaiveDate,
     /// Mood on a scale of 1-10, where 1 is very bad and 10 is very good
     pub mood: Option<u8>,
+    /// Template the workout was started from, if any
+    pub template_id: Option<u64>,
     pub exercises: Vec<NewWorkoutExercise>,
 }
 
@@ -22,6 +26,13 @@ pub struct WorkoutExercise {
     pub sets: Vec<WorkoutSet>,
 }
 
+/// A set of one exercise, together with the workout it was done in
+pub struct ExerciseHistorySet {
+    pub workout_id: u64,
+    pub date: NaiveDate,
+    pub set: WorkoutSet,
+}
+
 pub struct NewWorkoutExercise {
     pub exercise_id: u64,
     pub sets: Vec<NewWorkoutSet>,
@@ -33,12 +44,65 @@ pub enum WorkoutSet {
     BodyweightTime(BodyweightTimeSet),
 }
 
+impl WorkoutSet {
+    /// Whether the set was taken to failure.
+    pub fn is_failure(&self) -> bool {
+        match self {
+            WorkoutSet::Weighted(s) => s.failure,
+            WorkoutSet::BodyweightReps(s) => s.failure,
+            WorkoutSet::BodyweightTime(s) => s.failure,
+        }
+    }
+}
+
 pub enum NewWorkoutSet {
     Weighted(NewWeightedSet),
     BodyweightReps(NewBodyweightRepSet),
     BodyweightTime(NewBodyweightTimeSet),
 }
 
+impl NewWorkoutSet {
+    /// Whether this kind of set can be logged against an exercise of the given type.
+    pub fn matches_exercise_type(&self, exercise_type: &ExerciseType) -> bool {
+        matches!(
+            (self, exercise_type),
+            (NewWorkoutSet::Weighted(_), ExerciseType::Weighted { .. })
+                | (
+                    NewWorkoutSet::BodyweightReps(_),
+                    ExerciseType::BodyweightReps { .. }
+                )
+                | (
+                    NewWorkoutSet::BodyweightTime(_),
+                    ExerciseType::BodyweightTime { .. }
+                )
+        )
+    }
+}
+
+/// Sets are replaced wholesale on update, so the new ones carry no id yet.
+impl From<NewWorkoutSet> for WorkoutSet {
+    fn from(set: NewWorkoutSet) -> Self {
+        match set {
+            NewWorkoutSet::Weighted(s) => WorkoutSet::Weighted(WeightedSet {
+                id: 0,
+                reps: s.reps,
+                weight: s.weight,
+                failure: s.failure,
+            }),
+            NewWorkoutSet::BodyweightReps(s) => WorkoutSet::BodyweightReps(BodyweightRepSet {
+                id: 0,
+                reps: s.reps,
+                failure: s.failure,
+            }),
+            NewWorkoutSet::BodyweightTime(s) => WorkoutSet::BodyweightTime(BodyweightTimeSet {
+                id: 0,
+                duration_seconds: s.duration_seconds,
+                failure: s.failure,
+            }),
+        }
+    }
+}
+
 pub struct WeightedSet {
     pub id: u64,
     pub reps: u16,
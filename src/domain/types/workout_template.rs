@@ -1,12 +1,132 @@
-use crate::domain::types::exercise::Exercise;
+use std::str::FromStr;
+
+use crate::domain::types::exercise::{Exercise, ExerciseType};
 
 pub struct WorkoutTemplate {
     pub id: u64,
     pub name: String,
-    pub exercises: Vec<Exercise>,
+    /// Exercises in the order they are planned
+    pub exercises: Vec<TemplateExercise>,
+}
+
+pub struct TemplateExercise {
+    pub exercise: Exercise,
+    /// Sets to do, the goal's sets are used when there is none
+    pub set_scheme: Option<SetScheme>,
 }
 
 pub struct NewWorkoutTemplate {
     pub name: String,
-    pub exercise_ids: Vec<u64>,
+    pub exercises: Vec<NewTemplateExercise>,
+}
+
+pub struct NewTemplateExercise {
+    pub exercise_id: u64,
+    pub set_scheme: Option<SetScheme>,
+}
+
+/// Sets planned for an exercise, written `5x5`, `3xAMRAP` or `3x30s`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SetScheme {
+    pub sets: u16,
+    /// Reps, or seconds for held exercises, of each set. `None` for as many as possible.
+    pub target: Option<u16>,
+}
+
+impl FromStr for SetScheme {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Planned sets must look like 5x5, 3xAMRAP or 3x30s, not {value}");
+        let (sets, target) = value
+            .trim()
+            .split_once(['x', 'X', '×'])
+            .ok_or_else(invalid)?;
+        let sets = sets.trim().parse().map_err(|_| invalid())?;
+        let target = match target.trim() {
+            target if target.eq_ignore_ascii_case("amrap") => None,
+            target => Some(
+                target
+                    .trim_end_matches('s')
+                    .trim()
+                    .parse()
+                    .map_err(|_| invalid())?,
+            ),
+        };
+        if sets == 0 || target == Some(0) {
+            return Err(invalid());
+        }
+        Ok(SetScheme { sets, target })
+    }
+}
+
+impl SetScheme {
+    /// Writes the scheme back the way it is parsed, with the `s` of held exercises, whose
+    /// target is in seconds.
+    pub fn notation(self, exercise_type: &ExerciseType) -> String {
+        match (self.target, exercise_type) {
+            (None, _) => format!("{}xAMRAP", self.sets),
+            (Some(target), ExerciseType::BodyweightTime { .. }) => {
+                format!("{}x{target}s", self.sets)
+            }
+            (Some(target), _) => format!("{}x{target}", self.sets),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_scheme_parses_the_usual_notations() {
+        assert_eq!(
+            "5x5".parse(),
+            Ok(SetScheme {
+                sets: 5,
+                target: Some(5)
+            })
+        );
+        assert_eq!(
+            " 3 × amrap ".parse(),
+            Ok(SetScheme {
+                sets: 3,
+                target: None
+            })
+        );
+        assert_eq!(
+            "3X30s".parse(),
+            Ok(SetScheme {
+                sets: 3,
+                target: Some(30)
+            })
+        );
+    }
+
+    #[test]
+    fn set_scheme_rejects_other_values() {
+        for value in ["", "5", "0x5", "5x0", "five x five", "3x30min"] {
+            assert!(
+                value.parse::<SetScheme>().is_err(),
+                "{value} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn set_scheme_notation_round_trips() {
+        let weighted = ExerciseType::Weighted {
+            goal_weight: 100.0,
+            goal_reps: 5,
+            goal_sets: 5,
+        };
+        let held = ExerciseType::BodyweightTime {
+            goal_duration_seconds: 30,
+            goal_sets: 3,
+        };
+        for (value, exercise_type) in [("5x5", &weighted), ("3xAMRAP", &held), ("1x30s", &held)] {
+            let scheme: SetScheme = value.parse().unwrap();
+            assert_eq!(scheme.notation(exercise_type), value);
+        }
+    }
 }
@@ -0,0 +1,112 @@
+use axum::{
+    Json,
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+
+use crate::{
+    domain::traits::{
+        exercise_model::ExerciseModelError, personal_record_model::PersonalRecordModelError,
+        workout_model::WorkoutModelError, workout_template_model::WorkoutTemplateModelError,
+    },
+    inbound::log_internal_error,
+};
+
+/// Error returned by every `/api` route, rendered as a JSON body alongside its status code.
+#[derive(Debug)]
+pub struct ApiError {
+    pub status: StatusCode,
+    pub message: String,
+    pub details: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct ApiErrorBody<'a> {
+    error: &'a str,
+    #[serde(skip_serializing_if = "<[String]>::is_empty")]
+    details: &'a [String],
+}
+
+impl ApiError {
+    pub fn new(status: StatusCode, message: impl Into<String>) -> Self {
+        Self {
+            status,
+            message: message.into(),
+            details: Vec::new(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::NOT_FOUND, message)
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::CONFLICT, message)
+    }
+
+    pub fn unprocessable(message: impl Into<String>) -> Self {
+        Self::new(StatusCode::UNPROCESSABLE_ENTITY, message)
+    }
+
+    pub fn internal(error: impl std::fmt::Debug) -> Self {
+        log_internal_error(&error);
+        Self::new(StatusCode::INTERNAL_SERVER_ERROR, "Internal server error")
+    }
+
+    pub fn with_details(mut self, details: Vec<String>) -> Self {
+        self.details = details;
+        self
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let body = ApiErrorBody {
+            error: &self.message,
+            details: &self.details,
+        };
+        (self.status, Json(body)).into_response()
+    }
+}
+
+impl From<WorkoutModelError> for ApiError {
+    fn from(error: WorkoutModelError) -> Self {
+        match error {
+            WorkoutModelError::NotFound => Self::not_found("Workout not found"),
+            WorkoutModelError::DatabaseError(e) => Self::internal(e),
+        }
+    }
+}
+
+impl From<ExerciseModelError> for ApiError {
+    fn from(error: ExerciseModelError) -> Self {
+        match error {
+            ExerciseModelError::NotFound => Self::not_found("Exercise not found"),
+            ExerciseModelError::AlreadyExists => {
+                Self::conflict("An exercise with this name already exists")
+            }
+            ExerciseModelError::DatabaseError(e) => Self::internal(e),
+        }
+    }
+}
+
+impl From<WorkoutTemplateModelError> for ApiError {
+    fn from(error: WorkoutTemplateModelError) -> Self {
+        match error {
+            WorkoutTemplateModelError::NotFound => Self::not_found("Template not found"),
+            WorkoutTemplateModelError::AlreadyExists => {
+                Self::conflict("A template with this name already exists")
+            }
+            WorkoutTemplateModelError::DatabaseError(e) => Self::internal(e),
+        }
+    }
+}
+
+impl From<PersonalRecordModelError> for ApiError {
+    fn from(error: PersonalRecordModelError) -> Self {
+        match error {
+            PersonalRecordModelError::DatabaseError(e) => Self::internal(e),
+        }
+    }
+}
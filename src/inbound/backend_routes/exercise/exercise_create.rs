@@ -0,0 +1,158 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::State,
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::types::exercise::Exercise,
+    inbound::backend_routes::{
+        api_error::ApiError,
+        exercise::exercise_read::{ExerciseGoal, ExerciseResponse},
+    },
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseCreateRequest {
+    pub name: String,
+    pub goal: ExerciseGoal,
+}
+
+impl TryFrom<ExerciseCreateRequest> for Exercise {
+    type Error = String;
+
+    fn try_from(value: ExerciseCreateRequest) -> Result<Self, Self::Error> {
+        let name = value.name.trim();
+        if name.is_empty() {
+            return Err("Name must not be empty".to_string());
+        }
+        Ok(Exercise {
+            id: 0,
+            name: name.to_string(),
+            exercise_type: value.goal.try_into()?,
+        })
+    }
+}
+
+pub async fn create_exercise(
+    State(core_logic): State<Arc<AppState>>,
+    Json(exercise_create_req): Json<ExerciseCreateRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let exercise: Exercise = exercise_create_req
+        .try_into()
+        .map_err(ApiError::unprocessable)?;
+    let exercise_id = core_logic
+        .exercise_model
+        .write()
+        .await
+        .add_exercise(exercise)
+        .await?;
+    let exercise = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_exercise_by_id(exercise_id)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, format!("/api/exercises/{exercise_id}"))],
+        Json(ExerciseResponse::from(exercise)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, json_body, json_request};
+
+    #[sqlx::test]
+    async fn create_exercise_returns_created_exercise(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 115.0 } } }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers()["location"], "/api/exercises/1");
+        let body = json_body(response).await;
+        assert_eq!(
+            body,
+            json!({
+                "id": 1,
+                "name": "Squat",
+                "goal": { "weighted": { "goalWeight": 115.0, "goalReps": 1, "goalSets": 1 } }
+            })
+        );
+    }
+
+    #[sqlx::test]
+    async fn create_exercise_stores_sets_and_reps_goal(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({
+                    "name": "Squat",
+                    "goal": { "weighted": { "goalWeight": 100.0, "goalReps": 5, "goalSets": 5 } }
+                }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = json_body(response).await;
+        assert_eq!(
+            body["goal"],
+            json!({ "weighted": { "goalWeight": 100.0, "goalReps": 5, "goalSets": 5 } })
+        );
+    }
+
+    #[sqlx::test]
+    async fn create_exercise_returns_conflict_on_duplicate_name(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('Squat', 'weighted', 100.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 115.0 } } }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[sqlx::test]
+    async fn create_exercise_rejects_empty_goal(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Plank", "goal": { "bodyweightTime": { "goalDurationSeconds": 0 } } }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
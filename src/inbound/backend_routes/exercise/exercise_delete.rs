@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{inbound::backend_routes::api_error::ApiError, state::AppState};
+
+pub async fn delete_exercise(
+    State(core_logic): State<Arc<AppState>>,
+    Path(exercise_id): Path<u64>,
+) -> Result<StatusCode, ApiError> {
+    core_logic
+        .exercise_model
+        .write()
+        .await
+        .delete_exercise(exercise_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
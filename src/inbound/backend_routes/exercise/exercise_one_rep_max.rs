@@ -0,0 +1,188 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, Query, State},
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::{
+        services::one_rep_max::{OneRepMaxEstimate, OneRepMaxFormula, OneRepMaxHistory},
+        types::exercise::ExerciseType,
+    },
+    inbound::backend_routes::api_error::ApiError,
+    state::AppState,
+};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OneRepMaxQuery {
+    #[serde(default)]
+    pub formula: OneRepMaxFormula,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OneRepMaxResponse {
+    pub exercise_id: u64,
+    pub name: String,
+    /// One-rep max equivalent of the goal
+    pub goal_estimate: Option<f32>,
+    pub goal_reached: bool,
+    pub best: Option<OneRepMaxEstimateResponse>,
+    pub history: Vec<OneRepMaxEstimateResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OneRepMaxEstimateResponse {
+    pub workout_id: u64,
+    pub date: NaiveDate,
+    pub reps: u16,
+    pub weight: f32,
+    pub estimate: f32,
+}
+
+impl From<&OneRepMaxEstimate> for OneRepMaxEstimateResponse {
+    fn from(value: &OneRepMaxEstimate) -> Self {
+        OneRepMaxEstimateResponse {
+            workout_id: value.workout_id,
+            date: value.date,
+            reps: value.reps,
+            weight: value.weight,
+            estimate: value.estimate,
+        }
+    }
+}
+
+impl From<OneRepMaxHistory> for OneRepMaxResponse {
+    fn from(value: OneRepMaxHistory) -> Self {
+        OneRepMaxResponse {
+            goal_estimate: value.goal_estimate(),
+            goal_reached: value.is_goal_reached(),
+            best: value.best().map(Into::into),
+            history: value.history.iter().map(Into::into).collect(),
+            exercise_id: value.exercise.id,
+            name: value.exercise.name,
+        }
+    }
+}
+
+/// Estimated one-rep max of a weighted exercise, `?formula=` picks the formula (Epley by default).
+pub async fn get_exercise_one_rep_max(
+    State(core_logic): State<Arc<AppState>>,
+    Path(id): Path<u64>,
+    Query(query): Query<OneRepMaxQuery>,
+) -> Result<Json<OneRepMaxResponse>, ApiError> {
+    let exercise = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_exercise_by_id(id)
+        .await?;
+    if !matches!(exercise.exercise_type, ExerciseType::Weighted { .. }) {
+        return Err(ApiError::unprocessable(
+            "Only weighted exercises have a one-rep max",
+        ));
+    }
+    let workouts = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workouts_with_exercises(&[exercise.id])
+        .await?;
+    Ok(Json(
+        OneRepMaxHistory::new(exercise, query.formula, &workouts).into(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request, json_body, json_request};
+
+    async fn seed(app: &axum::Router) {
+        for exercise in [
+            json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0 } } }),
+            json!({ "name": "Plank", "goal": { "bodyweightTime": { "goalDurationSeconds": 60 } } }),
+        ] {
+            app.clone()
+                .oneshot(json_request("POST", "/api/exercises", exercise))
+                .await
+                .unwrap();
+        }
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": "2026-03-03",
+                    "exercises": [{
+                        "name": "Squat",
+                        "sets": [
+                            { "weighted": { "reps": 5, "weight": 90.0 } },
+                            { "weighted": { "reps": 3, "weight": 92.5 } }
+                        ]
+                    }]
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[sqlx::test]
+    async fn get_exercise_one_rep_max_counts_goal_reached_from_estimate(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(empty_request("GET", "/api/exercises/1/one-rep-max"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body["goalReached"], true);
+        assert_eq!(body["best"]["reps"], 5);
+        assert_eq!(body["best"]["estimate"], 105.0);
+        assert_eq!(body["history"].as_array().unwrap().len(), 1);
+    }
+
+    #[sqlx::test]
+    async fn get_exercise_one_rep_max_uses_requested_formula(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(empty_request(
+                "GET",
+                "/api/exercises/1/one-rep-max?formula=brzycki",
+            ))
+            .await
+            .unwrap();
+
+        let body = json_body(response).await;
+        assert_eq!(body["goalReached"], true);
+        assert_eq!(body["best"]["estimate"], 101.25);
+    }
+
+    #[sqlx::test]
+    async fn get_exercise_one_rep_max_rejects_bodyweight_exercises(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(empty_request("GET", "/api/exercises/2/one-rep-max"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
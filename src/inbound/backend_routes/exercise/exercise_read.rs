@@ -0,0 +1,174 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::types::exercise::{Exercise, ExerciseType},
+    inbound::backend_routes::api_error::ApiError,
+    state::AppState,
+};
+
+/// JSON shape of an `ExerciseType`, shared by requests and responses. Reps of weighted goals
+/// and sets default to 1 when left out.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", rename_all_fields = "camelCase")]
+pub enum ExerciseGoal {
+    Weighted {
+        goal_weight: f32,
+        #[serde(default = "one")]
+        goal_reps: u16,
+        #[serde(default = "one")]
+        goal_sets: u16,
+    },
+    BodyweightReps {
+        goal_reps: u16,
+        #[serde(default = "one")]
+        goal_sets: u16,
+    },
+    BodyweightTime {
+        goal_duration_seconds: u16,
+        #[serde(default = "one")]
+        goal_sets: u16,
+    },
+}
+
+fn one() -> u16 {
+    1
+}
+
+impl From<ExerciseType> for ExerciseGoal {
+    fn from(value: ExerciseType) -> Self {
+        match value {
+            ExerciseType::Weighted {
+                goal_weight,
+                goal_reps,
+                goal_sets,
+            } => ExerciseGoal::Weighted {
+                goal_weight,
+                goal_reps,
+                goal_sets,
+            },
+            ExerciseType::BodyweightReps {
+                goal_reps,
+                goal_sets,
+            } => ExerciseGoal::BodyweightReps {
+                goal_reps,
+                goal_sets,
+            },
+            ExerciseType::BodyweightTime {
+                goal_duration_seconds,
+                goal_sets,
+            } => ExerciseGoal::BodyweightTime {
+                goal_duration_seconds,
+                goal_sets,
+            },
+        }
+    }
+}
+
+fn check_goal_sets(goal_sets: u16) -> Result<(), String> {
+    if goal_sets == 0 {
+        return Err("Goal sets must be greater than 0".to_string());
+    }
+    Ok(())
+}
+
+impl TryFrom<ExerciseGoal> for ExerciseType {
+    type Error = String;
+
+    fn try_from(value: ExerciseGoal) -> Result<Self, Self::Error> {
+        match value {
+            ExerciseGoal::Weighted {
+                goal_weight,
+                goal_reps,
+                goal_sets,
+            } => {
+                if goal_weight <= 0.0 {
+                    return Err("Goal weight must be greater than 0".to_string());
+                }
+                if goal_reps == 0 {
+                    return Err("Goal reps must be greater than 0".to_string());
+                }
+                check_goal_sets(goal_sets)?;
+                Ok(ExerciseType::Weighted {
+                    goal_weight,
+                    goal_reps,
+                    goal_sets,
+                })
+            }
+            ExerciseGoal::BodyweightReps {
+                goal_reps,
+                goal_sets,
+            } => {
+                if goal_reps == 0 {
+                    return Err("Goal reps must be greater than 0".to_string());
+                }
+                check_goal_sets(goal_sets)?;
+                Ok(ExerciseType::BodyweightReps {
+                    goal_reps,
+                    goal_sets,
+                })
+            }
+            ExerciseGoal::BodyweightTime {
+                goal_duration_seconds,
+                goal_sets,
+            } => {
+                if goal_duration_seconds == 0 {
+                    return Err("Goal duration must be greater than 0".to_string());
+                }
+                check_goal_sets(goal_sets)?;
+                Ok(ExerciseType::BodyweightTime {
+                    goal_duration_seconds,
+                    goal_sets,
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseResponse {
+    pub id: u64,
+    pub name: String,
+    pub goal: ExerciseGoal,
+}
+
+impl From<Exercise> for ExerciseResponse {
+    fn from(value: Exercise) -> Self {
+        ExerciseResponse {
+            id: value.id,
+            name: value.name,
+            goal: value.exercise_type.into(),
+        }
+    }
+}
+
+pub async fn get_exercise(
+    State(core_logic): State<Arc<AppState>>,
+    Path(exercise_id): Path<u64>,
+) -> Result<Json<ExerciseResponse>, ApiError> {
+    let exercise = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_exercise_by_id(exercise_id)
+        .await?;
+    Ok(Json(exercise.into()))
+}
+
+pub async fn list_exercises(
+    State(core_logic): State<Arc<AppState>>,
+) -> Result<Json<Vec<ExerciseResponse>>, ApiError> {
+    let exercises = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_all_exercises()
+        .await?;
+    Ok(Json(exercises.into_iter().map(Into::into).collect()))
+}
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+
+use crate::{
+    domain::types::exercise::Exercise,
+    inbound::backend_routes::{
+        api_error::ApiError,
+        exercise::{exercise_create::ExerciseCreateRequest, exercise_read::ExerciseResponse},
+    },
+    state::AppState,
+};
+
+pub async fn update_exercise(
+    State(core_logic): State<Arc<AppState>>,
+    Path(exercise_id): Path<u64>,
+    Json(exercise_update_req): Json<ExerciseCreateRequest>,
+) -> Result<Json<ExerciseResponse>, ApiError> {
+    let exercise: Exercise = exercise_update_req
+        .try_into()
+        .map_err(ApiError::unprocessable)?;
+    core_logic
+        .exercise_model
+        .write()
+        .await
+        .update_exercise(Exercise {
+            id: exercise_id,
+            ..exercise
+        })
+        .await?;
+
+    let exercise = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_exercise_by_id(exercise_id)
+        .await?;
+    Ok(Json(exercise.into()))
+}
@@ -0,0 +1,5 @@
+pub mod exercise_create;
+pub mod exercise_delete;
+pub mod exercise_one_rep_max;
+pub mod exercise_read;
+pub mod exercise_update;
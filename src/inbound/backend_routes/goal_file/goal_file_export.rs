@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use axum::{extract::State, http::header, response::IntoResponse};
+
+use crate::{
+    domain::services::{
+        goal_export::export_goals,
+        goal_file::{GoalFileError, serialize_goals},
+    },
+    inbound::backend_routes::api_error::ApiError,
+    state::AppState,
+};
+
+/// Returns every goal as a `goal.yml` document.
+pub async fn export_goal_file(
+    State(core_logic): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, ApiError> {
+    let goals = export_goals(core_logic.exercise_model.read().await.as_ref()).await?;
+    let yaml = serialize_goals(goals).map_err(|e| match e {
+        e @ GoalFileError::Invalid(_) => ApiError::unprocessable(e.to_string()),
+        other => ApiError::internal(other),
+    })?;
+    Ok(([(header::CONTENT_TYPE, "application/yaml")], yaml))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request, http::StatusCode};
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request};
+
+    const GOAL_FILE: &str = include_str!("../../../../goal.yml");
+
+    #[sqlx::test]
+    async fn export_goal_file_returns_imported_goal_file(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        let response = app
+            .clone()
+            .oneshot(
+                Request::builder()
+                    .method("POST")
+                    .uri("/api/goals/import")
+                    .body(Body::from(GOAL_FILE))
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let response = app
+            .oneshot(empty_request("GET", "/api/goals/export"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["content-type"], "application/yaml");
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        assert_eq!(std::str::from_utf8(&bytes).unwrap(), GOAL_FILE);
+    }
+}
@@ -0,0 +1,116 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::services::{
+        goal_file::parse_goals,
+        goal_import::{ImportReport, import_goals},
+    },
+    inbound::backend_routes::api_error::ApiError,
+    state::AppState,
+};
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalFileImportQuery {
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GoalFileImportResponse {
+    pub dry_run: bool,
+    pub created: Vec<String>,
+    pub changed: Vec<String>,
+    pub unchanged: Vec<String>,
+}
+
+impl GoalFileImportResponse {
+    fn new(report: ImportReport, dry_run: bool) -> Self {
+        GoalFileImportResponse {
+            dry_run,
+            created: report.created,
+            changed: report.changed,
+            unchanged: report.unchanged,
+        }
+    }
+}
+
+/// Takes a `goal.yml` document as the raw request body.
+pub async fn import_goal_file(
+    State(core_logic): State<Arc<AppState>>,
+    Query(query): Query<GoalFileImportQuery>,
+    body: String,
+) -> Result<Json<GoalFileImportResponse>, ApiError> {
+    let goals = parse_goals(&body).map_err(|e| ApiError::unprocessable(e.to_string()))?;
+    let report = import_goals(
+        core_logic.exercise_model.write().await.as_mut(),
+        goals,
+        query.dry_run,
+    )
+    .await?;
+    Ok(Json(GoalFileImportResponse::new(report, query.dry_run)))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::{body::Body, http::Request, http::StatusCode};
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, json_body};
+
+    fn yaml_request(uri: &str, body: &'static str) -> Request<Body> {
+        Request::builder()
+            .method("POST")
+            .uri(uri)
+            .header("content-type", "application/yaml")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    #[sqlx::test]
+    async fn import_goal_file_dry_run_reports_changes(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+
+        let response = app
+            .clone()
+            .oneshot(yaml_request(
+                "/api/goals/import?dryRun=true",
+                include_str!("../../../../goal.yml"),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body["dryRun"], true);
+        assert_eq!(body["created"].as_array().unwrap().len(), 5);
+
+        let response = app
+            .oneshot(
+                Request::builder()
+                    .uri("/api/exercises")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+        assert_eq!(json_body(response).await, serde_json::json!([]));
+    }
+
+    #[sqlx::test]
+    async fn import_goal_file_rejects_malformed_yaml(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(yaml_request("/api/goals/import", "- name: [unclosed"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
@@ -0,0 +1,2 @@
+pub mod goal_file_export;
+pub mod goal_file_import;
@@ -1 +1,9 @@
+pub mod api_error;
+pub mod exercise;
+pub mod goal_file;
+pub mod mood;
+pub mod personal_record;
+pub mod progression;
+pub mod stats;
 pub mod workout;
+pub mod workout_template;
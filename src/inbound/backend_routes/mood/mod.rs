@@ -0,0 +1 @@
+pub mod mood_read;
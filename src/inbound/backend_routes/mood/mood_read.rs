@@ -0,0 +1,264 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use chrono::{Local, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::services::{
+        mood_report::{MoodPerformance, MoodReport, MoodSession, WeekMood, WeekdayMood},
+        training_stats::StatsGrouping,
+    },
+    inbound::backend_routes::api_error::ApiError,
+    state::AppState,
+};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct MoodReportQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoodReportResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    /// Pearson correlation of the mood with the share of the goal reached, from -1 to 1
+    pub goal_correlation: Option<f32>,
+    /// Pearson correlation of the mood with the share of failure sets, from -1 to 1
+    pub failure_correlation: Option<f32>,
+    pub by_mood: Vec<MoodPerformanceResponse>,
+    pub by_week: Vec<WeekMoodResponse>,
+    pub by_weekday: Vec<WeekdayMoodResponse>,
+    pub sessions: Vec<MoodSessionResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoodSessionResponse {
+    pub workout_id: u64,
+    pub date: NaiveDate,
+    pub mood: u8,
+    /// Share of the goal reached, from 0 to 1
+    pub goal_reached: f32,
+    /// Share of the sets taken to failure, from 0 to 1
+    pub failure_ratio: f32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoodPerformanceResponse {
+    pub mood: u8,
+    pub workouts: u32,
+    pub goal_reached: f32,
+    pub failure_ratio: f32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeekMoodResponse {
+    pub start: NaiveDate,
+    pub workouts: u32,
+    pub average_mood: Option<f32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeekdayMoodResponse {
+    pub weekday: &'static str,
+    pub workouts: u32,
+    pub average_mood: Option<f32>,
+}
+
+impl From<MoodSession> for MoodSessionResponse {
+    fn from(value: MoodSession) -> Self {
+        MoodSessionResponse {
+            workout_id: value.workout_id,
+            date: value.date,
+            mood: value.mood,
+            goal_reached: value.goal_reached,
+            failure_ratio: value.failure_ratio,
+        }
+    }
+}
+
+impl From<MoodPerformance> for MoodPerformanceResponse {
+    fn from(value: MoodPerformance) -> Self {
+        MoodPerformanceResponse {
+            mood: value.mood,
+            workouts: value.workouts,
+            goal_reached: value.goal_reached,
+            failure_ratio: value.failure_ratio,
+        }
+    }
+}
+
+impl From<WeekMood> for WeekMoodResponse {
+    fn from(value: WeekMood) -> Self {
+        WeekMoodResponse {
+            start: value.start,
+            workouts: value.workouts,
+            average_mood: value.average_mood,
+        }
+    }
+}
+
+impl From<WeekdayMood> for WeekdayMoodResponse {
+    fn from(value: WeekdayMood) -> Self {
+        WeekdayMoodResponse {
+            weekday: match value.weekday {
+                Weekday::Mon => "monday",
+                Weekday::Tue => "tuesday",
+                Weekday::Wed => "wednesday",
+                Weekday::Thu => "thursday",
+                Weekday::Fri => "friday",
+                Weekday::Sat => "saturday",
+                Weekday::Sun => "sunday",
+            },
+            workouts: value.workouts,
+            average_mood: value.average_mood,
+        }
+    }
+}
+
+impl From<MoodReport> for MoodReportResponse {
+    fn from(value: MoodReport) -> Self {
+        MoodReportResponse {
+            from: value.from,
+            to: value.to,
+            goal_correlation: value.goal_correlation,
+            failure_correlation: value.failure_correlation,
+            by_mood: value.by_mood.into_iter().map(Into::into).collect(),
+            by_week: value.by_week.into_iter().map(Into::into).collect(),
+            by_weekday: value.by_weekday.into_iter().map(Into::into).collect(),
+            sessions: value.sessions.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Mood of the workouts set against how they went, `?from=&to=`, covering the last 12 weeks
+/// up to today by default, and at most 260 weeks.
+pub async fn get_mood_report(
+    State(core_logic): State<Arc<AppState>>,
+    Query(query): Query<MoodReportQuery>,
+) -> Result<Json<MoodReportResponse>, ApiError> {
+    let to = query.to.unwrap_or_else(|| Local::now().date_naive());
+    let from = query
+        .from
+        .unwrap_or_else(|| StatsGrouping::Week.default_from(to));
+    StatsGrouping::Week
+        .check_range(from, to)
+        .map_err(ApiError::unprocessable)?;
+
+    let workouts = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workouts_between(from, to)
+        .await?;
+    Ok(Json(MoodReport::new(from, to, &workouts).into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request, json_body, json_request};
+
+    #[sqlx::test]
+    async fn get_mood_report_compares_mood_with_performance(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Pull-up", "goal": { "bodyweightReps": { "goalReps": 10 } } }),
+            ))
+            .await
+            .unwrap();
+        for (date, mood, reps, failure) in [
+            ("2026-03-02", 3, 5, true),
+            ("2026-03-04", 6, 8, false),
+            ("2026-03-09", 9, 10, false),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(json_request(
+                    "POST",
+                    "/api/workouts",
+                    json!({
+                        "date": date,
+                        "mood": mood,
+                        "exercises": [{
+                            "name": "Pull-up",
+                            "sets": [{ "bodyweightReps": { "reps": reps, "failure": failure } }]
+                        }]
+                    }),
+                ))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+
+        let response = app
+            .oneshot(empty_request(
+                "GET",
+                "/api/mood?from=2026-03-02&to=2026-03-15",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert!(body["goalCorrelation"].as_f64().unwrap() > 0.9);
+        assert!(body["failureCorrelation"].as_f64().unwrap() < -0.5);
+        assert_eq!(
+            body["byMood"][0],
+            json!({ "mood": 3, "workouts": 1, "goalReached": 0.5, "failureRatio": 1.0 })
+        );
+        assert_eq!(
+            body["byWeek"],
+            json!([
+                { "start": "2026-03-02", "workouts": 2, "averageMood": 4.5 },
+                { "start": "2026-03-09", "workouts": 1, "averageMood": 9.0 },
+            ])
+        );
+        assert_eq!(
+            body["byWeekday"][0],
+            json!({ "weekday": "monday", "workouts": 2, "averageMood": 6.0 })
+        );
+        assert_eq!(body["sessions"].as_array().unwrap().len(), 3);
+    }
+
+    #[sqlx::test]
+    async fn get_mood_report_rejects_reversed_dates(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request(
+                "GET",
+                "/api/mood?from=2026-03-31&to=2026-03-01",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[sqlx::test]
+    async fn get_mood_report_rejects_ranges_of_too_many_weeks(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request(
+                "GET",
+                "/api/mood?from=2000-01-01&to=2026-03-01",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
@@ -0,0 +1 @@
+pub mod personal_record_read;
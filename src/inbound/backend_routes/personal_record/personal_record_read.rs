@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::types::personal_record::{PersonalRecord, RecordKind},
+    inbound::backend_routes::api_error::ApiError,
+    state::AppState,
+};
+
+const DEFAULT_LIMIT: u32 = 50;
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalRecordListQuery {
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalRecordResponse {
+    pub id: u64,
+    pub workout_id: u64,
+    pub date: NaiveDate,
+    pub exercise_id: u64,
+    pub name: String,
+    pub kind: RecordKindResponse,
+    pub value: f32,
+    /// Best value before this record
+    pub previous: f32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RecordKindResponse {
+    HeaviestWeight { reps: u16 },
+    MostReps { weight: Option<f32> },
+    LongestHold,
+    BestOneRepMax,
+    SessionVolume,
+}
+
+impl From<RecordKind> for RecordKindResponse {
+    fn from(value: RecordKind) -> Self {
+        match value {
+            RecordKind::HeaviestWeight { reps } => RecordKindResponse::HeaviestWeight { reps },
+            RecordKind::MostReps { weight } => RecordKindResponse::MostReps { weight },
+            RecordKind::LongestHold => RecordKindResponse::LongestHold,
+            RecordKind::BestOneRepMax => RecordKindResponse::BestOneRepMax,
+            RecordKind::SessionVolume => RecordKindResponse::SessionVolume,
+        }
+    }
+}
+
+impl From<PersonalRecord> for PersonalRecordResponse {
+    fn from(value: PersonalRecord) -> Self {
+        PersonalRecordResponse {
+            id: value.id,
+            workout_id: value.workout_id,
+            date: value.date,
+            exercise_id: value.exercise.id,
+            name: value.exercise.name,
+            kind: value.kind.into(),
+            value: value.value,
+            previous: value.previous,
+        }
+    }
+}
+
+/// Personal records, newest first, paged with `?limit=` (50 by default) and `?offset=`.
+pub async fn list_personal_records(
+    State(core_logic): State<Arc<AppState>>,
+    Query(query): Query<PersonalRecordListQuery>,
+) -> Result<Json<Vec<PersonalRecordResponse>>, ApiError> {
+    let records = core_logic
+        .record_model
+        .read()
+        .await
+        .get_latest_records(
+            query.limit.unwrap_or(DEFAULT_LIMIT),
+            query.offset.unwrap_or(0),
+        )
+        .await?;
+    Ok(Json(records.into_iter().map(Into::into).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request, json_body, json_request};
+
+    async fn log_squat(app: &axum::Router, date: &str, sets: serde_json::Value) {
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({ "date": date, "exercises": [{ "name": "Squat", "sets": sets }] }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[sqlx::test]
+    async fn list_personal_records_returns_records_of_saved_workouts(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0 } } }),
+            ))
+            .await
+            .unwrap();
+        log_squat(
+            &app,
+            "2026-03-01",
+            json!([{ "weighted": { "reps": 5, "weight": 80.0 } }]),
+        )
+        .await;
+        log_squat(
+            &app,
+            "2026-03-08",
+            json!([{ "weighted": { "reps": 5, "weight": 85.0 } }]),
+        )
+        .await;
+
+        let response = app
+            .oneshot(empty_request("GET", "/api/records?limit=1"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(
+            body,
+            json!([{
+                "id": 1,
+                "workoutId": 2,
+                "date": "2026-03-08",
+                "exerciseId": 1,
+                "name": "Squat",
+                "kind": { "heaviestWeight": { "reps": 5 } },
+                "value": 85.0,
+                "previous": 80.0,
+            }])
+        );
+    }
+
+    #[sqlx::test]
+    async fn deleting_a_workout_recomputes_later_records(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0 } } }),
+            ))
+            .await
+            .unwrap();
+        log_squat(
+            &app,
+            "2026-03-01",
+            json!([{ "weighted": { "reps": 5, "weight": 80.0 } }]),
+        )
+        .await;
+        log_squat(
+            &app,
+            "2026-03-04",
+            json!([{ "weighted": { "reps": 5, "weight": 90.0 } }]),
+        )
+        .await;
+        log_squat(
+            &app,
+            "2026-03-08",
+            json!([{ "weighted": { "reps": 5, "weight": 85.0 } }]),
+        )
+        .await;
+
+        app.clone()
+            .oneshot(empty_request("DELETE", "/api/workouts/2"))
+            .await
+            .unwrap();
+        let response = app
+            .oneshot(empty_request("GET", "/api/records"))
+            .await
+            .unwrap();
+
+        let body = json_body(response).await;
+        let heaviest: Vec<&serde_json::Value> = body
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|record| record["kind"] == json!({ "heaviestWeight": { "reps": 5 } }))
+            .collect();
+        assert_eq!(heaviest.len(), 1);
+        assert_eq!(heaviest[0]["workoutId"], 3);
+        assert_eq!(heaviest[0]["previous"], 80.0);
+    }
+}
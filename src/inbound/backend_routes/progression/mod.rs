@@ -0,0 +1,5 @@
+pub mod progression_create;
+pub mod progression_delete;
+pub mod progression_read;
+pub mod progression_status;
+pub mod progression_update;
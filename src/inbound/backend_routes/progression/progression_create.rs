@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::State,
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        traits::exercise_model::ExerciseModelError,
+        types::exercise::{Exercise, ExerciseProgression},
+    },
+    inbound::backend_routes::{
+        api_error::ApiError,
+        exercise::exercise_create::ExerciseCreateRequest,
+        progression::progression_read::{ProgressionResponse, progression_error},
+    },
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressionCreateRequest {
+    pub name: String,
+    /// Steps from the easiest to the final goal
+    pub progression: Vec<ExerciseCreateRequest>,
+}
+
+impl TryFrom<ProgressionCreateRequest> for ExerciseProgression {
+    type Error = String;
+
+    fn try_from(value: ProgressionCreateRequest) -> Result<Self, Self::Error> {
+        let name = value.name.trim();
+        if name.is_empty() {
+            return Err("Name must not be empty".to_string());
+        }
+        if value.progression.is_empty() {
+            return Err("A progression needs at least one step".to_string());
+        }
+        let progression = value
+            .progression
+            .into_iter()
+            .map(Exercise::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ExerciseProgression {
+            name: name.to_string(),
+            progression,
+        })
+    }
+}
+
+pub async fn create_progression(
+    State(core_logic): State<Arc<AppState>>,
+    Json(progression_create_req): Json<ProgressionCreateRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let progression: ExerciseProgression = progression_create_req
+        .try_into()
+        .map_err(ApiError::unprocessable)?;
+    let name = progression.name.clone();
+
+    {
+        let mut exercise_model = core_logic.exercise_model.write().await;
+        match exercise_model
+            .get_exercise_progression_from_name(&name)
+            .await
+        {
+            Ok(_) => {
+                return Err(ApiError::conflict(
+                    "A progression with this name already exists",
+                ));
+            }
+            Err(ExerciseModelError::NotFound) => {}
+            Err(e) => return Err(e.into()),
+        }
+        exercise_model.add_exercise_progression(progression).await?;
+    }
+
+    let progression = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_exercise_progression_from_name(&name)
+        .await
+        .map_err(progression_error)?;
+
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, format!("/api/progressions/{name}"))],
+        Json(ProgressionResponse::from(progression)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, json_body, json_request};
+
+    fn handstand_pushup() -> serde_json::Value {
+        json!({
+            "name": "Handstand pushup",
+            "progression": [
+                { "name": "Pike pushup", "goal": { "bodyweightReps": { "goalReps": 15 } } },
+                { "name": "Wall pushup", "goal": { "bodyweightReps": { "goalReps": 5 } } },
+                { "name": "Handstand pushup", "goal": { "bodyweightReps": { "goalReps": 3 } } }
+            ]
+        })
+    }
+
+    #[sqlx::test]
+    async fn create_progression_keeps_step_order(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/progressions",
+                handstand_pushup(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = json_body(response).await;
+        let steps: Vec<&str> = body["progression"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|step| step["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(steps, ["Pike pushup", "Wall pushup", "Handstand pushup"]);
+    }
+
+    #[sqlx::test]
+    async fn create_progression_returns_conflict_when_name_taken(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/progressions",
+                handstand_pushup(),
+            ))
+            .await
+            .unwrap();
+
+        let response = app
+            .oneshot(json_request(
+                "POST",
+                "/api/progressions",
+                handstand_pushup(),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+}
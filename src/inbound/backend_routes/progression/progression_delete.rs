@@ -0,0 +1,32 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{
+    domain::types::exercise::ExerciseProgression,
+    inbound::backend_routes::{
+        api_error::ApiError, progression::progression_read::progression_error,
+    },
+    state::AppState,
+};
+
+/// Deletes the progression along with every exercise it contains.
+pub async fn delete_progression(
+    State(core_logic): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    core_logic
+        .exercise_model
+        .write()
+        .await
+        .delete_exercise_progression(ExerciseProgression {
+            name,
+            progression: Vec::new(),
+        })
+        .await
+        .map_err(progression_error)?;
+    Ok(StatusCode::NO_CONTENT)
+}
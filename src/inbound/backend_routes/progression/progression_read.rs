@@ -0,0 +1,64 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use serde::Serialize;
+
+use crate::{
+    domain::{traits::exercise_model::ExerciseModelError, types::exercise::ExerciseProgression},
+    inbound::backend_routes::{api_error::ApiError, exercise::exercise_read::ExerciseResponse},
+    state::AppState,
+};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressionResponse {
+    pub name: String,
+    /// Steps in the order they should be worked through, the final goal being last
+    pub progression: Vec<ExerciseResponse>,
+}
+
+impl From<ExerciseProgression> for ProgressionResponse {
+    fn from(value: ExerciseProgression) -> Self {
+        ProgressionResponse {
+            name: value.name,
+            progression: value.progression.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Progressions are looked up by name, so a missing one is not a missing exercise.
+pub fn progression_error(error: ExerciseModelError) -> ApiError {
+    match error {
+        ExerciseModelError::NotFound => ApiError::not_found("Progression not found"),
+        other => other.into(),
+    }
+}
+
+pub async fn get_progression(
+    State(core_logic): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> Result<Json<ProgressionResponse>, ApiError> {
+    let progression = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_exercise_progression_from_name(&name)
+        .await
+        .map_err(progression_error)?;
+    Ok(Json(progression.into()))
+}
+
+pub async fn list_progressions(
+    State(core_logic): State<Arc<AppState>>,
+) -> Result<Json<Vec<ProgressionResponse>>, ApiError> {
+    let progressions = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_all_exercise_progressions()
+        .await?;
+    Ok(Json(progressions.into_iter().map(Into::into).collect()))
+}
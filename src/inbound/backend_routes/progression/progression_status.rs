@@ -0,0 +1,197 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::{
+    domain::services::progression_status::{ProgressionStatus, StepStatus},
+    inbound::backend_routes::{
+        api_error::ApiError, exercise::exercise_read::ExerciseResponse,
+        progression::progression_read::progression_error,
+    },
+    state::AppState,
+};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressionStatusResponse {
+    pub name: String,
+    /// Index in `steps` of the step to work on, `null` once the progression is finished
+    pub current_step: Option<usize>,
+    pub steps: Vec<StepStatusResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StepStatusResponse {
+    #[serde(flatten)]
+    pub exercise: ExerciseResponse,
+    pub completed_on: Option<NaiveDate>,
+}
+
+impl From<ProgressionStatus> for ProgressionStatusResponse {
+    fn from(value: ProgressionStatus) -> Self {
+        ProgressionStatusResponse {
+            name: value.name,
+            current_step: value.current_step,
+            steps: value.steps.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<StepStatus> for StepStatusResponse {
+    fn from(value: StepStatus) -> Self {
+        StepStatusResponse {
+            exercise: value.exercise.into(),
+            completed_on: value.completed_on,
+        }
+    }
+}
+
+pub async fn get_progression_status(
+    State(core_logic): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> Result<Json<ProgressionStatusResponse>, ApiError> {
+    let progression = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_exercise_progression_from_name(&name)
+        .await
+        .map_err(progression_error)?;
+    let exercise_ids: Vec<u64> = progression.progression.iter().map(|step| step.id).collect();
+    let workouts = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workouts_with_exercises(&exercise_ids)
+        .await?;
+    Ok(Json(ProgressionStatus::new(progression, &workouts).into()))
+}
+
+pub async fn list_progression_statuses(
+    State(core_logic): State<Arc<AppState>>,
+) -> Result<Json<Vec<ProgressionStatusResponse>>, ApiError> {
+    let progressions = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_all_exercise_progressions()
+        .await?;
+    let exercise_ids: Vec<u64> = progressions
+        .iter()
+        .flat_map(|progression| &progression.progression)
+        .map(|step| step.id)
+        .collect();
+    let workouts = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workouts_with_exercises(&exercise_ids)
+        .await?;
+    Ok(Json(
+        progressions
+            .into_iter()
+            .map(|progression| ProgressionStatus::new(progression, &workouts).into())
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request, json_body, json_request};
+
+    async fn seed_progression(app: &axum::Router) {
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/progressions",
+                json!({
+                    "name": "Handstand pushup",
+                    "progression": [
+                        { "name": "Pike pushup", "goal": { "bodyweightReps": { "goalReps": 15, "goalSets": 2 } } },
+                        { "name": "Wall pushup", "goal": { "bodyweightReps": { "goalReps": 5 } } },
+                        { "name": "Handstand pushup", "goal": { "bodyweightReps": { "goalReps": 3 } } }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+    }
+
+    #[sqlx::test]
+    async fn get_progression_status_advances_once_goal_is_met(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed_progression(&app).await;
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": "2026-03-07",
+                    "exercises": [{
+                        "name": "Pike pushup",
+                        "sets": [
+                            { "bodyweightReps": { "reps": 15 } },
+                            { "bodyweightReps": { "reps": 16 } }
+                        ]
+                    }]
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app
+            .oneshot(empty_request(
+                "GET",
+                "/api/progressions/Handstand%20pushup/status",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body["currentStep"], 1);
+        assert_eq!(body["steps"][0]["name"], "Pike pushup");
+        assert_eq!(body["steps"][0]["completedOn"], "2026-03-07");
+        assert_eq!(body["steps"][1]["completedOn"], json!(null));
+    }
+
+    #[sqlx::test]
+    async fn list_progression_statuses_starts_at_first_step(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed_progression(&app).await;
+
+        let response = app
+            .oneshot(empty_request("GET", "/api/progressions/status"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body[0]["name"], "Handstand pushup");
+        assert_eq!(body[0]["currentStep"], 0);
+    }
+
+    #[sqlx::test]
+    async fn get_progression_status_returns_not_found_for_unknown_name(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/api/progressions/Planche/status"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
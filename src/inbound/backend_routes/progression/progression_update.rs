@@ -0,0 +1,155 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::types::exercise::{Exercise, ExerciseProgression},
+    inbound::backend_routes::{
+        api_error::ApiError,
+        exercise::exercise_read::ExerciseGoal,
+        progression::progression_read::{ProgressionResponse, progression_error},
+    },
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressionUpdateRequest {
+    /// Steps from the easiest to the final goal. Steps missing from the list are detached from
+    /// the progression but the exercises are kept.
+    pub progression: Vec<ProgressionStepUpdateRequest>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProgressionStepUpdateRequest {
+    /// Id of an existing exercise, or none to create the step
+    pub id: Option<u64>,
+    pub name: String,
+    pub goal: ExerciseGoal,
+}
+
+impl TryFrom<ProgressionStepUpdateRequest> for Exercise {
+    type Error = String;
+
+    fn try_from(value: ProgressionStepUpdateRequest) -> Result<Self, Self::Error> {
+        let name = value.name.trim();
+        if name.is_empty() {
+            return Err("Name must not be empty".to_string());
+        }
+        Ok(Exercise {
+            id: value.id.unwrap_or(0),
+            name: name.to_string(),
+            exercise_type: value.goal.try_into()?,
+        })
+    }
+}
+
+pub async fn update_progression(
+    State(core_logic): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Json(progression_update_req): Json<ProgressionUpdateRequest>,
+) -> Result<Json<ProgressionResponse>, ApiError> {
+    if progression_update_req.progression.is_empty() {
+        return Err(ApiError::unprocessable(
+            "A progression needs at least one step",
+        ));
+    }
+    let progression = progression_update_req
+        .progression
+        .into_iter()
+        .map(Exercise::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(ApiError::unprocessable)?;
+
+    {
+        let mut exercise_model = core_logic.exercise_model.write().await;
+        exercise_model
+            .get_exercise_progression_from_name(&name)
+            .await
+            .map_err(progression_error)?;
+        exercise_model
+            .update_exercise_progression(ExerciseProgression {
+                name: name.clone(),
+                progression,
+            })
+            .await?;
+    }
+
+    let progression = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_exercise_progression_from_name(&name)
+        .await
+        .map_err(progression_error)?;
+    Ok(Json(progression.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, json_body, json_request};
+
+    #[sqlx::test]
+    async fn update_progression_replaces_steps(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (id, name, exercise_type, progression_name, progression_order, goal_duration_seconds) VALUES
+             (1, 'Wall handstand', 'bodyweight_time', 'Handstand', 1, 60),
+             (2, 'Handstand', 'bodyweight_time', 'Handstand', 2, 30)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "PUT",
+                "/api/progressions/Handstand",
+                json!({
+                    "progression": [
+                        { "id": 1, "name": "Wall handstand", "goal": { "bodyweightTime": { "goalDurationSeconds": 60 } } },
+                        { "name": "Bump handstand", "goal": { "bodyweightTime": { "goalDurationSeconds": 60 } } },
+                        { "id": 2, "name": "Handstand", "goal": { "bodyweightTime": { "goalDurationSeconds": 45 } } }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body["progression"][1]["name"], "Bump handstand");
+        assert_eq!(
+            body["progression"][2]["goal"]["bodyweightTime"]["goalDurationSeconds"],
+            45
+        );
+    }
+
+    #[sqlx::test]
+    async fn update_progression_returns_not_found_when_missing(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(json_request(
+                "PUT",
+                "/api/progressions/Planche",
+                json!({
+                    "progression": [
+                        { "name": "Tuck planche", "goal": { "bodyweightTime": { "goalDurationSeconds": 10 } } }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = json_body(response).await;
+        assert_eq!(body["error"], "Progression not found");
+    }
+}
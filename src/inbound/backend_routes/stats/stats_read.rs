@@ -0,0 +1,303 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Query, State},
+};
+use chrono::{Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    domain::services::training_stats::{
+        ExerciseStats, PeriodStats, StatsGrouping, TrainingStats, TrainingVolume,
+    },
+    inbound::backend_routes::api_error::ApiError,
+    state::AppState,
+};
+
+#[derive(Debug, Default, Deserialize)]
+pub struct StatsQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    #[serde(default)]
+    pub group_by: StatsGrouping,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatsResponse {
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+    pub group_by: &'static str,
+    pub workouts: u32,
+    #[serde(flatten)]
+    pub volume: VolumeResponse,
+    pub average_mood: Option<f32>,
+    pub periods: Vec<PeriodStatsResponse>,
+    pub exercises: Vec<ExerciseStatsResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VolumeResponse {
+    /// Reps × weight of the weighted sets, in kg
+    pub tonnage: f32,
+    pub bodyweight_reps: u32,
+    pub time_under_tension_seconds: u32,
+    pub sets: u32,
+    pub failure_sets: u32,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeriodStatsResponse {
+    pub start: NaiveDate,
+    pub end: NaiveDate,
+    pub workouts: u32,
+    #[serde(flatten)]
+    pub volume: VolumeResponse,
+    pub average_mood: Option<f32>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExerciseStatsResponse {
+    pub exercise_id: u64,
+    pub name: String,
+    pub workouts: u32,
+    #[serde(flatten)]
+    pub volume: VolumeResponse,
+}
+
+impl From<TrainingVolume> for VolumeResponse {
+    fn from(value: TrainingVolume) -> Self {
+        VolumeResponse {
+            tonnage: value.tonnage,
+            bodyweight_reps: value.bodyweight_reps,
+            time_under_tension_seconds: value.time_under_tension_seconds,
+            sets: value.sets,
+            failure_sets: value.failure_sets,
+        }
+    }
+}
+
+impl From<PeriodStats> for PeriodStatsResponse {
+    fn from(value: PeriodStats) -> Self {
+        PeriodStatsResponse {
+            start: value.start,
+            end: value.end,
+            workouts: value.workouts,
+            volume: value.volume.into(),
+            average_mood: value.average_mood,
+        }
+    }
+}
+
+impl From<ExerciseStats> for ExerciseStatsResponse {
+    fn from(value: ExerciseStats) -> Self {
+        ExerciseStatsResponse {
+            exercise_id: value.exercise.id,
+            name: value.exercise.name,
+            workouts: value.workouts,
+            volume: value.volume.into(),
+        }
+    }
+}
+
+impl From<TrainingStats> for StatsResponse {
+    fn from(value: TrainingStats) -> Self {
+        StatsResponse {
+            from: value.from,
+            to: value.to,
+            group_by: match value.group_by {
+                StatsGrouping::Week => "week",
+                StatsGrouping::Month => "month",
+            },
+            workouts: value.workouts,
+            volume: value.volume.into(),
+            average_mood: value.average_mood,
+            periods: value.periods.into_iter().map(Into::into).collect(),
+            exercises: value.exercises.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Training volume and mood per week or month, `?from=&to=&group_by=week|month`. Covers the
+/// last 12 periods up to today by default, and at most 260 periods.
+pub async fn get_training_stats(
+    State(core_logic): State<Arc<AppState>>,
+    Query(query): Query<StatsQuery>,
+) -> Result<Json<StatsResponse>, ApiError> {
+    let to = query.to.unwrap_or_else(|| Local::now().date_naive());
+    let from = query
+        .from
+        .unwrap_or_else(|| query.group_by.default_from(to));
+    query
+        .group_by
+        .check_range(from, to)
+        .map_err(ApiError::unprocessable)?;
+
+    let workouts = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workouts_between(from, to)
+        .await?;
+    let stats = TrainingStats::new(from, to, query.group_by, &workouts);
+    Ok(Json(stats.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request, json_body, json_request};
+
+    async fn seed(app: &axum::Router) {
+        for exercise in [
+            json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0 } } }),
+            json!({ "name": "Pull-up", "goal": { "bodyweightReps": { "goalReps": 10 } } }),
+        ] {
+            let response = app
+                .clone()
+                .oneshot(json_request("POST", "/api/exercises", exercise))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+        for (date, mood, weight) in [("2026-03-03", 6, 80.0), ("2026-03-20", 8, 90.0)] {
+            let response = app
+                .clone()
+                .oneshot(json_request(
+                    "POST",
+                    "/api/workouts",
+                    json!({
+                        "date": date,
+                        "mood": mood,
+                        "exercises": [
+                            {
+                                "name": "Squat",
+                                "sets": [{ "weighted": { "reps": 5, "weight": weight, "failure": true } }]
+                            },
+                            {
+                                "name": "Pull-up",
+                                "sets": [{ "bodyweightReps": { "reps": 8 } }]
+                            }
+                        ]
+                    }),
+                ))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+    }
+
+    #[sqlx::test]
+    async fn get_training_stats_groups_by_month(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(empty_request(
+                "GET",
+                "/api/stats?from=2026-02-15&to=2026-03-31&group_by=month",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body["workouts"], 2);
+        assert_eq!(body["tonnage"], 850.0);
+        assert_eq!(body["bodyweightReps"], 16);
+        assert_eq!(body["failureSets"], 2);
+        assert_eq!(body["averageMood"], 7.0);
+        assert_eq!(
+            body["periods"],
+            json!([
+                {
+                    "start": "2026-02-15",
+                    "end": "2026-02-28",
+                    "workouts": 0,
+                    "tonnage": 0.0,
+                    "bodyweightReps": 0,
+                    "timeUnderTensionSeconds": 0,
+                    "sets": 0,
+                    "failureSets": 0,
+                    "averageMood": null,
+                },
+                {
+                    "start": "2026-03-01",
+                    "end": "2026-03-31",
+                    "workouts": 2,
+                    "tonnage": 850.0,
+                    "bodyweightReps": 16,
+                    "timeUnderTensionSeconds": 0,
+                    "sets": 4,
+                    "failureSets": 2,
+                    "averageMood": 7.0,
+                },
+            ])
+        );
+        let names: Vec<&str> = body["exercises"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|exercise| exercise["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, ["Pull-up", "Squat"]);
+        assert_eq!(body["exercises"][1]["tonnage"], 850.0);
+    }
+
+    #[sqlx::test]
+    async fn get_training_stats_groups_by_week_by_default(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(empty_request(
+                "GET",
+                "/api/stats?from=2026-03-02&to=2026-03-22",
+            ))
+            .await
+            .unwrap();
+
+        let body = json_body(response).await;
+        assert_eq!(body["groupBy"], "week");
+        let tonnage: Vec<f64> = body["periods"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|period| period["tonnage"].as_f64().unwrap())
+            .collect();
+        assert_eq!(tonnage, [400.0, 0.0, 450.0]);
+    }
+
+    #[sqlx::test]
+    async fn get_training_stats_rejects_reversed_dates(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request(
+                "GET",
+                "/api/stats?from=2026-03-31&to=2026-03-01",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[sqlx::test]
+    async fn get_training_stats_rejects_ranges_of_too_many_weeks(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request(
+                "GET",
+                "/api/stats?from=2000-01-01&to=2026-03-01",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
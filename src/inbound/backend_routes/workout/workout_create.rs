@@ -1,21 +1,29 @@
-#![allow(unused)]
-
 use std::sync::Arc;
 
 use axum::{
     Json,
-    body::{self, Body},
     extract::State,
+    http::{StatusCode, header},
     response::IntoResponse,
 };
 use chrono::NaiveDate;
 use serde::Deserialize;
 
 use crate::{
-    domain::types::workout::{
-        NewBodyweightRepSet, NewBodyweightTimeSet, NewWeightedSet, NewWorkout, NewWorkoutExercise,
-        NewWorkoutSet,
+    domain::{
+        traits::{
+            exercise_model::{ExerciseModel, ExerciseModelError},
+            workout_template_model::{WorkoutTemplateModel, WorkoutTemplateModelError},
+        },
+        types::{
+            exercise::ExerciseType,
+            workout::{
+                NewBodyweightRepSet, NewBodyweightTimeSet, NewWeightedSet, NewWorkout,
+                NewWorkoutExercise, NewWorkoutSet,
+            },
+        },
     },
+    inbound::backend_routes::{api_error::ApiError, workout::workout_read::WorkoutResponse},
     state::AppState,
 };
 
@@ -24,26 +32,64 @@ use crate::{
 pub struct WorkoutCreateRequest {
     pub date: NaiveDate,
     pub mood: Option<u8>,
+    pub template_id: Option<u64>,
     pub exercises: Vec<ExerciseDoneCreateRequest>,
 }
 
-impl TryFrom<WorkoutCreateRequest> for NewWorkout {
-    type Error = String;
+impl WorkoutCreateRequest {
+    /// Resolves every exercise name through `exercise_model` and checks each set against the
+    /// exercise's type. All problems are collected so the client can fix them in one go.
+    pub async fn resolve(self, exercise_model: &dyn ExerciseModel) -> Result<NewWorkout, ApiError> {
+        if let Some(mood) = self.mood
+            && !(1..=10).contains(&mood)
+        {
+            return Err(ApiError::unprocessable("Mood must be between 1 and 10"));
+        }
 
-    fn try_from(value: WorkoutCreateRequest) -> Result<Self, Self::Error> {
-        let exercises = value
-            .exercises
-            .into_iter()
-            .map(NewWorkoutExercise::try_from)
-            .collect::<Result<Vec<_>, _>>()?;
+        let mut errors = Vec::new();
+        let mut exercises = Vec::with_capacity(self.exercises.len());
+        for (index, exercise_done) in self.exercises.into_iter().enumerate() {
+            match exercise_done.resolve(exercise_model).await {
+                Ok(exercise) => exercises.push(exercise),
+                Err(ExerciseResolveError::Invalid(messages)) => errors.extend(
+                    messages
+                        .into_iter()
+                        .map(|message| format!("exercises[{index}]: {message}")),
+                ),
+                Err(ExerciseResolveError::Model(e)) => return Err(ApiError::internal(e)),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ApiError::unprocessable("Invalid workout").with_details(errors));
+        }
         Ok(NewWorkout {
-            date: value.date,
-            mood: value.mood,
+            date: self.date,
+            mood: self.mood,
+            template_id: self.template_id,
             exercises,
         })
     }
 }
 
+/// Checks the template a workout refers to exists, so a wrong id is reported as such instead
+/// of failing on the foreign key.
+pub async fn check_template(
+    template_model: &dyn WorkoutTemplateModel,
+    template_id: Option<u64>,
+) -> Result<(), ApiError> {
+    let Some(template_id) = template_id else {
+        return Ok(());
+    };
+    match template_model.get_workout_template(template_id).await {
+        Ok(_) => Ok(()),
+        Err(WorkoutTemplateModelError::NotFound) => Err(ApiError::unprocessable(format!(
+            "Unknown template {template_id}"
+        ))),
+        Err(e) => Err(e.into()),
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ExerciseDoneCreateRequest {
@@ -51,22 +97,59 @@ pub struct ExerciseDoneCreateRequest {
     pub sets: Vec<ExerciseSetCreateRequest>,
 }
 
-impl TryFrom<ExerciseDoneCreateRequest> for NewWorkoutExercise {
-    type Error = String;
+enum ExerciseResolveError {
+    Invalid(Vec<String>),
+    Model(ExerciseModelError),
+}
+
+impl ExerciseDoneCreateRequest {
+    async fn resolve(
+        self,
+        exercise_model: &dyn ExerciseModel,
+    ) -> Result<NewWorkoutExercise, ExerciseResolveError> {
+        let exercise = match exercise_model.get_exercise_by_name(&self.name).await {
+            Ok(exercise) => exercise,
+            Err(ExerciseModelError::NotFound) => {
+                return Err(ExerciseResolveError::Invalid(vec![format!(
+                    "unknown exercise '{}'",
+                    self.name
+                )]));
+            }
+            Err(e) => return Err(ExerciseResolveError::Model(e)),
+        };
 
-    fn try_from(value: ExerciseDoneCreateRequest) -> Result<Self, Self::Error> {
-        let sets = value
-            .sets
-            .into_iter()
-            .map(NewWorkoutSet::try_from)
-            .collect::<Result<Vec<_>, _>>()?;
+        let mut errors = Vec::new();
+        let mut sets = Vec::with_capacity(self.sets.len());
+        for (index, set) in self.sets.into_iter().enumerate() {
+            match NewWorkoutSet::try_from(set) {
+                Ok(set) if set.matches_exercise_type(&exercise.exercise_type) => sets.push(set),
+                Ok(_) => errors.push(format!(
+                    "sets[{index}]: '{}' only accepts {} sets",
+                    exercise.name,
+                    set_kind(&exercise.exercise_type)
+                )),
+                Err(message) => errors.push(format!("sets[{index}]: {message}")),
+            }
+        }
+
+        if !errors.is_empty() {
+            return Err(ExerciseResolveError::Invalid(errors));
+        }
         Ok(NewWorkoutExercise {
-            exercise_id: todo!(),
+            exercise_id: exercise.id,
             sets,
         })
     }
 }
 
+fn set_kind(exercise_type: &ExerciseType) -> &'static str {
+    match exercise_type {
+        ExerciseType::Weighted { .. } => "weighted",
+        ExerciseType::BodyweightReps { .. } => "bodyweightReps",
+        ExerciseType::BodyweightTime { .. } => "bodyweightTime",
+    }
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum ExerciseSetCreateRequest {
@@ -167,7 +250,181 @@ impl TryFrom<BodyweightTimeSetCreateRequest> for NewBodyweightTimeSet {
 pub async fn create_workout(
     State(core_logic): State<Arc<AppState>>,
     Json(workout_create_req): Json<WorkoutCreateRequest>,
-) -> Result<impl IntoResponse, String> {
-    let workout: NewWorkout = workout_create_req.try_into()?;
-    Ok("Pouet".into_response())
+) -> Result<impl IntoResponse, ApiError> {
+    check_template(
+        core_logic.workout_plan_model.read().await.as_ref(),
+        workout_create_req.template_id,
+    )
+    .await?;
+    let workout = workout_create_req
+        .resolve(core_logic.exercise_model.read().await.as_ref())
+        .await?;
+    let workout_id = core_logic
+        .workout_model
+        .write()
+        .await
+        .create_workout(workout)
+        .await?;
+    let workout = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workout(workout_id)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, format!("/api/workouts/{workout_id}"))],
+        Json(WorkoutResponse::from(workout)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, json_body, json_request};
+
+    async fn seed_exercises(pool: &sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight, goal_duration_seconds) VALUES
+             ('Squat', 'weighted', 100.0, NULL),
+             ('Handstand', 'bodyweight_time', NULL, 30)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn create_workout_resolves_exercise_names_ignoring_case(pool: sqlx::SqlitePool) {
+        seed_exercises(&pool).await;
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": "2026-02-05",
+                    "mood": 6,
+                    "exercises": [
+                        { "name": "squat", "sets": [{ "weighted": { "reps": 5, "weight": 80.0 } }] },
+                        { "name": "HANDSTAND", "sets": [{ "bodyweightTime": { "durationSeconds": 20 } }] }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(response.headers()["location"], "/api/workouts/1");
+        let body = json_body(response).await;
+        let mut names: Vec<&str> = body["exercises"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|exercise| exercise["name"].as_str().unwrap())
+            .collect();
+        names.sort();
+        assert_eq!(names, ["Handstand", "Squat"]);
+    }
+
+    #[sqlx::test]
+    async fn create_workout_records_template(pool: sqlx::SqlitePool) {
+        seed_exercises(&pool).await;
+        let app = app(pool);
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/templates",
+                json!({ "name": "Legs", "exerciseIds": [1] }),
+            ))
+            .await
+            .unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({ "date": "2026-02-05", "templateId": 1, "exercises": [] }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        assert_eq!(json_body(response).await["templateId"], 1);
+
+        let response = app
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({ "date": "2026-02-05", "templateId": 7, "exercises": [] }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[sqlx::test]
+    async fn create_workout_lists_unknown_exercises(pool: sqlx::SqlitePool) {
+        seed_exercises(&pool).await;
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": "2026-02-05",
+                    "exercises": [
+                        { "name": "Bench", "sets": [] },
+                        { "name": "Squat", "sets": [] },
+                        { "name": "Row", "sets": [] }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = json_body(response).await;
+        assert_eq!(
+            body["details"],
+            json!([
+                "exercises[0]: unknown exercise 'Bench'",
+                "exercises[2]: unknown exercise 'Row'"
+            ])
+        );
+    }
+
+    #[sqlx::test]
+    async fn create_workout_rejects_sets_not_matching_exercise_type(pool: sqlx::SqlitePool) {
+        seed_exercises(&pool).await;
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": "2026-02-05",
+                    "exercises": [{
+                        "name": "Handstand",
+                        "sets": [
+                            { "bodyweightTime": { "durationSeconds": 20 } },
+                            { "weighted": { "reps": 5, "weight": 80.0 } }
+                        ]
+                    }]
+                }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = json_body(response).await;
+        assert_eq!(
+            body["details"],
+            json!(["exercises[0]: sets[1]: 'Handstand' only accepts bodyweightTime sets"])
+        );
+    }
 }
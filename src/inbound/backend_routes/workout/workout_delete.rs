@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{inbound::backend_routes::api_error::ApiError, state::AppState};
+
+pub async fn delete_workout(
+    State(core_logic): State<Arc<AppState>>,
+    Path(workout_id): Path<u64>,
+) -> Result<StatusCode, ApiError> {
+    core_logic
+        .workout_model
+        .write()
+        .await
+        .delete_workout(workout_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request};
+
+    #[sqlx::test]
+    async fn delete_workout_removes_existing(pool: sqlx::SqlitePool) {
+        sqlx::query("INSERT INTO workout (date) VALUES ('2026-02-05')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let app = app(pool);
+
+        let response = app
+            .clone()
+            .oneshot(empty_request("DELETE", "/api/workouts/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+        let response = app
+            .oneshot(empty_request("GET", "/api/workouts/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test]
+    async fn delete_workout_returns_not_found_when_missing(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request("DELETE", "/api/workouts/999"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
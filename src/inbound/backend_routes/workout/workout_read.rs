@@ -0,0 +1,216 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use chrono::NaiveDate;
+use serde::Serialize;
+
+use crate::{
+    domain::types::workout::{Workout, WorkoutExercise, WorkoutSet},
+    inbound::backend_routes::api_error::ApiError,
+    state::AppState,
+};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutResponse {
+    pub id: u64,
+    pub date: NaiveDate,
+    pub mood: Option<u8>,
+    pub template_id: Option<u64>,
+    pub exercises: Vec<WorkoutExerciseResponse>,
+}
+
+impl From<Workout> for WorkoutResponse {
+    fn from(value: Workout) -> Self {
+        WorkoutResponse {
+            id: value.id,
+            date: value.date,
+            mood: value.mood,
+            template_id: value.template_id,
+            exercises: value.exercises.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutExerciseResponse {
+    pub exercise_id: u64,
+    pub name: String,
+    pub sets: Vec<WorkoutSetResponse>,
+}
+
+impl From<WorkoutExercise> for WorkoutExerciseResponse {
+    fn from(value: WorkoutExercise) -> Self {
+        WorkoutExerciseResponse {
+            exercise_id: value.exercise.id,
+            name: value.exercise.name,
+            sets: value.sets.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum WorkoutSetResponse {
+    Weighted(WeightedSetResponse),
+    BodyweightReps(BodyweightRepSetResponse),
+    BodyweightTime(BodyweightTimeSetResponse),
+}
+
+impl From<WorkoutSet> for WorkoutSetResponse {
+    fn from(value: WorkoutSet) -> Self {
+        match value {
+            WorkoutSet::Weighted(s) => WorkoutSetResponse::Weighted(WeightedSetResponse {
+                id: s.id,
+                reps: s.reps,
+                weight: s.weight,
+                failure: s.failure,
+            }),
+            WorkoutSet::BodyweightReps(s) => {
+                WorkoutSetResponse::BodyweightReps(BodyweightRepSetResponse {
+                    id: s.id,
+                    reps: s.reps,
+                    failure: s.failure,
+                })
+            }
+            WorkoutSet::BodyweightTime(s) => {
+                WorkoutSetResponse::BodyweightTime(BodyweightTimeSetResponse {
+                    id: s.id,
+                    duration_seconds: s.duration_seconds,
+                    failure: s.failure,
+                })
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeightedSetResponse {
+    pub id: u64,
+    pub reps: u16,
+    pub weight: f32,
+    pub failure: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BodyweightRepSetResponse {
+    pub id: u64,
+    pub reps: u16,
+    pub failure: bool,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BodyweightTimeSetResponse {
+    pub id: u64,
+    pub duration_seconds: u16,
+    pub failure: bool,
+}
+
+pub async fn get_workout(
+    State(core_logic): State<Arc<AppState>>,
+    Path(workout_id): Path<u64>,
+) -> Result<Json<WorkoutResponse>, ApiError> {
+    let workout = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workout(workout_id)
+        .await?;
+    Ok(Json(workout.into()))
+}
+
+pub async fn list_workouts(
+    State(core_logic): State<Arc<AppState>>,
+) -> Result<Json<Vec<WorkoutResponse>>, ApiError> {
+    let workouts = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_all_workouts()
+        .await?;
+    Ok(Json(workouts.into_iter().map(Into::into).collect()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request, json_body};
+
+    async fn seed_workout(pool: &sqlx::SqlitePool) {
+        sqlx::query("INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 100.0)")
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO workout (date, mood) VALUES ('2026-02-05', 7)")
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO workout_exercise (workout_id, exercise_id, position) VALUES (1, 1, 0)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO workout_set (workout_exercise_id, set_order, reps, weight, failure) VALUES
+             (1, 1, 5, 80.0, 0),
+             (1, 2, 3, 90.0, 1)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn get_workout_returns_workout_as_json(pool: sqlx::SqlitePool) {
+        seed_workout(&pool).await;
+
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/api/workouts/1"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body["date"], "2026-02-05");
+        assert_eq!(body["mood"], 7);
+        assert_eq!(body["exercises"][0]["name"], "squat");
+        assert_eq!(body["exercises"][0]["sets"][1]["weighted"]["weight"], 90.0);
+        assert_eq!(body["exercises"][0]["sets"][1]["weighted"]["failure"], true);
+    }
+
+    #[sqlx::test]
+    async fn get_workout_returns_structured_not_found(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/api/workouts/999"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = json_body(response).await;
+        assert_eq!(body["error"], "Workout not found");
+    }
+
+    #[sqlx::test]
+    async fn list_workouts_returns_all_workouts(pool: sqlx::SqlitePool) {
+        seed_workout(&pool).await;
+
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/api/workouts"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body.as_array().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,127 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+
+use crate::{
+    domain::types::workout::{Workout, WorkoutExercise},
+    inbound::backend_routes::{
+        api_error::ApiError,
+        workout::{
+            workout_create::{WorkoutCreateRequest, check_template},
+            workout_read::WorkoutResponse,
+        },
+    },
+    state::AppState,
+};
+
+pub async fn update_workout(
+    State(core_logic): State<Arc<AppState>>,
+    Path(workout_id): Path<u64>,
+    Json(workout_update_req): Json<WorkoutCreateRequest>,
+) -> Result<Json<WorkoutResponse>, ApiError> {
+    check_template(
+        core_logic.workout_plan_model.read().await.as_ref(),
+        workout_update_req.template_id,
+    )
+    .await?;
+    let exercise_model = core_logic.exercise_model.read().await;
+    let new_workout = workout_update_req.resolve(exercise_model.as_ref()).await?;
+
+    let mut exercises = Vec::with_capacity(new_workout.exercises.len());
+    for new_exercise in new_workout.exercises {
+        let exercise = exercise_model
+            .get_exercise_by_id(new_exercise.exercise_id)
+            .await
+            .map_err(ApiError::internal)?;
+        exercises.push(WorkoutExercise {
+            exercise,
+            sets: new_exercise.sets.into_iter().map(Into::into).collect(),
+        });
+    }
+    drop(exercise_model);
+
+    core_logic
+        .workout_model
+        .write()
+        .await
+        .update_workout(Workout {
+            id: workout_id,
+            date: new_workout.date,
+            mood: new_workout.mood,
+            template_id: new_workout.template_id,
+            exercises,
+        })
+        .await?;
+
+    let workout = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workout(workout_id)
+        .await?;
+    Ok(Json(workout.into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, json_body, json_request};
+
+    #[sqlx::test]
+    async fn update_workout_replaces_date_and_mood(pool: sqlx::SqlitePool) {
+        sqlx::query("INSERT INTO workout (date, mood) VALUES ('2026-02-05', 3)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "PUT",
+                "/api/workouts/1",
+                json!({ "date": "2026-02-06", "mood": 8, "exercises": [] }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(body["date"], "2026-02-06");
+        assert_eq!(body["mood"], 8);
+    }
+
+    #[sqlx::test]
+    async fn update_workout_returns_not_found_when_missing(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(json_request(
+                "PUT",
+                "/api/workouts/999",
+                json!({ "date": "2026-02-06", "exercises": [] }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test]
+    async fn update_workout_rejects_out_of_range_mood(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(json_request(
+                "PUT",
+                "/api/workouts/1",
+                json!({ "date": "2026-02-06", "mood": 11, "exercises": [] }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = json_body(response).await;
+        assert_eq!(body["error"], "Mood must be between 1 and 10");
+    }
+}
@@ -0,0 +1,4 @@
+pub mod workout_template_create;
+pub mod workout_template_delete;
+pub mod workout_template_read;
+pub mod workout_template_update;
@@ -0,0 +1,272 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use axum::{
+    Json,
+    extract::State,
+    http::{StatusCode, header},
+    response::IntoResponse,
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        traits::exercise_model::{ExerciseModel, ExerciseModelError},
+        types::{
+            exercise::Exercise,
+            workout_template::{
+                NewTemplateExercise, NewWorkoutTemplate, SetScheme, TemplateExercise,
+            },
+        },
+    },
+    inbound::backend_routes::{
+        api_error::ApiError, workout_template::workout_template_read::WorkoutTemplateResponse,
+    },
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutTemplateCreateRequest {
+    pub name: String,
+    /// Exercises in the order they are planned
+    pub exercise_ids: Vec<u64>,
+    /// Sets planned for some of the exercises by id, such as `"5x5"`, `"3xAMRAP"` or `"3x30s"`
+    #[serde(default)]
+    pub set_schemes: HashMap<u64, String>,
+}
+
+impl WorkoutTemplateCreateRequest {
+    /// Checks the name and fetches every listed exercise, reporting all unknown or repeated ids
+    /// and every invalid set scheme.
+    pub async fn resolve(
+        mut self,
+        exercise_model: &dyn ExerciseModel,
+    ) -> Result<(String, Vec<TemplateExercise>), ApiError> {
+        let name = self.name.trim();
+        if name.is_empty() {
+            return Err(ApiError::unprocessable("Name must not be empty"));
+        }
+        let exercises = fetch_exercises(exercise_model, &self.exercise_ids).await?;
+
+        let mut errors = Vec::new();
+        let mut planned = Vec::with_capacity(exercises.len());
+        for exercise in exercises {
+            let set_scheme = match self.set_schemes.remove(&exercise.id) {
+                None => None,
+                Some(scheme) => match scheme.parse::<SetScheme>() {
+                    Ok(scheme) => Some(scheme),
+                    Err(e) => {
+                        errors.push(format!("setSchemes[{}]: {e}", exercise.id));
+                        None
+                    }
+                },
+            };
+            planned.push(TemplateExercise {
+                exercise,
+                set_scheme,
+            });
+        }
+        let mut unknown: Vec<u64> = self.set_schemes.into_keys().collect();
+        unknown.sort();
+        errors.extend(unknown.into_iter().map(|exercise_id| {
+            format!("setSchemes[{exercise_id}]: exercise {exercise_id} is not in exerciseIds")
+        }));
+
+        if !errors.is_empty() {
+            return Err(ApiError::unprocessable("Invalid set schemes").with_details(errors));
+        }
+        Ok((name.to_string(), planned))
+    }
+}
+
+/// Fetches the exercises behind `exercise_ids`, keeping their order.
+pub async fn fetch_exercises(
+    exercise_model: &dyn ExerciseModel,
+    exercise_ids: &[u64],
+) -> Result<Vec<Exercise>, ApiError> {
+    let mut errors = Vec::new();
+    let mut seen = HashSet::new();
+    let mut exercises = Vec::with_capacity(exercise_ids.len());
+    for (index, &exercise_id) in exercise_ids.iter().enumerate() {
+        if !seen.insert(exercise_id) {
+            errors.push(format!(
+                "exerciseIds[{index}]: exercise {exercise_id} is listed twice"
+            ));
+            continue;
+        }
+        match exercise_model.get_exercise_by_id(exercise_id).await {
+            Ok(exercise) => exercises.push(exercise),
+            Err(ExerciseModelError::NotFound) => errors.push(format!(
+                "exerciseIds[{index}]: unknown exercise {exercise_id}"
+            )),
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(ApiError::unprocessable("Invalid template exercises").with_details(errors));
+    }
+    Ok(exercises)
+}
+
+pub async fn create_workout_template(
+    State(core_logic): State<Arc<AppState>>,
+    Json(template_create_req): Json<WorkoutTemplateCreateRequest>,
+) -> Result<impl IntoResponse, ApiError> {
+    let (name, exercises) = template_create_req
+        .resolve(core_logic.exercise_model.read().await.as_ref())
+        .await?;
+    let template_id = core_logic
+        .workout_plan_model
+        .write()
+        .await
+        .create_workout_template(NewWorkoutTemplate {
+            name,
+            exercises: exercises
+                .iter()
+                .map(|planned| NewTemplateExercise {
+                    exercise_id: planned.exercise.id,
+                    set_scheme: planned.set_scheme,
+                })
+                .collect(),
+        })
+        .await?;
+    let template = core_logic
+        .workout_plan_model
+        .read()
+        .await
+        .get_workout_template(template_id)
+        .await?;
+
+    Ok((
+        StatusCode::CREATED,
+        [(header::LOCATION, format!("/api/templates/{template_id}"))],
+        Json(WorkoutTemplateResponse::from(template)),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, json_body, json_request};
+
+    #[sqlx::test]
+    async fn create_workout_template_returns_expanded_exercises(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight, goal_duration_seconds) VALUES
+             ('Squat', 'weighted', 115.0, NULL),
+             ('Handstand', 'bodyweight_time', NULL, 30)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/templates",
+                json!({ "name": "Legs and Handstand", "exerciseIds": [2, 1] }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = json_body(response).await;
+        assert_eq!(body["name"], "Legs and Handstand");
+        assert_eq!(body["exercises"][0]["name"], "Handstand");
+        assert_eq!(
+            body["exercises"][1]["goal"],
+            json!({ "weighted": { "goalWeight": 115.0, "goalReps": 1, "goalSets": 1 } })
+        );
+    }
+
+    #[sqlx::test]
+    async fn create_workout_template_lists_invalid_exercise_ids(pool: sqlx::SqlitePool) {
+        sqlx::query("INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('Squat', 'weighted', 115.0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/templates",
+                json!({ "name": "Legs", "exerciseIds": [1, 7, 1] }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = json_body(response).await;
+        assert_eq!(
+            body["details"],
+            json!([
+                "exerciseIds[1]: unknown exercise 7",
+                "exerciseIds[2]: exercise 1 is listed twice"
+            ])
+        );
+    }
+
+    #[sqlx::test]
+    async fn create_workout_template_stores_set_schemes(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight, goal_duration_seconds) VALUES
+             ('Squat', 'weighted', 115.0, NULL),
+             ('Handstand', 'bodyweight_time', NULL, 30)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/templates",
+                json!({
+                    "name": "Legs and Handstand",
+                    "exerciseIds": [1, 2],
+                    "setSchemes": { "1": "5×5", "2": "3x30s" }
+                }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = json_body(response).await;
+        assert_eq!(body["exercises"][0]["setScheme"], "5x5");
+        assert_eq!(body["exercises"][1]["setScheme"], "3x30s");
+    }
+
+    #[sqlx::test]
+    async fn create_workout_template_lists_invalid_set_schemes(pool: sqlx::SqlitePool) {
+        sqlx::query("INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('Squat', 'weighted', 115.0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/templates",
+                json!({ "name": "Legs", "exerciseIds": [1], "setSchemes": { "1": "heavy", "3": "5x5" } }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let body = json_body(response).await;
+        assert_eq!(
+            body["details"],
+            json!([
+                "setSchemes[1]: Planned sets must look like 5x5, 3xAMRAP or 3x30s, not heavy",
+                "setSchemes[3]: exercise 3 is not in exerciseIds"
+            ])
+        );
+    }
+}
@@ -0,0 +1,21 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+};
+
+use crate::{inbound::backend_routes::api_error::ApiError, state::AppState};
+
+pub async fn delete_workout_template(
+    State(core_logic): State<Arc<AppState>>,
+    Path(template_id): Path<u64>,
+) -> Result<StatusCode, ApiError> {
+    core_logic
+        .workout_plan_model
+        .write()
+        .await
+        .delete_workout_template(template_id)
+        .await?;
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,77 @@
+use std::sync::Arc;
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use serde::Serialize;
+
+use crate::{
+    domain::types::workout_template::{TemplateExercise, WorkoutTemplate},
+    inbound::backend_routes::{api_error::ApiError, exercise::exercise_read::ExerciseResponse},
+    state::AppState,
+};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutTemplateResponse {
+    pub id: u64,
+    pub name: String,
+    /// Exercises in the order they are planned
+    pub exercises: Vec<TemplateExerciseResponse>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateExerciseResponse {
+    #[serde(flatten)]
+    pub exercise: ExerciseResponse,
+    /// Planned sets such as `"5x5"`, `"3xAMRAP"` or `"3x30s"`, none to follow the goal
+    pub set_scheme: Option<String>,
+}
+
+impl From<TemplateExercise> for TemplateExerciseResponse {
+    fn from(value: TemplateExercise) -> Self {
+        TemplateExerciseResponse {
+            set_scheme: value
+                .set_scheme
+                .map(|scheme| scheme.notation(&value.exercise.exercise_type)),
+            exercise: value.exercise.into(),
+        }
+    }
+}
+
+impl From<WorkoutTemplate> for WorkoutTemplateResponse {
+    fn from(value: WorkoutTemplate) -> Self {
+        WorkoutTemplateResponse {
+            id: value.id,
+            name: value.name,
+            exercises: value.exercises.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+pub async fn get_workout_template(
+    State(core_logic): State<Arc<AppState>>,
+    Path(template_id): Path<u64>,
+) -> Result<Json<WorkoutTemplateResponse>, ApiError> {
+    let template = core_logic
+        .workout_plan_model
+        .read()
+        .await
+        .get_workout_template(template_id)
+        .await?;
+    Ok(Json(template.into()))
+}
+
+pub async fn list_workout_templates(
+    State(core_logic): State<Arc<AppState>>,
+) -> Result<Json<Vec<WorkoutTemplateResponse>>, ApiError> {
+    let templates = core_logic
+        .workout_plan_model
+        .read()
+        .await
+        .get_all_workout_templates()
+        .await?;
+    Ok(Json(templates.into_iter().map(Into::into).collect()))
+}
@@ -0,0 +1,308 @@
+use std::{collections::HashSet, sync::Arc};
+
+use axum::{
+    Json,
+    extract::{Path, State},
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::types::workout_template::{SetScheme, TemplateExercise, WorkoutTemplate},
+    inbound::backend_routes::{
+        api_error::ApiError,
+        workout_template::{
+            workout_template_create::{WorkoutTemplateCreateRequest, fetch_exercises},
+            workout_template_read::WorkoutTemplateResponse,
+        },
+    },
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutTemplateReorderRequest {
+    /// The template's current exercises, in their new order
+    pub exercise_ids: Vec<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkoutTemplateExerciseAddRequest {
+    pub exercise_id: u64,
+    /// Zero-based index to insert the exercise at, appended at the end when missing
+    pub position: Option<usize>,
+    /// Planned sets such as `"5x5"`, the goal's sets are followed when missing
+    pub set_scheme: Option<String>,
+}
+
+async fn save_workout_template(
+    core_logic: &AppState,
+    template: WorkoutTemplate,
+) -> Result<Json<WorkoutTemplateResponse>, ApiError> {
+    let template_id = template.id;
+    core_logic
+        .workout_plan_model
+        .write()
+        .await
+        .update_workout_template(template)
+        .await?;
+    let template = core_logic
+        .workout_plan_model
+        .read()
+        .await
+        .get_workout_template(template_id)
+        .await?;
+    Ok(Json(template.into()))
+}
+
+pub async fn update_workout_template(
+    State(core_logic): State<Arc<AppState>>,
+    Path(template_id): Path<u64>,
+    Json(template_update_req): Json<WorkoutTemplateCreateRequest>,
+) -> Result<Json<WorkoutTemplateResponse>, ApiError> {
+    let (name, exercises) = template_update_req
+        .resolve(core_logic.exercise_model.read().await.as_ref())
+        .await?;
+    save_workout_template(
+        &core_logic,
+        WorkoutTemplate {
+            id: template_id,
+            name,
+            exercises,
+        },
+    )
+    .await
+}
+
+pub async fn reorder_workout_template(
+    State(core_logic): State<Arc<AppState>>,
+    Path(template_id): Path<u64>,
+    Json(reorder_req): Json<WorkoutTemplateReorderRequest>,
+) -> Result<Json<WorkoutTemplateResponse>, ApiError> {
+    let mut template = core_logic
+        .workout_plan_model
+        .read()
+        .await
+        .get_workout_template(template_id)
+        .await?;
+
+    let current: HashSet<u64> = template.exercises.iter().map(|e| e.exercise.id).collect();
+    let requested: HashSet<u64> = reorder_req.exercise_ids.iter().copied().collect();
+    if current != requested || reorder_req.exercise_ids.len() != template.exercises.len() {
+        return Err(ApiError::unprocessable(
+            "exerciseIds must list each of the template's exercises exactly once",
+        ));
+    }
+
+    let mut exercises = std::mem::take(&mut template.exercises);
+    for exercise_id in reorder_req.exercise_ids {
+        let index = exercises
+            .iter()
+            .position(|e| e.exercise.id == exercise_id)
+            .unwrap();
+        template.exercises.push(exercises.swap_remove(index));
+    }
+    save_workout_template(&core_logic, template).await
+}
+
+pub async fn add_workout_template_exercise(
+    State(core_logic): State<Arc<AppState>>,
+    Path(template_id): Path<u64>,
+    Json(add_req): Json<WorkoutTemplateExerciseAddRequest>,
+) -> Result<Json<WorkoutTemplateResponse>, ApiError> {
+    let mut template = core_logic
+        .workout_plan_model
+        .read()
+        .await
+        .get_workout_template(template_id)
+        .await?;
+    if template
+        .exercises
+        .iter()
+        .any(|e| e.exercise.id == add_req.exercise_id)
+    {
+        return Err(ApiError::conflict(
+            "The exercise is already part of this template",
+        ));
+    }
+
+    let set_scheme = add_req
+        .set_scheme
+        .as_deref()
+        .map(str::parse::<SetScheme>)
+        .transpose()
+        .map_err(ApiError::unprocessable)?;
+    let exercise = fetch_exercises(
+        core_logic.exercise_model.read().await.as_ref(),
+        &[add_req.exercise_id],
+    )
+    .await?
+    .remove(0);
+    let position = add_req
+        .position
+        .unwrap_or(template.exercises.len())
+        .min(template.exercises.len());
+    template.exercises.insert(
+        position,
+        TemplateExercise {
+            exercise,
+            set_scheme,
+        },
+    );
+    save_workout_template(&core_logic, template).await
+}
+
+pub async fn remove_workout_template_exercise(
+    State(core_logic): State<Arc<AppState>>,
+    Path((template_id, exercise_id)): Path<(u64, u64)>,
+) -> Result<Json<WorkoutTemplateResponse>, ApiError> {
+    let mut template = core_logic
+        .workout_plan_model
+        .read()
+        .await
+        .get_workout_template(template_id)
+        .await?;
+    let Some(index) = template
+        .exercises
+        .iter()
+        .position(|e| e.exercise.id == exercise_id)
+    else {
+        return Err(ApiError::not_found(
+            "The exercise is not part of this template",
+        ));
+    };
+    template.exercises.remove(index);
+    save_workout_template(&core_logic, template).await
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request, json_body, json_request};
+
+    async fn seed_template(pool: &sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES
+             ('Squat', 'weighted', 115.0),
+             ('Deadlift', 'weighted', 140.0),
+             ('Military press', 'weighted', 75.0)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO workout_template (name) VALUES ('Strength')")
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO workout_template_exercise (workout_template_id, exercise_id) VALUES (1, 1), (1, 2)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    fn exercise_names(body: &serde_json::Value) -> Vec<&str> {
+        body["exercises"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|exercise| exercise["name"].as_str().unwrap())
+            .collect()
+    }
+
+    #[sqlx::test]
+    async fn reorder_workout_template_changes_exercise_order(pool: sqlx::SqlitePool) {
+        seed_template(&pool).await;
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "PUT",
+                "/api/templates/1/order",
+                json!({ "exerciseIds": [2, 1] }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(exercise_names(&body), ["Deadlift", "Squat"]);
+    }
+
+    #[sqlx::test]
+    async fn reorder_workout_template_rejects_other_exercises(pool: sqlx::SqlitePool) {
+        seed_template(&pool).await;
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "PUT",
+                "/api/templates/1/order",
+                json!({ "exerciseIds": [3, 1] }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+
+    #[sqlx::test]
+    async fn add_workout_template_exercise_inserts_at_position(pool: sqlx::SqlitePool) {
+        seed_template(&pool).await;
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/templates/1/exercises",
+                json!({ "exerciseId": 3, "position": 1 }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(
+            exercise_names(&body),
+            ["Squat", "Military press", "Deadlift"]
+        );
+    }
+
+    #[sqlx::test]
+    async fn add_workout_template_exercise_returns_conflict_when_present(pool: sqlx::SqlitePool) {
+        seed_template(&pool).await;
+
+        let response = app(pool)
+            .oneshot(json_request(
+                "POST",
+                "/api/templates/1/exercises",
+                json!({ "exerciseId": 2 }),
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+    }
+
+    #[sqlx::test]
+    async fn remove_workout_template_exercise_drops_it(pool: sqlx::SqlitePool) {
+        seed_template(&pool).await;
+        let app = app(pool);
+
+        let response = app
+            .clone()
+            .oneshot(empty_request("DELETE", "/api/templates/1/exercises/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = json_body(response).await;
+        assert_eq!(exercise_names(&body), ["Deadlift"]);
+
+        let response = app
+            .oneshot(empty_request("DELETE", "/api/templates/1/exercises/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
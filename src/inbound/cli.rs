@@ -0,0 +1,175 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    domain::services::{
+        goal_export::export_goals,
+        goal_file::{parse_goals, serialize_goals},
+        goal_import::{ImportReport, import_goals},
+    },
+    state::AppState,
+};
+
+pub const USAGE: &str = "usage: sport_tracker [serve | import-goals [--dry-run] <goal.yml> | export-goals [goal.yml] | refresh-records]";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Serve,
+    ImportGoals {
+        path: PathBuf,
+        dry_run: bool,
+    },
+    /// Writes the goals to `path`, or to stdout when there is none.
+    ExportGoals {
+        path: Option<PathBuf>,
+    },
+    /// Recomputes the personal records of every exercise from the logged workouts.
+    RefreshRecords,
+}
+
+impl Command {
+    /// Parses the arguments following the program name. No arguments means serving.
+    pub fn parse(args: impl IntoIterator<Item = String>) -> Result<Self, String> {
+        let mut args = args.into_iter();
+        match args.next().as_deref() {
+            None | Some("serve") => Ok(Command::Serve),
+            Some("import-goals") => {
+                let mut path = None;
+                let mut dry_run = false;
+                for arg in args {
+                    match arg.as_str() {
+                        "--dry-run" => dry_run = true,
+                        _ if path.is_none() => path = Some(PathBuf::from(arg)),
+                        _ => return Err(format!("unexpected argument '{arg}'")),
+                    }
+                }
+                let path = path.ok_or("import-goals needs the path of a goal file")?;
+                Ok(Command::ImportGoals { path, dry_run })
+            }
+            Some("export-goals") => {
+                let path = args.next().map(PathBuf::from);
+                if let Some(arg) = args.next() {
+                    return Err(format!("unexpected argument '{arg}'"));
+                }
+                Ok(Command::ExportGoals { path })
+            }
+            Some("refresh-records") => match args.next() {
+                Some(arg) => Err(format!("unexpected argument '{arg}'")),
+                None => Ok(Command::RefreshRecords),
+            },
+            Some(other) => Err(format!("unknown command '{other}'")),
+        }
+    }
+}
+
+fn format_report(report: &ImportReport, dry_run: bool) -> String {
+    let mut output = String::new();
+    for (label, names) in [
+        ("created", &report.created),
+        ("changed", &report.changed),
+        ("unchanged", &report.unchanged),
+    ] {
+        for name in names {
+            output.push_str(&format!("{label}: {name}\n"));
+        }
+    }
+    if dry_run {
+        output.push_str("dry run, nothing was written\n");
+    }
+    output
+}
+
+/// Imports the goal file at `path` and returns a human readable report.
+pub async fn import_goal_file(
+    state: &AppState,
+    path: &Path,
+    dry_run: bool,
+) -> Result<String, String> {
+    let yaml = std::fs::read_to_string(path)
+        .map_err(|e| format!("could not read {}: {e}", path.display()))?;
+    let goals = parse_goals(&yaml).map_err(|e| e.to_string())?;
+    let report = import_goals(state.exercise_model.write().await.as_mut(), goals, dry_run)
+        .await
+        .map_err(|e| format!("import failed: {e:?}"))?;
+    Ok(format_report(&report, dry_run))
+}
+
+/// Exports the goals as a goal file, written to `path` when given and returned otherwise.
+pub async fn export_goal_file(state: &AppState, path: Option<&Path>) -> Result<String, String> {
+    let goals = export_goals(state.exercise_model.read().await.as_ref())
+        .await
+        .map_err(|e| format!("export failed: {e:?}"))?;
+    let yaml = serialize_goals(goals).map_err(|e| e.to_string())?;
+    match path {
+        Some(path) => {
+            std::fs::write(path, yaml)
+                .map_err(|e| format!("could not write {}: {e}", path.display()))?;
+            Ok(String::new())
+        }
+        None => Ok(yaml),
+    }
+}
+
+/// Recomputes every personal record, for workouts logged before records were detected.
+pub async fn refresh_records(state: &AppState) -> Result<(), String> {
+    state
+        .workout_model
+        .write()
+        .await
+        .refresh_all_records()
+        .await
+        .map_err(|e| format!("refreshing records failed: {e:?}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_defaults_to_serve() {
+        assert_eq!(Command::parse(args(&[])), Ok(Command::Serve));
+    }
+
+    #[test]
+    fn parse_reads_import_goals_options() {
+        assert_eq!(
+            Command::parse(args(&["import-goals", "--dry-run", "goal.yml"])),
+            Ok(Command::ImportGoals {
+                path: PathBuf::from("goal.yml"),
+                dry_run: true
+            })
+        );
+    }
+
+    #[test]
+    fn parse_rejects_import_goals_without_path() {
+        assert!(Command::parse(args(&["import-goals"])).is_err());
+    }
+
+    #[test]
+    fn parse_reads_refresh_records() {
+        assert_eq!(
+            Command::parse(args(&["refresh-records"])),
+            Ok(Command::RefreshRecords)
+        );
+        assert!(Command::parse(args(&["refresh-records", "now"])).is_err());
+    }
+
+    #[test]
+    fn parse_reads_export_goals_optional_path() {
+        assert_eq!(
+            Command::parse(args(&["export-goals"])),
+            Ok(Command::ExportGoals { path: None })
+        );
+        assert_eq!(
+            Command::parse(args(&["export-goals", "goal.yml"])),
+            Ok(Command::ExportGoals {
+                path: Some(PathBuf::from("goal.yml"))
+            })
+        );
+        assert!(Command::parse(args(&["export-goals", "a.yml", "b.yml"])).is_err());
+    }
+}
@@ -0,0 +1,229 @@
+use std::sync::Arc;
+
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::extract::{Query, State};
+use chrono::{Datelike, Local, Months, NaiveDate};
+use serde::Deserialize;
+
+use crate::{
+    domain::types::{exercise::Exercise, workout::Workout},
+    inbound::frontend_routes::{display::exercise_color, page_error::PageError},
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct CalendarQuery {
+    pub year: Option<i32>,
+    pub month: Option<u32>,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "calendar.html")]
+pub struct CalendarTemplate {
+    title: String,
+    /// Weeks from Monday to Sunday, padded with days outside the month
+    weeks: Vec<Vec<CalendarDay>>,
+    previous_year: i32,
+    previous_month: u32,
+    next_year: i32,
+    next_month: u32,
+    /// Every exercise done during the month
+    legend: Vec<ExerciseDot>,
+}
+
+struct CalendarDay {
+    /// Day of the month, `None` for the padding days
+    number: Option<u32>,
+    is_today: bool,
+    workouts: Vec<CalendarWorkout>,
+}
+
+struct CalendarWorkout {
+    id: u64,
+    dots: Vec<ExerciseDot>,
+}
+
+#[derive(Clone)]
+struct ExerciseDot {
+    name: String,
+    color: String,
+}
+
+impl From<&Exercise> for ExerciseDot {
+    fn from(exercise: &Exercise) -> Self {
+        ExerciseDot {
+            name: exercise.name.clone(),
+            color: exercise_color(exercise.id),
+        }
+    }
+}
+
+impl From<&Workout> for CalendarWorkout {
+    fn from(workout: &Workout) -> Self {
+        let mut exercises: Vec<&Exercise> = Vec::new();
+        for done in &workout.exercises {
+            if !exercises.iter().any(|e| e.id == done.exercise.id) {
+                exercises.push(&done.exercise);
+            }
+        }
+        CalendarWorkout {
+            id: workout.id,
+            dots: exercises.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// Month calendar, `?year=2026&month=3`, showing the current month by default.
+pub async fn calendar_page(
+    State(core_logic): State<Arc<AppState>>,
+    Query(query): Query<CalendarQuery>,
+) -> Result<CalendarTemplate, PageError> {
+    let today = Local::now().date_naive();
+    let first = NaiveDate::from_ymd_opt(
+        query.year.unwrap_or(today.year()),
+        query.month.unwrap_or(today.month()),
+        1,
+    )
+    .ok_or_else(|| PageError::bad_request("Invalid month"))?;
+    let (previous, next) = first
+        .checked_sub_months(Months::new(1))
+        .zip(first.checked_add_months(Months::new(1)))
+        .ok_or_else(|| PageError::bad_request("Invalid month"))?;
+    let last = next.pred_opt().unwrap_or(first);
+
+    let workouts = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workouts_between(first, last)
+        .await?;
+
+    let mut legend: Vec<ExerciseDot> = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+    for done in workouts.iter().flat_map(|workout| &workout.exercises) {
+        if seen.insert(done.exercise.id) {
+            legend.push((&done.exercise).into());
+        }
+    }
+    legend.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let padding = first.weekday().num_days_from_monday() as usize;
+    let mut days: Vec<CalendarDay> = (0..padding).map(|_| CalendarDay::outside()).collect();
+    days.extend(
+        first
+            .iter_days()
+            .take_while(|date| *date <= last)
+            .map(|date| CalendarDay {
+                number: Some(date.day()),
+                is_today: date == today,
+                workouts: workouts
+                    .iter()
+                    .filter(|workout| workout.date == date)
+                    .map(Into::into)
+                    .collect(),
+            }),
+    );
+    while !days.len().is_multiple_of(7) {
+        days.push(CalendarDay::outside());
+    }
+    let mut weeks = Vec::new();
+    let mut days = days.into_iter().peekable();
+    while days.peek().is_some() {
+        weeks.push(days.by_ref().take(7).collect());
+    }
+
+    Ok(CalendarTemplate {
+        title: first.format("%B %Y").to_string(),
+        weeks,
+        previous_year: previous.year(),
+        previous_month: previous.month(),
+        next_year: next.year(),
+        next_month: next.month(),
+        legend,
+    })
+}
+
+impl CalendarDay {
+    fn outside() -> Self {
+        CalendarDay {
+            number: None,
+            is_today: false,
+            workouts: Vec::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::{
+        frontend_routes::display::exercise_color,
+        testing::{app, empty_request, json_request, page_body},
+    };
+
+    #[sqlx::test]
+    async fn calendar_page_links_days_to_their_workout(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0 } } }),
+            ))
+            .await
+            .unwrap();
+        for date in ["2026-02-27", "2026-03-12"] {
+            app.clone()
+                .oneshot(json_request(
+                    "POST",
+                    "/api/workouts",
+                    json!({
+                        "date": date,
+                        "exercises": [{ "name": "Squat", "sets": [{ "weighted": { "weight": 80.0 } }] }]
+                    }),
+                ))
+                .await
+                .unwrap();
+        }
+
+        let response = app
+            .oneshot(empty_request("GET", "/calendar?year=2026&month=3"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains("March 2026"));
+        assert!(page.contains(r#"href="/workouts/2""#));
+        assert!(!page.contains(r#"href="/workouts/1""#));
+        assert!(page.contains(&exercise_color(1)));
+        assert!(page.contains("year=2026&month=2"));
+        assert!(page.contains("year=2026&month=4"));
+    }
+
+    #[sqlx::test]
+    async fn calendar_page_wraps_around_the_year(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/calendar?year=2026&month=12"))
+            .await
+            .unwrap();
+
+        let page = page_body(response).await;
+        assert!(page.contains("year=2026&month=11"));
+        assert!(page.contains("year=2027&month=1"));
+    }
+
+    #[sqlx::test]
+    async fn calendar_page_rejects_invalid_month(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/calendar?year=2026&month=13"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
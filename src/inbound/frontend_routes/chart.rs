@@ -0,0 +1,141 @@
+const WIDTH: f32 = 600.0;
+const HEIGHT: f32 = 200.0;
+/// Room above the bars for the scale
+const TOP: f32 = 16.0;
+/// Room under the bars for their labels
+const BOTTOM: f32 = 20.0;
+/// At most this many bars are labelled so the labels don't overlap
+const MAX_LABELS: usize = 12;
+
+/// Bar chart rendered as inline SVG by `chart.html`, one bar per value.
+pub struct BarChart {
+    pub title: String,
+    pub width: f32,
+    pub height: f32,
+    /// Y of the bottom of the bars
+    pub baseline: f32,
+    /// Value at the top of the scale, with its unit
+    pub max_label: String,
+    pub bars: Vec<ChartBar>,
+}
+
+pub struct ChartBar {
+    pub x: f32,
+    pub y: f32,
+    pub width: f32,
+    pub height: f32,
+    /// Center of the bar, where its label goes
+    pub label_x: f32,
+    /// Label under the bar, empty on the bars skipped to make room
+    pub label: String,
+    /// Label and value, shown when hovering the bar
+    pub tooltip: String,
+}
+
+impl BarChart {
+    /// `values` are `(label, value)` pairs, `None` leaving a gap. The scale goes up to the
+    /// largest value, or `max` when given so charts of bounded values stay comparable.
+    pub fn new(
+        title: impl Into<String>,
+        unit: &str,
+        values: Vec<(String, Option<f32>)>,
+        max: Option<f32>,
+    ) -> Self {
+        let max = max.unwrap_or_else(|| {
+            values
+                .iter()
+                .filter_map(|(_, value)| *value)
+                .fold(0.0, f32::max)
+        });
+        let baseline = HEIGHT - BOTTOM;
+        let slot = WIDTH / values.len().max(1) as f32;
+        let label_every = values.len().div_ceil(MAX_LABELS).max(1);
+        let bars = values
+            .into_iter()
+            .enumerate()
+            .map(|(index, (label, value))| {
+                let height = match value {
+                    Some(value) if max > 0.0 => value.clamp(0.0, max) / max * (baseline - TOP),
+                    _ => 0.0,
+                };
+                let x = index as f32 * slot + slot * 0.1;
+                ChartBar {
+                    x: round(x),
+                    y: round(baseline - height),
+                    width: round(slot * 0.8),
+                    height: round(height),
+                    label_x: round(x + slot * 0.4),
+                    tooltip: match value {
+                        Some(value) => format!("{label}: {} {unit}", round(value)),
+                        None => format!("{label}: -"),
+                    },
+                    label: if index % label_every == 0 {
+                        label
+                    } else {
+                        String::new()
+                    },
+                }
+            })
+            .collect();
+
+        BarChart {
+            title: title.into(),
+            width: WIDTH,
+            height: HEIGHT,
+            baseline,
+            max_label: format!("{} {unit}", round(max)),
+            bars,
+        }
+    }
+}
+
+/// Rounds to a tenth so the SVG doesn't carry float noise.
+fn round(value: f32) -> f32 {
+    (value * 10.0).round() / 10.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bars_are_scaled_to_the_largest_value() {
+        let chart = BarChart::new(
+            "Tonnage",
+            "kg",
+            vec![
+                ("Mar".to_string(), Some(1000.0)),
+                ("Apr".to_string(), None),
+                ("May".to_string(), Some(500.0)),
+            ],
+            None,
+        );
+
+        let heights: Vec<f32> = chart.bars.iter().map(|bar| bar.height).collect();
+        assert_eq!(heights, [164.0, 0.0, 82.0]);
+        assert_eq!(chart.bars[2].y, 98.0);
+        assert_eq!(chart.bars[1].x, 220.0);
+        assert_eq!(chart.bars[1].tooltip, "Apr: -");
+        assert_eq!(chart.max_label, "1000 kg");
+    }
+
+    #[test]
+    fn labels_are_thinned_out_on_crowded_charts() {
+        let values = (1..=26)
+            .map(|week| (format!("W{week}"), Some(1.0)))
+            .collect();
+        let chart = BarChart::new("Sets", "sets", values, Some(10.0));
+
+        let labels: Vec<&str> = chart
+            .bars
+            .iter()
+            .map(|bar| bar.label.as_str())
+            .filter(|label| !label.is_empty())
+            .collect();
+        assert_eq!(
+            labels,
+            ["W1", "W4", "W7", "W10", "W13", "W16", "W19", "W22", "W25"]
+        );
+        assert_eq!(chart.bars[0].height, 16.4);
+    }
+}
@@ -0,0 +1,242 @@
+use std::{collections::HashSet, sync::Arc};
+
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::extract::State;
+use chrono::{Days, Local};
+
+use crate::{
+    domain::services::exercise_progress::{ExerciseProgress, ProgressionProgress},
+    inbound::frontend_routes::{
+        display::{goal_summary, performance_summary},
+        page_error::PageError,
+    },
+    state::AppState,
+};
+
+/// Only the sets of this many last days count as the current level.
+const RECENT_DAYS: u64 = 90;
+
+#[derive(Template, WebTemplate)]
+#[template(path = "dashboard.html")]
+pub struct DashboardTemplate {
+    recent_days: u64,
+    progressions: Vec<ProgressionBar>,
+    exercises: Vec<ExerciseBar>,
+}
+
+struct ExerciseBar {
+    name: String,
+    best: String,
+    goal: String,
+    percent: u32,
+}
+
+struct ProgressionBar {
+    name: String,
+    percent: u32,
+    step: String,
+    current: Option<ExerciseBar>,
+}
+
+fn percent(ratio: f32) -> u32 {
+    (ratio * 100.0).round() as u32
+}
+
+impl From<ExerciseProgress> for ExerciseBar {
+    fn from(progress: ExerciseProgress) -> Self {
+        ExerciseBar {
+            percent: percent(progress.ratio()),
+            best: progress.best.map_or("-".to_string(), |best| {
+                performance_summary(best, &progress.exercise.exercise_type)
+            }),
+            goal: goal_summary(&progress.exercise.exercise_type),
+            name: progress.exercise.name,
+        }
+    }
+}
+
+impl From<ProgressionProgress> for ProgressionBar {
+    fn from(progress: ProgressionProgress) -> Self {
+        let status = &progress.status;
+        let step = match status.current() {
+            Some(exercise) => format!(
+                "Step {} of {}: {}",
+                status.current_step.unwrap_or_default() + 1,
+                status.steps.len(),
+                exercise.name
+            ),
+            None => "Finished".to_string(),
+        };
+        ProgressionBar {
+            percent: percent(progress.ratio()),
+            name: progress.status.name,
+            step,
+            current: progress.current.map(Into::into),
+        }
+    }
+}
+
+/// Progress bar of every exercise towards its goal, progressions taking a single entry.
+pub async fn dashboard_page(
+    State(core_logic): State<Arc<AppState>>,
+) -> Result<DashboardTemplate, PageError> {
+    let (exercises, progressions) = {
+        let exercise_model = core_logic.exercise_model.read().await;
+        (
+            exercise_model.get_all_exercises().await?,
+            exercise_model.get_all_exercise_progressions().await?,
+        )
+    };
+    let today = Local::now().date_naive();
+    let since = today - Days::new(RECENT_DAYS);
+
+    let in_progression: Vec<u64> = progressions
+        .iter()
+        .flat_map(|progression| &progression.progression)
+        .map(|exercise| exercise.id)
+        .collect();
+    // Exercise bars only look at recent workouts, progressions need every workout of their
+    // steps to know which ones are complete.
+    let (recent_workouts, progression_workouts) = {
+        let workout_model = core_logic.workout_model.read().await;
+        (
+            workout_model.get_workouts_between(since, today).await?,
+            workout_model
+                .get_workouts_with_exercises(&in_progression)
+                .await?,
+        )
+    };
+
+    let in_progression: HashSet<u64> = in_progression.into_iter().collect();
+    let exercises = exercises
+        .into_iter()
+        .filter(|exercise| !in_progression.contains(&exercise.id))
+        .map(|exercise| ExerciseProgress::new(exercise, &recent_workouts, since).into())
+        .collect();
+    let progressions = progressions
+        .into_iter()
+        .map(|progression| {
+            ProgressionProgress::new(progression, &progression_workouts, since).into()
+        })
+        .collect();
+
+    Ok(DashboardTemplate {
+        recent_days: RECENT_DAYS,
+        progressions,
+        exercises,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use chrono::{Days, Local};
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request, json_request, page_body};
+
+    #[sqlx::test]
+    async fn dashboard_page_shows_exercise_and_progression_bars(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0 } } }),
+            ))
+            .await
+            .unwrap();
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/progressions",
+                json!({
+                    "name": "Pushup",
+                    "progression": [
+                        { "name": "Knee pushup", "goal": { "bodyweightReps": { "goalReps": 20 } } },
+                        { "name": "Full pushup", "goal": { "bodyweightReps": { "goalReps": 10 } } }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": Local::now().date_naive(),
+                    "exercises": [
+                        { "name": "Squat", "sets": [{ "weighted": { "weight": 80.0 } }] },
+                        { "name": "Knee pushup", "sets": [{ "bodyweightReps": { "reps": 20 } }] },
+                        { "name": "Full pushup", "sets": [{ "bodyweightReps": { "reps": 5 } }] }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app.oneshot(empty_request("GET", "/")).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains(r#"<progress max="100" value="80">"#));
+        assert!(page.contains("Step 2 of 2: Full pushup"));
+        assert!(page.contains(r#"<progress max="100" value="75">"#));
+        assert!(page.contains(r#"<progress class="progress-step" max="100" value="50">"#));
+        assert!(!page.contains("Knee pushup"));
+    }
+
+    #[sqlx::test]
+    async fn dashboard_page_keeps_old_progression_steps_complete(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0 } } }),
+            ))
+            .await
+            .unwrap();
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/progressions",
+                json!({
+                    "name": "Pushup",
+                    "progression": [
+                        { "name": "Knee pushup", "goal": { "bodyweightReps": { "goalReps": 20 } } },
+                        { "name": "Full pushup", "goal": { "bodyweightReps": { "goalReps": 10 } } }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+        let response = app
+            .clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": Local::now().date_naive() - Days::new(200),
+                    "exercises": [
+                        { "name": "Squat", "sets": [{ "weighted": { "weight": 80.0 } }] },
+                        { "name": "Knee pushup", "sets": [{ "bodyweightReps": { "reps": 20 } }] }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        let response = app.oneshot(empty_request("GET", "/")).await.unwrap();
+
+        let page = page_body(response).await;
+        assert!(page.contains("Step 2 of 2: Full pushup"));
+        assert!(!page.contains(r#"<progress max="100" value="80">"#));
+    }
+}
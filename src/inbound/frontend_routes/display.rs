@@ -0,0 +1,230 @@
+use crate::domain::types::{
+    exercise::ExerciseType,
+    personal_record::{PersonalRecord, RecordKind},
+    workout::WorkoutSet,
+    workout_template::SetScheme,
+};
+
+/// Short human readable goal, such as "5 reps, 3 sets" or "5 × 100 kg".
+pub fn goal_summary(exercise_type: &ExerciseType) -> String {
+    let target = match exercise_type {
+        ExerciseType::Weighted {
+            goal_weight,
+            goal_reps: 1,
+            ..
+        } => format!("{goal_weight} kg"),
+        ExerciseType::Weighted {
+            goal_weight,
+            goal_reps,
+            ..
+        } => format!("{goal_reps} × {goal_weight} kg"),
+        ExerciseType::BodyweightReps { goal_reps, .. } => format!("{goal_reps} reps"),
+        ExerciseType::BodyweightTime {
+            goal_duration_seconds,
+            ..
+        } => format!("{goal_duration_seconds} s"),
+    };
+    match exercise_type.goal_sets() {
+        1 => target,
+        sets => format!("{target}, {sets} sets"),
+    }
+}
+
+/// Planned sets in the unit of the exercise, such as "5×5", "3×AMRAP" or "3×30 s".
+pub fn scheme_summary(set_scheme: SetScheme, exercise_type: &ExerciseType) -> String {
+    match (set_scheme.target, exercise_type) {
+        (None, _) => format!("{}×AMRAP", set_scheme.sets),
+        (Some(target), ExerciseType::BodyweightTime { .. }) => {
+            format!("{}×{target} s", set_scheme.sets)
+        }
+        (Some(target), _) => format!("{}×{target}", set_scheme.sets),
+    }
+}
+
+/// A best weight, rep count or hold time in the unit of the exercise's goal.
+pub fn performance_summary(value: f32, exercise_type: &ExerciseType) -> String {
+    match exercise_type {
+        ExerciseType::Weighted { .. } => format!("{value} kg"),
+        ExerciseType::BodyweightReps { .. } => format!("{value} reps"),
+        ExerciseType::BodyweightTime { .. } => format!("{value} s"),
+    }
+}
+
+/// What a personal record beat, such as "Heaviest 5 reps: 85 kg (was 80 kg)".
+pub fn record_summary(record: &PersonalRecord) -> String {
+    let (label, unit) = match record.kind {
+        RecordKind::HeaviestWeight { reps: 1 } => ("Heaviest single".to_string(), "kg"),
+        RecordKind::HeaviestWeight { reps } => (format!("Heaviest {reps} reps"), "kg"),
+        RecordKind::MostReps {
+            weight: Some(weight),
+        } => (format!("Most reps at {weight} kg"), "reps"),
+        RecordKind::MostReps { weight: None } => ("Most reps".to_string(), "reps"),
+        RecordKind::LongestHold => ("Longest hold".to_string(), "s"),
+        RecordKind::BestOneRepMax => ("Best estimated 1RM".to_string(), "kg"),
+        RecordKind::SessionVolume => (
+            "Session volume".to_string(),
+            match record.exercise.exercise_type {
+                ExerciseType::Weighted { .. } => "kg",
+                ExerciseType::BodyweightReps { .. } => "reps",
+                ExerciseType::BodyweightTime { .. } => "s",
+            },
+        ),
+    };
+    // Estimates and volumes carry float noise, a tenth is as precise as weights get.
+    let round = |value: f32| (value * 10.0).round() / 10.0;
+    format!(
+        "{label}: {} {unit} (was {} {unit})",
+        round(record.value),
+        round(record.previous)
+    )
+}
+
+/// Sets of one exercise in a line, such as "3×5 @ 100 kg" or "5/5/4 @ 100 kg" when the reps
+/// differ, falling back to listing every set.
+pub fn sets_summary(sets: &[WorkoutSet]) -> String {
+    let all_same = |key: &dyn Fn(&WorkoutSet) -> String| {
+        sets.windows(2).all(|pair| key(&pair[0]) == key(&pair[1]))
+    };
+    let reps = |set: &WorkoutSet| match set {
+        WorkoutSet::Weighted(set) => set.reps.to_string(),
+        WorkoutSet::BodyweightReps(set) => set.reps.to_string(),
+        WorkoutSet::BodyweightTime(set) => format!("{}s", set.duration_seconds),
+    };
+    let weight = |set: &WorkoutSet| match set {
+        WorkoutSet::Weighted(set) => format!(" @ {} kg", set.weight),
+        _ => String::new(),
+    };
+    let Some(first) = sets.first() else {
+        return String::new();
+    };
+
+    if all_same(&reps) && all_same(&weight) {
+        format!("{}×{}{}", sets.len(), reps(first), weight(first))
+    } else if all_same(&weight) {
+        let reps: Vec<String> = sets.iter().map(reps).collect();
+        format!("{}{}", reps.join("/"), weight(first))
+    } else {
+        let sets: Vec<String> = sets
+            .iter()
+            .map(|set| format!("{}{}", reps(set), weight(set)))
+            .collect();
+        sets.join(", ")
+    }
+}
+
+/// Color of an exercise's dots. Hues are a golden angle apart, so exercises created one after
+/// the other get far apart colors and an exercise keeps its color forever.
+pub fn exercise_color(exercise_id: u64) -> String {
+    let hue = (exercise_id as f64 * 137.508) % 360.0;
+    format!("hsl({hue:.0}, 65%, 55%)")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::workout::{BodyweightTimeSet, WeightedSet};
+
+    fn weighted(reps: u16, weight: f32) -> WorkoutSet {
+        WorkoutSet::Weighted(WeightedSet {
+            id: 0,
+            reps,
+            weight,
+            failure: false,
+        })
+    }
+
+    #[test]
+    fn goal_summary_only_mentions_counts_above_one() {
+        assert_eq!(
+            goal_summary(&ExerciseType::Weighted {
+                goal_weight: 102.5,
+                goal_reps: 1,
+                goal_sets: 1
+            }),
+            "102.5 kg"
+        );
+        assert_eq!(
+            goal_summary(&ExerciseType::Weighted {
+                goal_weight: 80.0,
+                goal_reps: 5,
+                goal_sets: 3
+            }),
+            "5 × 80 kg, 3 sets"
+        );
+        assert_eq!(
+            goal_summary(&ExerciseType::BodyweightTime {
+                goal_duration_seconds: 30,
+                goal_sets: 1
+            }),
+            "30 s"
+        );
+    }
+
+    #[test]
+    fn record_summary_names_what_was_beaten() {
+        let record = |kind, value, previous| PersonalRecord {
+            id: 1,
+            workout_id: 1,
+            date: "2026-03-08".parse().unwrap(),
+            exercise: crate::domain::types::exercise::Exercise {
+                id: 1,
+                name: "Squat".to_string(),
+                exercise_type: ExerciseType::Weighted {
+                    goal_weight: 100.0,
+                    goal_reps: 5,
+                    goal_sets: 1,
+                },
+            },
+            kind,
+            value,
+            previous,
+        };
+
+        assert_eq!(
+            record_summary(&record(RecordKind::HeaviestWeight { reps: 5 }, 85.0, 82.5)),
+            "Heaviest 5 reps: 85 kg (was 82.5 kg)"
+        );
+        assert_eq!(
+            record_summary(&record(
+                RecordKind::MostReps { weight: Some(80.0) },
+                8.0,
+                5.0
+            )),
+            "Most reps at 80 kg: 8 reps (was 5 reps)"
+        );
+        assert_eq!(
+            record_summary(&record(RecordKind::BestOneRepMax, 101.33334, 100.0)),
+            "Best estimated 1RM: 101.3 kg (was 100 kg)"
+        );
+    }
+
+    #[test]
+    fn exercise_color_is_stable_and_distinct() {
+        assert_eq!(exercise_color(3), exercise_color(3));
+        assert_ne!(exercise_color(1), exercise_color(2));
+    }
+
+    #[test]
+    fn sets_summary_groups_identical_sets() {
+        assert_eq!(
+            sets_summary(&[weighted(5, 100.0), weighted(5, 100.0), weighted(5, 100.0)]),
+            "3×5 @ 100 kg"
+        );
+        assert_eq!(
+            sets_summary(&[weighted(5, 100.0), weighted(4, 100.0)]),
+            "5/4 @ 100 kg"
+        );
+        assert_eq!(
+            sets_summary(&[weighted(5, 100.0), weighted(3, 110.0)]),
+            "5 @ 100 kg, 3 @ 110 kg"
+        );
+        assert_eq!(
+            sets_summary(&[WorkoutSet::BodyweightTime(BodyweightTimeSet {
+                id: 0,
+                duration_seconds: 30,
+                failure: true,
+            })]),
+            "1×30s"
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use crate::{
+    domain::types::exercise::{Exercise, ExerciseType},
+    inbound::frontend_routes::workout_form::positive,
+};
+
+/// Values of the name and goal inputs of an exercise, kept as typed so a rejected submission
+/// can be shown again.
+///
+/// Inputs are named `name`, `kind`, `goal_weight`, `goal_reps`, `goal_duration` and
+/// `goal_sets`, after `prefix` which tells the steps of a progression apart.
+#[derive(Debug, Clone)]
+pub struct ExerciseFields {
+    pub prefix: String,
+    /// 0 for an exercise that isn't saved yet
+    pub id: u64,
+    pub name: String,
+    /// `weighted`, `bodyweightReps` or `bodyweightTime`
+    pub kind: String,
+    pub goal_weight: String,
+    pub goal_reps: String,
+    pub goal_duration: String,
+    pub goal_sets: String,
+}
+
+impl ExerciseFields {
+    pub fn blank(prefix: impl Into<String>) -> Self {
+        ExerciseFields {
+            prefix: prefix.into(),
+            id: 0,
+            name: String::new(),
+            kind: "weighted".to_string(),
+            goal_weight: String::new(),
+            goal_reps: String::new(),
+            goal_duration: String::new(),
+            goal_sets: "1".to_string(),
+        }
+    }
+
+    pub fn from_exercise(prefix: impl Into<String>, exercise: &Exercise) -> Self {
+        let blank = ExerciseFields {
+            id: exercise.id,
+            name: exercise.name.clone(),
+            goal_sets: exercise.exercise_type.goal_sets().to_string(),
+            ..Self::blank(prefix)
+        };
+        match exercise.exercise_type {
+            ExerciseType::Weighted {
+                goal_weight,
+                goal_reps,
+                ..
+            } => ExerciseFields {
+                goal_weight: goal_weight.to_string(),
+                goal_reps: goal_reps.to_string(),
+                ..blank
+            },
+            ExerciseType::BodyweightReps { goal_reps, .. } => ExerciseFields {
+                kind: "bodyweightReps".to_string(),
+                goal_reps: goal_reps.to_string(),
+                ..blank
+            },
+            ExerciseType::BodyweightTime {
+                goal_duration_seconds,
+                ..
+            } => ExerciseFields {
+                kind: "bodyweightTime".to_string(),
+                goal_duration: goal_duration_seconds.to_string(),
+                ..blank
+            },
+        }
+    }
+
+    /// Stores the value of the input called `field`, once the prefix is removed. Returns
+    /// whether the field is one of the exercise's.
+    pub fn set(&mut self, field: &str, value: String) -> bool {
+        match field {
+            "id" => self.id = value.parse().unwrap_or(0),
+            "name" => self.name = value,
+            "kind" => self.kind = value,
+            "goal_weight" => self.goal_weight = value,
+            "goal_reps" => self.goal_reps = value,
+            "goal_duration" => self.goal_duration = value,
+            "goal_sets" => self.goal_sets = value,
+            _ => return false,
+        }
+        true
+    }
+
+    /// Checks the typed values. Blank reps of a weighted goal and blank sets count as 1, like
+    /// in the API and the goal file.
+    pub fn to_exercise(&self) -> Result<Exercise, Vec<String>> {
+        let mut errors = Vec::new();
+        let name = self.name.trim();
+        if name.is_empty() {
+            errors.push("Name must not be empty".to_string());
+        }
+        let goal_sets = or_one(&self.goal_sets, "Goal sets").unwrap_or_else(|e| {
+            errors.push(e);
+            1
+        });
+        let exercise_type = match self.kind.as_str() {
+            "weighted" => positive(&self.goal_weight, "Goal weight").and_then(|goal_weight| {
+                Ok(ExerciseType::Weighted {
+                    goal_weight,
+                    goal_reps: or_one(&self.goal_reps, "Goal reps")?,
+                    goal_sets,
+                })
+            }),
+            "bodyweightReps" => positive(&self.goal_reps, "Goal reps").map(|goal_reps| {
+                ExerciseType::BodyweightReps {
+                    goal_reps,
+                    goal_sets,
+                }
+            }),
+            "bodyweightTime" => {
+                positive(&self.goal_duration, "Goal duration").map(|goal_duration_seconds| {
+                    ExerciseType::BodyweightTime {
+                        goal_duration_seconds,
+                        goal_sets,
+                    }
+                })
+            }
+            _ => Err("Pick the kind of exercise".to_string()),
+        };
+
+        match exercise_type {
+            Ok(exercise_type) if errors.is_empty() => Ok(Exercise {
+                id: self.id,
+                name: name.to_string(),
+                exercise_type,
+            }),
+            Ok(_) => Err(errors),
+            Err(e) => {
+                errors.push(e);
+                Err(errors)
+            }
+        }
+    }
+}
+
+fn or_one(value: &str, what: &str) -> Result<u16, String> {
+    match value.trim() {
+        "" => Ok(1),
+        value => positive(value, what),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields(kind: &str) -> ExerciseFields {
+        ExerciseFields {
+            name: " Squat ".to_string(),
+            kind: kind.to_string(),
+            ..ExerciseFields::blank("")
+        }
+    }
+
+    #[test]
+    fn to_exercise_defaults_reps_and_sets_to_one() {
+        let exercise = ExerciseFields {
+            goal_weight: "102.5".to_string(),
+            goal_sets: String::new(),
+            ..fields("weighted")
+        }
+        .to_exercise()
+        .expect("fields should be valid");
+
+        assert_eq!(exercise.name, "Squat");
+        assert_eq!(
+            exercise.exercise_type,
+            ExerciseType::Weighted {
+                goal_weight: 102.5,
+                goal_reps: 1,
+                goal_sets: 1
+            }
+        );
+    }
+
+    #[test]
+    fn to_exercise_only_reads_inputs_of_the_kind() {
+        let exercise = ExerciseFields {
+            goal_weight: "heavy".to_string(),
+            goal_duration: "30".to_string(),
+            goal_sets: "3".to_string(),
+            ..fields("bodyweightTime")
+        }
+        .to_exercise()
+        .expect("fields should be valid");
+
+        assert_eq!(
+            exercise.exercise_type,
+            ExerciseType::BodyweightTime {
+                goal_duration_seconds: 30,
+                goal_sets: 3
+            }
+        );
+    }
+
+    #[test]
+    fn to_exercise_lists_every_problem() {
+        let errors = ExerciseFields {
+            name: String::new(),
+            goal_sets: "0".to_string(),
+            ..fields("bodyweightReps")
+        }
+        .to_exercise()
+        .expect_err("fields should be invalid");
+
+        assert_eq!(
+            errors,
+            [
+                "Name must not be empty",
+                "Goal sets must be a number greater than 0",
+                "Goal reps must be a number greater than 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn from_exercise_round_trips() {
+        let exercise = Exercise {
+            id: 3,
+            name: "Pull-up".to_string(),
+            exercise_type: ExerciseType::BodyweightReps {
+                goal_reps: 8,
+                goal_sets: 3,
+            },
+        };
+
+        let fields = ExerciseFields::from_exercise("s0.", &exercise);
+
+        assert_eq!(fields.kind, "bodyweightReps");
+        assert_eq!(fields.to_exercise(), Ok(exercise));
+    }
+}
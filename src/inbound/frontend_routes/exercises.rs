@@ -0,0 +1,385 @@
+use std::{collections::HashSet, sync::Arc};
+
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    Form,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Redirect, Response},
+};
+
+use crate::{
+    domain::{traits::exercise_model::ExerciseModelError, types::exercise::Exercise},
+    inbound::frontend_routes::{
+        display::goal_summary, exercise_form::ExerciseFields, page_error::PageError,
+    },
+    state::AppState,
+};
+
+#[derive(Template, WebTemplate)]
+#[template(path = "exercises.html")]
+pub struct ExerciseListTemplate {
+    /// Exercises outside of any progression
+    exercises: Vec<CatalogueExercise>,
+    progressions: Vec<CatalogueProgression>,
+}
+
+struct CatalogueExercise {
+    id: u64,
+    name: String,
+    goal: String,
+}
+
+struct CatalogueProgression {
+    name: String,
+    steps: Vec<CatalogueExercise>,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "exercise.html")]
+pub struct ExerciseTemplate {
+    heading: String,
+    action: String,
+    fields: ExerciseFields,
+    errors: Vec<String>,
+}
+
+impl ExerciseTemplate {
+    fn new(fields: ExerciseFields, errors: Vec<String>) -> Self {
+        let (heading, action) = match fields.id {
+            0 => ("New exercise".to_string(), "/exercises/new".to_string()),
+            id => (
+                format!("Edit {}", fields.name),
+                format!("/exercises/{id}/edit"),
+            ),
+        };
+        ExerciseTemplate {
+            heading,
+            action,
+            fields,
+            errors,
+        }
+    }
+
+    /// The form shown again with `errors`, as a 422.
+    fn rejected(fields: ExerciseFields, errors: Vec<String>) -> Response {
+        (StatusCode::UNPROCESSABLE_ENTITY, Self::new(fields, errors)).into_response()
+    }
+}
+
+fn read_fields(id: u64, fields: Vec<(String, String)>) -> ExerciseFields {
+    let mut exercise = ExerciseFields {
+        id,
+        ..ExerciseFields::blank("")
+    };
+    for (name, value) in fields {
+        if name != "id" {
+            exercise.set(&name, value);
+        }
+    }
+    exercise
+}
+
+/// Message shown next to the form when a name is already used by another exercise.
+pub fn duplicate_name_error(name: &str) -> String {
+    format!("An exercise called {} already exists", name.trim())
+}
+
+pub async fn exercise_list_page(
+    State(core_logic): State<Arc<AppState>>,
+) -> Result<ExerciseListTemplate, PageError> {
+    let exercise_model = core_logic.exercise_model.read().await;
+    let progressions = exercise_model.get_all_exercise_progressions().await?;
+    let in_progression: HashSet<u64> = progressions
+        .iter()
+        .flat_map(|progression| progression.progression.iter().map(|exercise| exercise.id))
+        .collect();
+    let to_catalogue = |exercise: &Exercise| CatalogueExercise {
+        id: exercise.id,
+        name: exercise.name.clone(),
+        goal: goal_summary(&exercise.exercise_type),
+    };
+
+    Ok(ExerciseListTemplate {
+        exercises: exercise_model
+            .get_all_exercises()
+            .await?
+            .iter()
+            .filter(|exercise| !in_progression.contains(&exercise.id))
+            .map(to_catalogue)
+            .collect(),
+        progressions: progressions
+            .iter()
+            .map(|progression| CatalogueProgression {
+                name: progression.name.clone(),
+                steps: progression.progression.iter().map(to_catalogue).collect(),
+            })
+            .collect(),
+    })
+}
+
+pub async fn new_exercise_page() -> ExerciseTemplate {
+    ExerciseTemplate::new(ExerciseFields::blank(""), Vec::new())
+}
+
+pub async fn create_exercise_action(
+    State(core_logic): State<Arc<AppState>>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Response, PageError> {
+    let fields = read_fields(0, fields);
+    let exercise = match fields.to_exercise() {
+        Ok(exercise) => exercise,
+        Err(errors) => return Ok(ExerciseTemplate::rejected(fields, errors)),
+    };
+    match core_logic
+        .exercise_model
+        .write()
+        .await
+        .add_exercise(exercise)
+        .await
+    {
+        Ok(_) => Ok(Redirect::to("/exercises").into_response()),
+        Err(ExerciseModelError::AlreadyExists) => {
+            let errors = vec![duplicate_name_error(&fields.name)];
+            Ok(ExerciseTemplate::rejected(fields, errors))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub async fn edit_exercise_page(
+    State(core_logic): State<Arc<AppState>>,
+    Path(exercise_id): Path<u64>,
+) -> Result<ExerciseTemplate, PageError> {
+    let exercise = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_exercise_by_id(exercise_id)
+        .await?;
+    Ok(ExerciseTemplate::new(
+        ExerciseFields::from_exercise("", &exercise),
+        Vec::new(),
+    ))
+}
+
+pub async fn update_exercise_action(
+    State(core_logic): State<Arc<AppState>>,
+    Path(exercise_id): Path<u64>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Response, PageError> {
+    let fields = read_fields(exercise_id, fields);
+    let exercise = match fields.to_exercise() {
+        Ok(exercise) => exercise,
+        Err(errors) => return Ok(ExerciseTemplate::rejected(fields, errors)),
+    };
+    match core_logic
+        .exercise_model
+        .write()
+        .await
+        .update_exercise(exercise)
+        .await
+    {
+        Ok(()) => Ok(Redirect::to("/exercises").into_response()),
+        Err(ExerciseModelError::AlreadyExists) => {
+            let errors = vec![duplicate_name_error(&fields.name)];
+            Ok(ExerciseTemplate::rejected(fields, errors))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Deletes the exercise along with the sets logged for it.
+pub async fn delete_exercise_action(
+    State(core_logic): State<Arc<AppState>>,
+    Path(exercise_id): Path<u64>,
+) -> Result<Redirect, PageError> {
+    core_logic
+        .exercise_model
+        .write()
+        .await
+        .delete_exercise(exercise_id)
+        .await?;
+    Ok(Redirect::to("/exercises"))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{
+        app, empty_request, form_request, json_body, json_request, page_body,
+    };
+
+    async fn seed(app: &axum::Router) {
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0, "goalReps": 5 } } }),
+            ))
+            .await
+            .unwrap();
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/progressions",
+                json!({
+                    "name": "Handstand",
+                    "progression": [
+                        { "name": "Wall handstand", "goal": { "bodyweightTime": { "goalDurationSeconds": 60 } } }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn exercise_list_page_lists_exercises_and_progressions(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(empty_request("GET", "/exercises"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains(r#"href="/exercises/1/edit""#));
+        assert!(page.contains("5 × 100 kg"));
+        assert!(page.contains(r#"href="/progressions/Handstand/edit""#));
+        assert_eq!(page.matches("Wall handstand").count(), 1);
+    }
+
+    #[sqlx::test]
+    async fn create_exercise_action_saves_exercise(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/exercises/new",
+                &[
+                    ("name", "Pull-up"),
+                    ("kind", "bodyweightReps"),
+                    ("goal_weight", ""),
+                    ("goal_reps", "10"),
+                    ("goal_duration", ""),
+                    ("goal_sets", "3"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers()["location"], "/exercises");
+        let response = app
+            .oneshot(empty_request("GET", "/api/exercises/1"))
+            .await
+            .unwrap();
+        assert_eq!(
+            json_body(response).await["goal"],
+            json!({ "bodyweightReps": { "goalReps": 10, "goalSets": 3 } })
+        );
+    }
+
+    #[sqlx::test]
+    async fn create_exercise_action_shows_duplicate_name_inline(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(form_request(
+                "/exercises/new",
+                &[
+                    ("name", "Squat"),
+                    ("kind", "weighted"),
+                    ("goal_weight", "90"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let page = page_body(response).await;
+        assert!(page.contains("An exercise called Squat already exists"));
+        assert!(page.contains(r#"value="90""#));
+    }
+
+    #[sqlx::test]
+    async fn update_exercise_action_shows_invalid_goal_inline(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(form_request(
+                "/exercises/1/edit",
+                &[
+                    ("name", "Squat"),
+                    ("kind", "weighted"),
+                    ("goal_weight", "0"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let page = page_body(response).await;
+        assert!(page.contains("Goal weight must be a number greater than 0"));
+        assert!(page.contains(r#"action="/exercises/1/edit""#));
+    }
+
+    #[sqlx::test]
+    async fn update_exercise_action_renames_exercise(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/exercises/1/edit",
+                &[
+                    ("name", "Back squat"),
+                    ("kind", "weighted"),
+                    ("goal_weight", "110"),
+                    ("goal_reps", "5"),
+                    ("goal_sets", "3"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        let response = app
+            .oneshot(empty_request("GET", "/api/exercises/1"))
+            .await
+            .unwrap();
+        let body = json_body(response).await;
+        assert_eq!(body["name"], "Back squat");
+        assert_eq!(body["goal"]["weighted"]["goalSets"], 3);
+    }
+
+    #[sqlx::test]
+    async fn delete_exercise_action_removes_exercise(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request("/exercises/1/delete", &[]))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        let response = app
+            .oneshot(empty_request("GET", "/exercises/1/edit"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -1 +1,16 @@
+pub mod calendar;
+pub mod chart;
+pub mod dashboard;
+pub mod display;
+pub mod exercise_form;
+pub mod exercises;
+pub mod mood;
 pub mod new_workout;
+pub mod page_error;
+pub mod progressions;
+pub mod records;
+pub mod stats;
+pub mod workout_edit;
+pub mod workout_form;
+pub mod workout_history;
+pub mod workout_templates;
@@ -0,0 +1,222 @@
+use std::sync::Arc;
+
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::extract::{Query, State};
+use chrono::{Local, NaiveDate};
+use serde::Deserialize;
+
+use crate::{
+    domain::services::{
+        mood_report::{MoodPerformance, MoodReport},
+        training_stats::StatsGrouping,
+    },
+    inbound::frontend_routes::{chart::BarChart, page_error::PageError},
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct MoodQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "mood.html")]
+pub struct MoodTemplate {
+    from: NaiveDate,
+    to: NaiveDate,
+    sessions: usize,
+    goal_correlation: String,
+    failure_correlation: String,
+    by_mood: Vec<MoodRow>,
+    charts: Vec<BarChart>,
+}
+
+struct MoodRow {
+    mood: u8,
+    workouts: u32,
+    /// Percentages, rounded
+    goal_reached: f32,
+    failure_ratio: f32,
+}
+
+impl From<&MoodPerformance> for MoodRow {
+    fn from(performance: &MoodPerformance) -> Self {
+        MoodRow {
+            mood: performance.mood,
+            workouts: performance.workouts,
+            goal_reached: (performance.goal_reached * 100.0).round(),
+            failure_ratio: (performance.failure_ratio * 100.0).round(),
+        }
+    }
+}
+
+/// Mood of the workouts set against how they went, `?from=&to=`, covering the last 12 weeks
+/// by default.
+pub async fn mood_page(
+    State(core_logic): State<Arc<AppState>>,
+    Query(query): Query<MoodQuery>,
+) -> Result<MoodTemplate, PageError> {
+    let to = query.to.unwrap_or_else(|| Local::now().date_naive());
+    let from = query
+        .from
+        .unwrap_or_else(|| StatsGrouping::Week.default_from(to));
+    StatsGrouping::Week
+        .check_range(from, to)
+        .map_err(PageError::bad_request)?;
+
+    let workouts = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workouts_between(from, to)
+        .await?;
+    let report = MoodReport::new(from, to, &workouts);
+
+    Ok(MoodTemplate {
+        from,
+        to,
+        sessions: report.sessions.len(),
+        goal_correlation: correlation_summary(report.goal_correlation),
+        failure_correlation: correlation_summary(report.failure_correlation),
+        by_mood: report.by_mood.iter().map(Into::into).collect(),
+        charts: charts(&report),
+    })
+}
+
+/// How strongly two things go together, such as "strong positive (r = 0.82)".
+fn correlation_summary(correlation: Option<f32>) -> String {
+    let Some(r) = correlation else {
+        return "not enough workouts with a mood yet".to_string();
+    };
+    let strength = match r.abs() {
+        strength if strength >= 0.7 => "strong",
+        strength if strength >= 0.4 => "moderate",
+        strength if strength >= 0.2 => "weak",
+        _ => return format!("no clear link (r = {r:.2})"),
+    };
+    let direction = if r > 0.0 { "positive" } else { "negative" };
+    format!("{strength} {direction} (r = {r:.2})")
+}
+
+fn charts(report: &MoodReport) -> Vec<BarChart> {
+    let by_mood = |value: &dyn Fn(&MoodPerformance) -> f32| {
+        (1..=10)
+            .map(|mood| {
+                let performance = report
+                    .by_mood
+                    .iter()
+                    .find(|performance| performance.mood == mood);
+                (mood.to_string(), performance.map(value))
+            })
+            .collect()
+    };
+
+    vec![
+        BarChart::new(
+            "Goal reached by mood",
+            "%",
+            by_mood(&|performance| performance.goal_reached * 100.0),
+            Some(100.0),
+        ),
+        BarChart::new(
+            "Failure sets by mood",
+            "%",
+            by_mood(&|performance| performance.failure_ratio * 100.0),
+            Some(100.0),
+        ),
+        BarChart::new(
+            "Mood by week",
+            "/ 10",
+            report
+                .by_week
+                .iter()
+                .map(|week| (week.start.format("%-d %b").to_string(), week.average_mood))
+                .collect(),
+            Some(10.0),
+        ),
+        BarChart::new(
+            "Mood by weekday",
+            "/ 10",
+            report
+                .by_weekday
+                .iter()
+                .map(|day| (day.weekday.to_string(), day.average_mood))
+                .collect(),
+            Some(10.0),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use super::correlation_summary;
+    use crate::inbound::testing::{app, empty_request, json_request, page_body};
+
+    #[test]
+    fn correlation_summary_describes_strength_and_direction() {
+        assert_eq!(
+            correlation_summary(Some(0.82)),
+            "strong positive (r = 0.82)"
+        );
+        assert_eq!(
+            correlation_summary(Some(-0.45)),
+            "moderate negative (r = -0.45)"
+        );
+        assert_eq!(correlation_summary(Some(0.1)), "no clear link (r = 0.10)");
+        assert_eq!(
+            correlation_summary(None),
+            "not enough workouts with a mood yet"
+        );
+    }
+
+    #[sqlx::test]
+    async fn mood_page_charts_performance_by_mood(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Pull-up", "goal": { "bodyweightReps": { "goalReps": 10 } } }),
+            ))
+            .await
+            .unwrap();
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": "2026-03-04",
+                    "mood": 6,
+                    "exercises": [{
+                        "name": "Pull-up",
+                        "sets": [
+                            { "bodyweightReps": { "reps": 8 } },
+                            { "bodyweightReps": { "reps": 6, "failure": true } }
+                        ]
+                    }]
+                }),
+            ))
+            .await
+            .unwrap();
+
+        let response = app
+            .oneshot(empty_request("GET", "/mood?from=2026-03-02&to=2026-03-15"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert_eq!(page.matches("<svg").count(), 4);
+        assert!(page.contains("<title>6: 80 %</title>"));
+        assert!(page.contains("<title>6: 50 %</title>"));
+        assert!(page.contains("<title>Wed: 6 / 10</title>"));
+        assert!(page.contains("<td>80 %</td>"));
+        assert!(page.contains("not enough workouts with a mood yet"));
+    }
+}
@@ -1,45 +1,478 @@
+use std::sync::Arc;
+
 use askama::Template;
 use askama_web::WebTemplate;
+use axum::{
+    Form,
+    extract::{Query, State},
+    http::StatusCode,
+    response::{IntoResponse, Redirect, Response},
+};
+use chrono::Local;
+use serde::Deserialize;
 
-use crate::domain::types::{
-    exercise::{Exercise, ExerciseType},
-    workout_template::WorkoutTemplate,
+use crate::{
+    domain::{services::progression_status::ProgressionStatus, types::exercise::ExerciseType},
+    inbound::frontend_routes::{
+        display::goal_summary,
+        page_error::PageError,
+        workout_form::{ExerciseForm, WorkoutForm},
+    },
+    state::AppState,
 };
 
+#[derive(Debug, Deserialize)]
+pub struct NewWorkoutQuery {
+    pub template: Option<u64>,
+}
+
 #[derive(Template, WebTemplate)]
 #[template(path = "new_workout.html")]
 pub struct NewWorkoutTemplate {
-    workout_plan: WorkoutTemplate,
-}
-
-pub async fn new_workout_page() -> NewWorkoutTemplate {
-    NewWorkoutTemplate {
-        workout_plan: workout_template(),
-    }
-}
-
-fn workout_template() -> WorkoutTemplate {
-    WorkoutTemplate {
-        id: 1,
-        name: "Legs and Handstand".to_string(),
-        exercises: vec![
-            Exercise {
-                id: 1,
-                name: "squat".to_string(),
-                exercise_type: ExerciseType::Weighted { goal_weight: 100.0 },
-            },
-            Exercise {
-                id: 2,
-                name: "handstand pushup".to_string(),
-                exercise_type: ExerciseType::BodyweightReps { goal_reps: 2 },
-            },
-            Exercise {
-                id: 3,
-                name: "handstand".to_string(),
-                exercise_type: ExerciseType::BodyweightTime {
-                    goal_duration_seconds: 12,
-                },
-            },
-        ],
+    templates: Vec<TemplateChoice>,
+    /// `None` until a template is picked
+    form: Option<WorkoutForm>,
+    errors: Vec<String>,
+    next_steps: Vec<NextStep>,
+}
+
+struct TemplateChoice {
+    id: u64,
+    name: String,
+    exercises: String,
+}
+
+/// The step to work on in a progression that isn't finished yet.
+pub struct NextStep {
+    progression: String,
+    exercise: String,
+    goal: String,
+    step: usize,
+    steps: usize,
+}
+
+impl NextStep {
+    fn from_status(status: &ProgressionStatus) -> Option<Self> {
+        let exercise = status.current()?;
+        Some(NextStep {
+            progression: status.name.clone(),
+            exercise: exercise.name.clone(),
+            goal: goal_summary(&exercise.exercise_type),
+            step: status.current_step? + 1,
+            steps: status.steps.len(),
+        })
+    }
+}
+
+impl NewWorkoutTemplate {
+    async fn new(
+        core_logic: &AppState,
+        form: Option<WorkoutForm>,
+        errors: Vec<String>,
+    ) -> Result<Self, PageError> {
+        let templates = core_logic
+            .workout_plan_model
+            .read()
+            .await
+            .get_all_workout_templates()
+            .await?
+            .into_iter()
+            .map(|template| TemplateChoice {
+                id: template.id,
+                exercises: template
+                    .exercises
+                    .iter()
+                    .map(|planned| planned.exercise.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                name: template.name,
+            })
+            .collect();
+
+        let progressions = core_logic
+            .exercise_model
+            .read()
+            .await
+            .get_all_exercise_progressions()
+            .await?;
+        let exercise_ids: Vec<u64> = progressions
+            .iter()
+            .flat_map(|progression| &progression.progression)
+            .map(|step| step.id)
+            .collect();
+        let workouts = core_logic
+            .workout_model
+            .read()
+            .await
+            .get_workouts_with_exercises(&exercise_ids)
+            .await?;
+        let next_steps = progressions
+            .into_iter()
+            .filter_map(|progression| {
+                NextStep::from_status(&ProgressionStatus::new(progression, &workouts))
+            })
+            .collect();
+
+        Ok(NewWorkoutTemplate {
+            templates,
+            form,
+            errors,
+            next_steps,
+        })
+    }
+}
+
+/// Lists the templates to start from, or the form of `?template=` once one is picked. The
+/// form starts with the sets done last time for each exercise.
+pub async fn new_workout_page(
+    State(core_logic): State<Arc<AppState>>,
+    Query(query): Query<NewWorkoutQuery>,
+) -> Result<NewWorkoutTemplate, PageError> {
+    let form = match query.template {
+        None => None,
+        Some(template_id) => {
+            let template = core_logic
+                .workout_plan_model
+                .read()
+                .await
+                .get_workout_template(template_id)
+                .await?;
+            let today = Local::now().date_naive();
+            let workout_model = core_logic.workout_model.read().await;
+            let mut exercises = Vec::with_capacity(template.exercises.len());
+            for (index, planned) in template.exercises.into_iter().enumerate() {
+                let last_sets = workout_model
+                    .get_last_sets_for_exercise(planned.exercise.id, today)
+                    .await?;
+                exercises.push(ExerciseForm::prefilled(
+                    index,
+                    planned.exercise,
+                    planned.set_scheme,
+                    &last_sets,
+                ));
+            }
+            Some(WorkoutForm {
+                template_id: Some(template.id),
+                template_name: template.name,
+                date: today.to_string(),
+                mood: String::new(),
+                exercises,
+            })
+        }
+    };
+    NewWorkoutTemplate::new(&core_logic, form, Vec::new()).await
+}
+
+/// Saves the submitted workout, showing the form again with the problems if it is invalid.
+pub async fn save_new_workout(
+    State(core_logic): State<Arc<AppState>>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Response, PageError> {
+    let mut form =
+        WorkoutForm::from_fields(fields, core_logic.exercise_model.read().await.as_ref()).await?;
+    let template_errors = form
+        .check_template(core_logic.workout_plan_model.read().await.as_ref())
+        .await?;
+    let workout = match form.to_new_workout() {
+        Ok(workout) if template_errors.is_empty() => workout,
+        result => {
+            let errors = template_errors
+                .into_iter()
+                .chain(result.err().unwrap_or_default())
+                .collect();
+            let page = NewWorkoutTemplate::new(&core_logic, Some(form), errors).await?;
+            return Ok((StatusCode::UNPROCESSABLE_ENTITY, page).into_response());
+        }
+    };
+
+    let workout_id = core_logic
+        .workout_model
+        .write()
+        .await
+        .create_workout(workout)
+        .await?;
+    Ok(Redirect::to(&format!("/workouts/{workout_id}")).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{
+        app, empty_request, form_request, json_body, json_request, page_body,
+    };
+
+    async fn seed_template(app: &axum::Router) {
+        for exercise in [
+            json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0, "goalReps": 5, "goalSets": 3 } } }),
+            json!({ "name": "Handstand", "goal": { "bodyweightTime": { "goalDurationSeconds": 30 } } }),
+        ] {
+            app.clone()
+                .oneshot(json_request("POST", "/api/exercises", exercise))
+                .await
+                .unwrap();
+        }
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/templates",
+                json!({ "name": "Legs and Handstand", "exerciseIds": [1, 2] }),
+            ))
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn new_workout_page_lists_templates_to_pick(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed_template(&app).await;
+
+        let response = app.oneshot(empty_request("GET", "/new")).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains(r#"href="/new?template=1""#));
+        assert!(page.contains("Squat, Handstand"));
+        assert!(!page.contains("<form"));
+    }
+
+    #[sqlx::test]
+    async fn new_workout_page_renders_unique_inputs_per_set(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed_template(&app).await;
+
+        let response = app
+            .oneshot(empty_request("GET", "/new?template=1"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains(r#"name="e0.id" value="1""#));
+        assert!(page.contains(r#"id="e0s2-weight""#));
+        assert!(page.contains(r#"id="e0s2-reps""#));
+        assert!(page.contains(r#"id="e1s0-duration""#));
+        assert!(!page.contains(r#"id="e1s1-duration""#));
+    }
+
+    #[sqlx::test]
+    async fn new_workout_page_shows_next_progression_step(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/progressions",
+                json!({
+                    "name": "Handstand",
+                    "progression": [
+                        { "name": "Wall handstand", "goal": { "bodyweightTime": { "goalDurationSeconds": 60 } } },
+                        { "name": "Free handstand", "goal": { "bodyweightTime": { "goalDurationSeconds": 30, "goalSets": 3 } } }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": "2026-03-07",
+                    "exercises": [{
+                        "name": "Wall handstand",
+                        "sets": [{ "bodyweightTime": { "durationSeconds": 65 } }]
+                    }]
+                }),
+            ))
+            .await
+            .unwrap();
+
+        let response = app.oneshot(empty_request("GET", "/new")).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains("Free handstand"));
+        assert!(page.contains("30 s, 3 sets"));
+        assert!(!page.contains("Wall handstand"));
+    }
+
+    #[sqlx::test]
+    async fn new_workout_page_prefills_sets_from_last_session(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed_template(&app).await;
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": "2026-03-07",
+                    "exercises": [{
+                        "name": "Squat",
+                        "sets": [
+                            { "weighted": { "reps": 5, "weight": 82.5 } },
+                            { "weighted": { "reps": 4, "weight": 85.0, "failure": true } }
+                        ]
+                    }]
+                }),
+            ))
+            .await
+            .unwrap();
+
+        let response = app
+            .oneshot(empty_request("GET", "/new?template=1"))
+            .await
+            .unwrap();
+
+        let page = page_body(response).await;
+        assert!(page.contains(r#"id="e0s0-weight" value="82.5""#));
+        assert!(page.contains(r#"id="e0s1-reps" value="4""#));
+        assert!(!page.contains(r#"id="e0s2-weight""#));
+        assert!(!page.contains("checked"));
+        assert!(page.contains(r#"id="e1s0-duration" value="""#));
+    }
+
+    #[sqlx::test]
+    async fn new_workout_page_renders_a_row_per_planned_set(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed_template(&app).await;
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/templates",
+                json!({ "name": "Volume", "exerciseIds": [1], "setSchemes": { "1": "5x5" } }),
+            ))
+            .await
+            .unwrap();
+
+        let response = app
+            .oneshot(empty_request("GET", "/new?template=2"))
+            .await
+            .unwrap();
+
+        let page = page_body(response).await;
+        assert!(page.contains("planned 5×5"));
+        assert!(page.contains(r#"id="e0s4-reps" value="5""#));
+        assert!(!page.contains(r#"id="e0s5-reps""#));
+    }
+
+    #[sqlx::test]
+    async fn new_workout_page_returns_not_found_for_unknown_template(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/new?template=9"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test]
+    async fn save_new_workout_creates_the_workout(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed_template(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/new",
+                &[
+                    ("template", "1"),
+                    ("date", "2026-03-07"),
+                    ("mood", "7"),
+                    ("e0.id", "1"),
+                    ("e0.s0.weight", "80"),
+                    ("e0.s0.reps", "5"),
+                    ("e0.s1.weight", "85"),
+                    ("e0.s1.reps", "4"),
+                    ("e0.s1.failure", "on"),
+                    ("e0.s2.weight", ""),
+                    ("e0.s2.reps", ""),
+                    ("e1.id", "2"),
+                    ("e1.s0.duration", "25"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers()["location"], "/workouts/1");
+        let response = app
+            .oneshot(empty_request("GET", "/api/workouts/1"))
+            .await
+            .unwrap();
+        let body = json_body(response).await;
+        assert_eq!(body["mood"], 7);
+        assert_eq!(body["templateId"], 1);
+        let squat = body["exercises"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|exercise| exercise["name"] == "Squat")
+            .unwrap();
+        assert_eq!(squat["sets"].as_array().unwrap().len(), 2);
+        assert_eq!(squat["sets"][1]["weighted"]["failure"], true);
+    }
+
+    #[sqlx::test]
+    async fn save_new_workout_shows_form_again_when_invalid(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed_template(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/new",
+                &[
+                    ("date", "2026-03-07"),
+                    ("e0.id", "1"),
+                    ("e0.s0.weight", "80"),
+                    ("e0.s0.reps", "five"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let page = page_body(response).await;
+        assert!(page.contains("Squat, set 1: Reps must be a number greater than 0"));
+        assert!(page.contains(r#"value="five""#));
+        let response = app
+            .oneshot(empty_request("GET", "/api/workouts"))
+            .await
+            .unwrap();
+        assert_eq!(json_body(response).await, json!([]));
+    }
+
+    #[sqlx::test]
+    async fn save_new_workout_reports_unknown_template(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed_template(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/new",
+                &[
+                    ("template", "42"),
+                    ("template_name", "Gone"),
+                    ("date", "2026-03-07"),
+                    ("e0.id", "1"),
+                    ("e0.s0.weight", "80"),
+                    ("e0.s0.reps", "5"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let page = page_body(response).await;
+        assert!(page.contains("The template of this workout no longer exists"));
+        assert!(!page.contains(r#"name="template" value="42""#));
+        let response = app
+            .oneshot(empty_request("GET", "/api/workouts"))
+            .await
+            .unwrap();
+        assert_eq!(json_body(response).await, json!([]));
     }
 }
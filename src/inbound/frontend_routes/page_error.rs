@@ -0,0 +1,84 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    domain::traits::{
+        exercise_model::ExerciseModelError, personal_record_model::PersonalRecordModelError,
+        workout_model::WorkoutModelError, workout_template_model::WorkoutTemplateModelError,
+    },
+    inbound::log_internal_error,
+};
+
+/// Error returned by the HTML pages, shown to the user as a plain message.
+#[derive(Debug)]
+pub struct PageError {
+    pub status: StatusCode,
+    pub message: String,
+}
+
+impl PageError {
+    pub fn bad_request(message: impl Into<String>) -> Self {
+        PageError {
+            status: StatusCode::BAD_REQUEST,
+            message: message.into(),
+        }
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        PageError {
+            status: StatusCode::NOT_FOUND,
+            message: message.into(),
+        }
+    }
+
+    pub fn internal(error: impl std::fmt::Debug) -> Self {
+        log_internal_error(&error);
+        PageError {
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+            message: "Something went wrong, please try again".to_string(),
+        }
+    }
+}
+
+impl IntoResponse for PageError {
+    fn into_response(self) -> Response {
+        (self.status, self.message).into_response()
+    }
+}
+
+impl From<WorkoutModelError> for PageError {
+    fn from(error: WorkoutModelError) -> Self {
+        match error {
+            WorkoutModelError::NotFound => Self::not_found("Workout not found"),
+            WorkoutModelError::DatabaseError(e) => Self::internal(e),
+        }
+    }
+}
+
+impl From<ExerciseModelError> for PageError {
+    fn from(error: ExerciseModelError) -> Self {
+        match error {
+            ExerciseModelError::NotFound => Self::not_found("Exercise not found"),
+            other => Self::internal(other),
+        }
+    }
+}
+
+impl From<WorkoutTemplateModelError> for PageError {
+    fn from(error: WorkoutTemplateModelError) -> Self {
+        match error {
+            WorkoutTemplateModelError::NotFound => Self::not_found("Template not found"),
+            other => Self::internal(other),
+        }
+    }
+}
+
+impl From<PersonalRecordModelError> for PageError {
+    fn from(error: PersonalRecordModelError) -> Self {
+        match error {
+            PersonalRecordModelError::DatabaseError(e) => Self::internal(e),
+        }
+    }
+}
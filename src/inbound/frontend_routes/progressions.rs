@@ -0,0 +1,536 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    Form,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Redirect, Response},
+};
+
+use crate::{
+    domain::{
+        traits::exercise_model::{ExerciseModel, ExerciseModelError},
+        types::exercise::ExerciseProgression,
+    },
+    inbound::frontend_routes::{
+        exercise_form::ExerciseFields, exercises::duplicate_name_error, page_error::PageError,
+    },
+    state::AppState,
+};
+
+/// Values of the progression form. Steps are named `s{i}.` followed by the exercise inputs,
+/// `i` being the position of the step once the user is done reordering them.
+pub struct ProgressionForm {
+    pub name: String,
+    /// Whether the progression is already saved, its name can't be changed then
+    pub is_saved: bool,
+    pub steps: Vec<ExerciseFields>,
+}
+
+impl ProgressionForm {
+    fn from_fields(name: Option<String>, fields: Vec<(String, String)>) -> Self {
+        let mut form = ProgressionForm {
+            is_saved: name.is_some(),
+            name: name.unwrap_or_default(),
+            steps: Vec::new(),
+        };
+        let mut steps: BTreeMap<usize, ExerciseFields> = BTreeMap::new();
+        for (field, value) in fields {
+            if field == "name" {
+                if !form.is_saved {
+                    form.name = value;
+                }
+                continue;
+            }
+            let Some((step, field)) = field.split_once('.') else {
+                continue;
+            };
+            let Some(index) = step.strip_prefix('s').and_then(|i| i.parse().ok()) else {
+                continue;
+            };
+            steps
+                .entry(index)
+                .or_insert_with(|| ExerciseFields::blank(""))
+                .set(field, value);
+        }
+        form.steps = steps
+            .into_values()
+            .enumerate()
+            .map(|(index, step)| ExerciseFields {
+                prefix: format!("s{index}."),
+                ..step
+            })
+            .collect();
+        form
+    }
+
+    /// Checks every step, problems are prefixed with the step they are about.
+    fn to_progression(&self) -> Result<ExerciseProgression, Vec<String>> {
+        let mut errors = Vec::new();
+        let name = self.name.trim();
+        if name.is_empty() {
+            errors.push("Name must not be empty".to_string());
+        }
+        if self.steps.is_empty() {
+            errors.push("A progression needs at least one step".to_string());
+        }
+        let mut names: Vec<String> = self
+            .steps
+            .iter()
+            .map(|step| step.name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect();
+        names.sort();
+        if names.windows(2).any(|pair| pair[0] == pair[1]) {
+            errors.push("Every step needs its own name".to_string());
+        }
+        let mut progression = Vec::new();
+        for (index, step) in self.steps.iter().enumerate() {
+            match step.to_exercise() {
+                Ok(exercise) => progression.push(exercise),
+                Err(step_errors) => errors.extend(
+                    step_errors
+                        .into_iter()
+                        .map(|e| format!("Step {}: {e}", index + 1)),
+                ),
+            }
+        }
+        if errors.is_empty() {
+            Ok(ExerciseProgression {
+                name: name.to_string(),
+                progression,
+            })
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "progression.html")]
+pub struct ProgressionTemplate {
+    form: ProgressionForm,
+    /// Inputs of a blank step, copied by the "add step" button
+    blank_step: ExerciseFields,
+    errors: Vec<String>,
+}
+
+impl ProgressionTemplate {
+    fn new(form: ProgressionForm, errors: Vec<String>) -> Self {
+        ProgressionTemplate {
+            blank_step: ExerciseFields::blank(format!("s{}.", form.steps.len())),
+            form,
+            errors,
+        }
+    }
+
+    fn rejected(form: ProgressionForm, errors: Vec<String>) -> Response {
+        (StatusCode::UNPROCESSABLE_ENTITY, Self::new(form, errors)).into_response()
+    }
+}
+
+/// Names of the steps already used by exercises outside of the step, to explain why saving
+/// the steps hit the unique name constraint.
+async fn step_name_errors(
+    exercise_model: &dyn ExerciseModel,
+    form: &ProgressionForm,
+) -> Result<Vec<String>, PageError> {
+    let mut errors = Vec::new();
+    for step in &form.steps {
+        match exercise_model.get_exercise_by_name(step.name.trim()).await {
+            Ok(exercise) if exercise.id != step.id => errors.push(duplicate_name_error(&step.name)),
+            Ok(_) | Err(ExerciseModelError::NotFound) => {}
+            Err(e) => return Err(e.into()),
+        }
+    }
+    if errors.is_empty() {
+        errors.push("Another exercise already uses the name of one of the steps".to_string());
+    }
+    Ok(errors)
+}
+
+pub async fn new_progression_page() -> ProgressionTemplate {
+    ProgressionTemplate::new(
+        ProgressionForm {
+            name: String::new(),
+            is_saved: false,
+            steps: vec![ExerciseFields::blank("s0.")],
+        },
+        Vec::new(),
+    )
+}
+
+pub async fn create_progression_action(
+    State(core_logic): State<Arc<AppState>>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Response, PageError> {
+    let form = ProgressionForm::from_fields(None, fields);
+    let progression = match form.to_progression() {
+        Ok(progression) => progression,
+        Err(errors) => return Ok(ProgressionTemplate::rejected(form, errors)),
+    };
+
+    let mut exercise_model = core_logic.exercise_model.write().await;
+    match exercise_model
+        .get_exercise_progression_from_name(&progression.name)
+        .await
+    {
+        Ok(_) => {
+            let errors = vec![format!(
+                "A progression called {} already exists",
+                progression.name
+            )];
+            return Ok(ProgressionTemplate::rejected(form, errors));
+        }
+        Err(ExerciseModelError::NotFound) => {}
+        Err(e) => return Err(e.into()),
+    }
+    match exercise_model.add_exercise_progression(progression).await {
+        Ok(()) => Ok(Redirect::to("/exercises").into_response()),
+        Err(ExerciseModelError::AlreadyExists) => {
+            let errors = step_name_errors(exercise_model.as_ref(), &form).await?;
+            Ok(ProgressionTemplate::rejected(form, errors))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub async fn edit_progression_page(
+    State(core_logic): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> Result<ProgressionTemplate, PageError> {
+    let progression = core_logic
+        .exercise_model
+        .read()
+        .await
+        .get_exercise_progression_from_name(&name)
+        .await
+        .map_err(progression_error)?;
+    let form = ProgressionForm {
+        name: progression.name,
+        is_saved: true,
+        steps: progression
+            .progression
+            .iter()
+            .enumerate()
+            .map(|(index, exercise)| ExerciseFields::from_exercise(format!("s{index}."), exercise))
+            .collect(),
+    };
+    Ok(ProgressionTemplate::new(form, Vec::new()))
+}
+
+/// Saves the steps in the submitted order. Steps removed from the form are detached from the
+/// progression, their exercises and logged sets are kept.
+pub async fn update_progression_action(
+    State(core_logic): State<Arc<AppState>>,
+    Path(name): Path<String>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Response, PageError> {
+    let form = ProgressionForm::from_fields(Some(name), fields);
+    let progression = match form.to_progression() {
+        Ok(progression) => progression,
+        Err(errors) => return Ok(ProgressionTemplate::rejected(form, errors)),
+    };
+
+    let mut exercise_model = core_logic.exercise_model.write().await;
+    exercise_model
+        .get_exercise_progression_from_name(&progression.name)
+        .await
+        .map_err(progression_error)?;
+    match exercise_model
+        .update_exercise_progression(progression)
+        .await
+    {
+        Ok(()) => Ok(Redirect::to("/exercises").into_response()),
+        Err(ExerciseModelError::AlreadyExists) => {
+            let errors = step_name_errors(exercise_model.as_ref(), &form).await?;
+            Ok(ProgressionTemplate::rejected(form, errors))
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Deletes the progression along with its exercises.
+pub async fn delete_progression_action(
+    State(core_logic): State<Arc<AppState>>,
+    Path(name): Path<String>,
+) -> Result<Redirect, PageError> {
+    let mut exercise_model = core_logic.exercise_model.write().await;
+    let progression = exercise_model
+        .get_exercise_progression_from_name(&name)
+        .await
+        .map_err(progression_error)?;
+    exercise_model
+        .delete_exercise_progression(progression)
+        .await?;
+    Ok(Redirect::to("/exercises"))
+}
+
+fn progression_error(error: ExerciseModelError) -> PageError {
+    match error {
+        ExerciseModelError::NotFound => PageError::not_found("Progression not found"),
+        other => other.into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{
+        app, empty_request, form_request, json_body, json_request, page_body,
+    };
+
+    async fn seed(app: &axum::Router) {
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/progressions",
+                json!({
+                    "name": "Handstand push-up",
+                    "progression": [
+                        { "name": "Pike push-up", "goal": { "bodyweightReps": { "goalReps": 10 } } },
+                        { "name": "Wall handstand push-up", "goal": { "bodyweightReps": { "goalReps": 5 } } }
+                    ]
+                }),
+            ))
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn edit_progression_page_lists_steps_in_order(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(empty_request(
+                "GET",
+                "/progressions/Handstand%20push-up/edit",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains(r#"action="/progressions/Handstand%20push-up/edit""#));
+        assert!(page.contains(r#"name="s0.name" id="s0.name" value="Pike push-up""#));
+        assert!(page.contains(r#"name="s1.id" value="2""#));
+    }
+
+    #[sqlx::test]
+    async fn edit_progression_page_returns_not_found(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/progressions/Planche/edit"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test]
+    async fn create_progression_action_saves_steps(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/progressions/new",
+                &[
+                    ("name", "Pull-up"),
+                    ("s0.id", "0"),
+                    ("s0.name", "Negative pull-up"),
+                    ("s0.kind", "bodyweightReps"),
+                    ("s0.goal_reps", "5"),
+                    ("s0.goal_sets", "3"),
+                    ("s1.id", "0"),
+                    ("s1.name", "Pull-up"),
+                    ("s1.kind", "bodyweightReps"),
+                    ("s1.goal_reps", "10"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        let response = app
+            .oneshot(empty_request("GET", "/api/progressions/Pull-up"))
+            .await
+            .unwrap();
+        let body = json_body(response).await;
+        assert_eq!(body["progression"][0]["name"], "Negative pull-up");
+        assert_eq!(body["progression"][1]["name"], "Pull-up");
+    }
+
+    #[sqlx::test]
+    async fn create_progression_action_shows_taken_names_inline(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(form_request(
+                "/progressions/new",
+                &[
+                    ("name", "Push-up"),
+                    ("s0.name", "Pike push-up"),
+                    ("s0.kind", "bodyweightReps"),
+                    ("s0.goal_reps", "10"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let page = page_body(response).await;
+        assert!(page.contains("An exercise called Pike push-up already exists"));
+    }
+
+    #[sqlx::test]
+    async fn update_progression_action_saves_new_order(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/progressions/Handstand%20push-up/edit",
+                &[
+                    ("s0.id", "2"),
+                    ("s0.name", "Wall handstand push-up"),
+                    ("s0.kind", "bodyweightReps"),
+                    ("s0.goal_reps", "5"),
+                    ("s1.id", "1"),
+                    ("s1.name", "Pike push-up"),
+                    ("s1.kind", "bodyweightReps"),
+                    ("s1.goal_reps", "12"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        let response = app
+            .oneshot(empty_request(
+                "GET",
+                "/api/progressions/Handstand%20push-up",
+            ))
+            .await
+            .unwrap();
+        let body = json_body(response).await;
+        assert_eq!(body["progression"][0]["id"], 2);
+        assert_eq!(body["progression"][1]["id"], 1);
+        assert_eq!(
+            body["progression"][1]["goal"]["bodyweightReps"]["goalReps"],
+            12
+        );
+    }
+
+    #[sqlx::test]
+    async fn update_progression_action_rejects_duplicate_step_names(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(form_request(
+                "/progressions/Handstand%20push-up/edit",
+                &[
+                    ("s0.id", "1"),
+                    ("s0.name", "Pike push-up"),
+                    ("s0.kind", "bodyweightReps"),
+                    ("s0.goal_reps", "10"),
+                    ("s1.id", "0"),
+                    ("s1.name", "pike push-up"),
+                    ("s1.kind", "bodyweightReps"),
+                    ("s1.goal_reps", ""),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let page = page_body(response).await;
+        assert!(page.contains("Every step needs its own name"));
+        assert!(page.contains("Step 2: Goal reps must be a number greater than 0"));
+    }
+
+    #[sqlx::test]
+    async fn update_progression_action_saves_nothing_when_a_step_name_is_taken(
+        pool: sqlx::SqlitePool,
+    ) {
+        let app = app(pool);
+        seed(&app).await;
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0 } } }),
+            ))
+            .await
+            .unwrap();
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/progressions/Handstand%20push-up/edit",
+                &[
+                    ("s0.id", "2"),
+                    ("s0.name", "Wall handstand push-up"),
+                    ("s0.kind", "bodyweightReps"),
+                    ("s0.goal_reps", "8"),
+                    ("s1.id", "0"),
+                    ("s1.name", "Squat"),
+                    ("s1.kind", "bodyweightReps"),
+                    ("s1.goal_reps", "10"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let page = page_body(response).await;
+        assert!(page.contains("An exercise called Squat already exists"));
+        let response = app
+            .oneshot(empty_request(
+                "GET",
+                "/api/progressions/Handstand%20push-up",
+            ))
+            .await
+            .unwrap();
+        let body = json_body(response).await;
+        assert_eq!(body["progression"][0]["id"], 1);
+        assert_eq!(body["progression"][1]["id"], 2);
+        assert_eq!(
+            body["progression"][1]["goal"]["bodyweightReps"]["goalReps"],
+            5
+        );
+    }
+
+    #[sqlx::test]
+    async fn delete_progression_action_removes_progression(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/progressions/Handstand%20push-up/delete",
+                &[],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        let response = app
+            .oneshot(empty_request(
+                "GET",
+                "/api/progressions/Handstand%20push-up",
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
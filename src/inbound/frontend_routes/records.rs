@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::extract::{Query, State};
+use serde::Deserialize;
+
+use crate::{
+    domain::types::personal_record::PersonalRecord,
+    inbound::frontend_routes::{display::record_summary, page_error::PageError},
+    state::AppState,
+};
+
+const PAGE_SIZE: u32 = 30;
+
+#[derive(Debug, Deserialize)]
+pub struct RecordsQuery {
+    pub page: Option<u32>,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "records.html")]
+pub struct RecordsTemplate {
+    records: Vec<RecordSummary>,
+    page: u32,
+    has_next_page: bool,
+}
+
+struct RecordSummary {
+    workout_id: u64,
+    date: String,
+    exercise: String,
+    summary: String,
+}
+
+impl From<PersonalRecord> for RecordSummary {
+    fn from(record: PersonalRecord) -> Self {
+        RecordSummary {
+            workout_id: record.workout_id,
+            date: record.date.format("%-d %B %Y").to_string(),
+            summary: record_summary(&record),
+            exercise: record.exercise.name,
+        }
+    }
+}
+
+/// Personal records, newest first, `?page=` starting at 1.
+pub async fn records_page(
+    State(core_logic): State<Arc<AppState>>,
+    Query(query): Query<RecordsQuery>,
+) -> Result<RecordsTemplate, PageError> {
+    let page = query.page.unwrap_or(1).max(1);
+    let offset = (page - 1)
+        .checked_mul(PAGE_SIZE)
+        .ok_or_else(|| PageError::not_found("Page not found"))?;
+    let mut records = core_logic
+        .record_model
+        .read()
+        .await
+        .get_latest_records(PAGE_SIZE + 1, offset)
+        .await?;
+    let has_next_page = records.len() > PAGE_SIZE as usize;
+    records.truncate(PAGE_SIZE as usize);
+
+    Ok(RecordsTemplate {
+        records: records.into_iter().map(Into::into).collect(),
+        page,
+        has_next_page,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request, form_request, json_request, page_body};
+
+    async fn seed(app: &axum::Router) {
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Plank", "goal": { "bodyweightTime": { "goalDurationSeconds": 60 } } }),
+            ))
+            .await
+            .unwrap();
+        for (date, duration) in [("2026-03-01", "40"), ("2026-03-08", "45")] {
+            let response = app
+                .clone()
+                .oneshot(form_request(
+                    "/new",
+                    &[("date", date), ("e0.id", "1"), ("e0.s0.duration", duration)],
+                ))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        }
+    }
+
+    #[sqlx::test]
+    async fn records_page_lists_records_with_their_workout(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app.oneshot(empty_request("GET", "/records")).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains(r#"<a href="/workouts/2">8 March 2026</a>"#));
+        assert!(page.contains("Longest hold: 45 s (was 40 s)"));
+    }
+
+    #[sqlx::test]
+    async fn records_page_past_the_last_offset_is_not_found(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/records?page=4294967295"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test]
+    async fn workout_detail_page_shows_record_badges(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let first = page_body(
+            app.clone()
+                .oneshot(empty_request("GET", "/workouts/1"))
+                .await
+                .unwrap(),
+        )
+        .await;
+        let second = page_body(
+            app.oneshot(empty_request("GET", "/workouts/2"))
+                .await
+                .unwrap(),
+        )
+        .await;
+
+        assert!(!first.contains("pr-badge"));
+        assert!(
+            second.contains(r#"<span class="pr-badge">PR</span> Session volume: 45 s (was 40 s)"#)
+        );
+    }
+}
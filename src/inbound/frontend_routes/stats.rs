@@ -0,0 +1,210 @@
+use std::sync::Arc;
+
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::extract::{Query, State};
+use chrono::{Local, NaiveDate};
+use serde::Deserialize;
+
+use crate::{
+    domain::services::training_stats::{ExerciseStats, PeriodStats, StatsGrouping, TrainingStats},
+    inbound::frontend_routes::{chart::BarChart, page_error::PageError},
+    state::AppState,
+};
+
+#[derive(Debug, Deserialize)]
+pub struct StatsQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+    #[serde(default)]
+    pub group_by: StatsGrouping,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "stats.html")]
+pub struct StatsTemplate {
+    from: NaiveDate,
+    to: NaiveDate,
+    by_month: bool,
+    workouts: u32,
+    tonnage: f32,
+    bodyweight_reps: u32,
+    time_under_tension_seconds: u32,
+    failure_sets: u32,
+    sets: u32,
+    average_mood: Option<String>,
+    charts: Vec<BarChart>,
+    exercises: Vec<ExerciseRow>,
+}
+
+struct ExerciseRow {
+    name: String,
+    workouts: u32,
+    tonnage: f32,
+    bodyweight_reps: u32,
+    time_under_tension_seconds: u32,
+    failure_sets: u32,
+}
+
+impl From<ExerciseStats> for ExerciseRow {
+    fn from(stats: ExerciseStats) -> Self {
+        ExerciseRow {
+            name: stats.exercise.name,
+            workouts: stats.workouts,
+            tonnage: stats.volume.tonnage.round(),
+            bodyweight_reps: stats.volume.bodyweight_reps,
+            time_under_tension_seconds: stats.volume.time_under_tension_seconds,
+            failure_sets: stats.volume.failure_sets,
+        }
+    }
+}
+
+/// Training volume and mood charts, `?from=&to=&group_by=week|month`, covering the last 12
+/// weeks by default.
+pub async fn stats_page(
+    State(core_logic): State<Arc<AppState>>,
+    Query(query): Query<StatsQuery>,
+) -> Result<StatsTemplate, PageError> {
+    let to = query.to.unwrap_or_else(|| Local::now().date_naive());
+    let from = query
+        .from
+        .unwrap_or_else(|| query.group_by.default_from(to));
+    query
+        .group_by
+        .check_range(from, to)
+        .map_err(PageError::bad_request)?;
+
+    let workouts = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workouts_between(from, to)
+        .await?;
+    let stats = TrainingStats::new(from, to, query.group_by, &workouts);
+
+    Ok(StatsTemplate {
+        from,
+        to,
+        by_month: stats.group_by == StatsGrouping::Month,
+        workouts: stats.workouts,
+        tonnage: stats.volume.tonnage.round(),
+        bodyweight_reps: stats.volume.bodyweight_reps,
+        time_under_tension_seconds: stats.volume.time_under_tension_seconds,
+        failure_sets: stats.volume.failure_sets,
+        sets: stats.volume.sets,
+        average_mood: stats.average_mood.map(|mood| format!("{mood:.1}")),
+        charts: charts(stats.group_by, &stats.periods),
+        exercises: stats.exercises.into_iter().map(Into::into).collect(),
+    })
+}
+
+fn charts(group_by: StatsGrouping, periods: &[PeriodStats]) -> Vec<BarChart> {
+    let label = |period: &PeriodStats| match group_by {
+        StatsGrouping::Week => period.start.format("%-d %b").to_string(),
+        StatsGrouping::Month => period.start.format("%b %Y").to_string(),
+    };
+    let series = |value: &dyn Fn(&PeriodStats) -> Option<f32>| {
+        periods
+            .iter()
+            .map(|period| (label(period), value(period)))
+            .collect()
+    };
+
+    vec![
+        BarChart::new(
+            "Tonnage",
+            "kg",
+            series(&|period| Some(period.volume.tonnage)),
+            None,
+        ),
+        BarChart::new(
+            "Bodyweight reps",
+            "reps",
+            series(&|period| Some(period.volume.bodyweight_reps as f32)),
+            None,
+        ),
+        BarChart::new(
+            "Time under tension",
+            "s",
+            series(&|period| Some(period.volume.time_under_tension_seconds as f32)),
+            None,
+        ),
+        BarChart::new(
+            "Failure sets",
+            "sets",
+            series(&|period| Some(period.volume.failure_sets as f32)),
+            None,
+        ),
+        BarChart::new(
+            "Average mood",
+            "/ 10",
+            series(&|period| period.average_mood),
+            Some(10.0),
+        ),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request, json_request, page_body};
+
+    #[sqlx::test]
+    async fn stats_page_draws_a_bar_per_period(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0 } } }),
+            ))
+            .await
+            .unwrap();
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": "2026-03-03",
+                    "mood": 7,
+                    "exercises": [{
+                        "name": "Squat",
+                        "sets": [{ "weighted": { "reps": 5, "weight": 100.0, "failure": true } }]
+                    }]
+                }),
+            ))
+            .await
+            .unwrap();
+
+        let response = app
+            .oneshot(empty_request(
+                "GET",
+                "/stats?from=2026-01-01&to=2026-03-31&group_by=month",
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert_eq!(page.matches("<svg").count(), 5);
+        assert_eq!(page.matches("<rect").count(), 15);
+        assert!(page.contains("<title>Mar 2026: 500 kg</title>"));
+        assert!(page.contains("<title>Jan 2026: -</title>"));
+        assert!(page.contains("<td>Squat</td>"));
+        assert!(page.contains(r#"<option value="month" selected>"#));
+        assert!(!page.contains("<script src"));
+    }
+
+    #[sqlx::test]
+    async fn stats_page_rejects_reversed_dates(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/stats?from=2026-03-31&to=2026-03-01"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+}
@@ -0,0 +1,239 @@
+use std::sync::Arc;
+
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    Form,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Redirect, Response},
+};
+
+use crate::{
+    domain::types::{
+        exercise::ExerciseType,
+        workout::{Workout, WorkoutExercise},
+    },
+    inbound::frontend_routes::{
+        page_error::PageError,
+        workout_form::{ExerciseForm, SetForm, WorkoutForm},
+        workout_history::template_name,
+    },
+    state::AppState,
+};
+
+#[derive(Template, WebTemplate)]
+#[template(path = "edit_workout.html")]
+pub struct EditWorkoutTemplate {
+    id: u64,
+    action: String,
+    form: WorkoutForm,
+    errors: Vec<String>,
+}
+
+impl EditWorkoutTemplate {
+    fn new(id: u64, form: WorkoutForm, errors: Vec<String>) -> Self {
+        EditWorkoutTemplate {
+            id,
+            action: format!("/workouts/{id}/edit"),
+            form,
+            errors,
+        }
+    }
+}
+
+pub async fn edit_workout_page(
+    State(core_logic): State<Arc<AppState>>,
+    Path(workout_id): Path<u64>,
+) -> Result<EditWorkoutTemplate, PageError> {
+    let workout = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workout(workout_id)
+        .await?;
+    let form = WorkoutForm {
+        template_id: workout.template_id,
+        template_name: template_name(&core_logic, workout.template_id)
+            .await?
+            .unwrap_or_default(),
+        date: workout.date.to_string(),
+        mood: workout
+            .mood
+            .map(|mood| mood.to_string())
+            .unwrap_or_default(),
+        exercises: workout
+            .exercises
+            .into_iter()
+            .enumerate()
+            .map(|(index, done)| ExerciseForm {
+                sets: done.sets.iter().map(SetForm::from).collect(),
+                ..ExerciseForm::blank(index, done.exercise)
+            })
+            .collect(),
+    };
+    Ok(EditWorkoutTemplate::new(workout_id, form, Vec::new()))
+}
+
+/// Replaces the workout with the submitted one, showing the form again if it is invalid.
+pub async fn save_workout_edit(
+    State(core_logic): State<Arc<AppState>>,
+    Path(workout_id): Path<u64>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Response, PageError> {
+    let mut form =
+        WorkoutForm::from_fields(fields, core_logic.exercise_model.read().await.as_ref()).await?;
+    let template_errors = form
+        .check_template(core_logic.workout_plan_model.read().await.as_ref())
+        .await?;
+    let new_workout = match form.to_new_workout() {
+        Ok(workout) if template_errors.is_empty() => workout,
+        result => {
+            let errors = template_errors
+                .into_iter()
+                .chain(result.err().unwrap_or_default())
+                .collect();
+            let page = EditWorkoutTemplate::new(workout_id, form, errors);
+            return Ok((StatusCode::UNPROCESSABLE_ENTITY, page).into_response());
+        }
+    };
+
+    let exercises = new_workout
+        .exercises
+        .into_iter()
+        .filter_map(|new_exercise| {
+            let exercise = form
+                .exercises
+                .iter()
+                .find(|exercise| exercise.exercise.id == new_exercise.exercise_id)?
+                .exercise
+                .clone();
+            Some(WorkoutExercise {
+                exercise,
+                sets: new_exercise.sets.into_iter().map(Into::into).collect(),
+            })
+        })
+        .collect();
+    core_logic
+        .workout_model
+        .write()
+        .await
+        .update_workout(Workout {
+            id: workout_id,
+            date: new_workout.date,
+            mood: new_workout.mood,
+            template_id: new_workout.template_id,
+            exercises,
+        })
+        .await?;
+    Ok(Redirect::to(&format!("/workouts/{workout_id}")).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{
+        app, empty_request, form_request, json_body, json_request, page_body,
+    };
+
+    async fn seed(app: &axum::Router) {
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0 } } }),
+            ))
+            .await
+            .unwrap();
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/workouts",
+                json!({
+                    "date": "2026-03-03",
+                    "mood": 6,
+                    "exercises": [{
+                        "name": "Squat",
+                        "sets": [{ "weighted": { "reps": 5, "weight": 90.0, "failure": true } }]
+                    }]
+                }),
+            ))
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn edit_workout_page_shows_current_sets(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(empty_request("GET", "/workouts/1/edit"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains(r#"action="/workouts/1/edit""#));
+        assert!(page.contains(r#"value="2026-03-03""#));
+        assert!(page.contains(r#"id="e0s0-weight" value="90""#));
+        assert!(page.contains(" checked"));
+    }
+
+    #[sqlx::test]
+    async fn save_workout_edit_updates_workout(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/workouts/1/edit",
+                &[
+                    ("date", "2026-03-04"),
+                    ("mood", ""),
+                    ("e0.id", "1"),
+                    ("e0.s0.weight", "92.5"),
+                    ("e0.s0.reps", "5"),
+                    ("e0.s1.weight", "95"),
+                    ("e0.s1.reps", "3"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers()["location"], "/workouts/1");
+        let response = app
+            .oneshot(empty_request("GET", "/api/workouts/1"))
+            .await
+            .unwrap();
+        let body = json_body(response).await;
+        assert_eq!(body["date"], "2026-03-04");
+        assert_eq!(body["mood"], json!(null));
+        assert_eq!(body["exercises"][0]["sets"][1]["weighted"]["weight"], 95.0);
+    }
+
+    #[sqlx::test]
+    async fn save_workout_edit_returns_not_found_for_unknown_workout(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(form_request(
+                "/workouts/5/edit",
+                &[
+                    ("date", "2026-03-04"),
+                    ("e0.id", "1"),
+                    ("e0.s0.weight", "92.5"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
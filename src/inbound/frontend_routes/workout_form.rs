@@ -0,0 +1,487 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+
+use crate::{
+    domain::{
+        traits::{
+            exercise_model::ExerciseModel,
+            workout_template_model::{WorkoutTemplateModel, WorkoutTemplateModelError},
+        },
+        types::{
+            exercise::{Exercise, ExerciseType},
+            workout::{
+                NewBodyweightRepSet, NewBodyweightTimeSet, NewWeightedSet, NewWorkout,
+                NewWorkoutExercise, NewWorkoutSet, WorkoutSet,
+            },
+            workout_template::SetScheme,
+        },
+    },
+    inbound::frontend_routes::{
+        display::{goal_summary, scheme_summary},
+        page_error::PageError,
+    },
+};
+
+/// Values of the workout form, kept as typed so a rejected submission can be shown again.
+///
+/// Inputs are named `date`, `mood`, `e{i}.id` for the exercise id and `e{i}.s{j}.weight`,
+/// `.reps`, `.duration` or `.failure` for the sets, `i` and `j` being positions in the form.
+pub struct WorkoutForm {
+    pub template_id: Option<u64>,
+    pub template_name: String,
+    pub date: String,
+    pub mood: String,
+    pub exercises: Vec<ExerciseForm>,
+}
+
+pub struct ExerciseForm {
+    pub index: usize,
+    pub exercise: Exercise,
+    pub goal: String,
+    /// Sets planned by the template, such as "5×5"
+    pub plan: Option<String>,
+    pub sets: Vec<SetForm>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct SetForm {
+    pub weight: String,
+    pub reps: String,
+    pub duration: String,
+    pub failure: bool,
+}
+
+impl SetForm {
+    fn is_blank(&self) -> bool {
+        self.weight.trim().is_empty()
+            && self.reps.trim().is_empty()
+            && self.duration.trim().is_empty()
+    }
+}
+
+#[derive(Default)]
+struct ExerciseInput {
+    exercise_id: Option<u64>,
+    sets: BTreeMap<usize, SetForm>,
+}
+
+impl ExerciseForm {
+    /// Blank form for `exercise` with one row per set of its goal.
+    pub fn blank(index: usize, exercise: Exercise) -> Self {
+        let set_count = exercise.exercise_type.goal_sets().max(1);
+        let sets = vec![SetForm::default(); usize::from(set_count)];
+        ExerciseForm {
+            index,
+            goal: goal_summary(&exercise.exercise_type),
+            plan: None,
+            exercise,
+            sets,
+        }
+    }
+
+    /// Form for `exercise` holding the sets done last time, blank if there are none. Failure is
+    /// left unchecked, it is how a set ended and not a target.
+    ///
+    /// With a `set_scheme` there is a row per planned set, starting from the weights of last time
+    /// and the planned reps or seconds, left blank for as many as possible.
+    pub fn prefilled(
+        index: usize,
+        exercise: Exercise,
+        set_scheme: Option<SetScheme>,
+        last_sets: &[WorkoutSet],
+    ) -> Self {
+        let mut form = Self::blank(index, exercise);
+        let last_sets: Vec<SetForm> = last_sets
+            .iter()
+            .map(|set| SetForm {
+                failure: false,
+                ..SetForm::from(set)
+            })
+            .collect();
+        let Some(set_scheme) = set_scheme else {
+            if !last_sets.is_empty() {
+                form.sets = last_sets;
+            }
+            return form;
+        };
+
+        let target = set_scheme
+            .target
+            .map(|target| target.to_string())
+            .unwrap_or_default();
+        form.sets = (0..usize::from(set_scheme.sets))
+            .map(|set_index| {
+                let mut set = last_sets
+                    .get(set_index)
+                    .or(last_sets.last())
+                    .cloned()
+                    .unwrap_or_default();
+                match form.exercise.exercise_type {
+                    ExerciseType::BodyweightTime { .. } => set.duration = target.clone(),
+                    _ => set.reps = target.clone(),
+                }
+                set
+            })
+            .collect();
+        form.plan = Some(scheme_summary(set_scheme, &form.exercise.exercise_type));
+        form
+    }
+}
+
+impl From<&WorkoutSet> for SetForm {
+    fn from(set: &WorkoutSet) -> Self {
+        match set {
+            WorkoutSet::Weighted(set) => SetForm {
+                weight: set.weight.to_string(),
+                reps: set.reps.to_string(),
+                failure: set.failure,
+                ..SetForm::default()
+            },
+            WorkoutSet::BodyweightReps(set) => SetForm {
+                reps: set.reps.to_string(),
+                failure: set.failure,
+                ..SetForm::default()
+            },
+            WorkoutSet::BodyweightTime(set) => SetForm {
+                duration: set.duration_seconds.to_string(),
+                failure: set.failure,
+                ..SetForm::default()
+            },
+        }
+    }
+}
+
+impl WorkoutForm {
+    /// Rebuilds the form from the submitted fields, looking every exercise up again.
+    pub async fn from_fields(
+        fields: Vec<(String, String)>,
+        exercise_model: &dyn ExerciseModel,
+    ) -> Result<Self, PageError> {
+        let mut form = WorkoutForm {
+            template_id: None,
+            template_name: String::new(),
+            date: String::new(),
+            mood: String::new(),
+            exercises: Vec::new(),
+        };
+        let mut inputs: BTreeMap<usize, ExerciseInput> = BTreeMap::new();
+        for (name, value) in fields {
+            let mut parts = name.split('.');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some("date"), None, None) => form.date = value,
+                (Some("mood"), None, None) => form.mood = value,
+                (Some("template"), None, None) => form.template_id = value.parse().ok(),
+                (Some("template_name"), None, None) => form.template_name = value,
+                (Some(exercise), Some(field), set_field) => {
+                    let Some(index) = position(exercise, 'e') else {
+                        continue;
+                    };
+                    let input = inputs.entry(index).or_default();
+                    match (field, set_field) {
+                        ("id", None) => input.exercise_id = value.parse().ok(),
+                        (set, Some(set_field)) => {
+                            let Some(set_index) = position(set, 's') else {
+                                continue;
+                            };
+                            let set = input.sets.entry(set_index).or_default();
+                            match set_field {
+                                "weight" => set.weight = value,
+                                "reps" => set.reps = value,
+                                "duration" => set.duration = value,
+                                "failure" => set.failure = true,
+                                _ => {}
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        for (index, input) in inputs.into_values().enumerate() {
+            let Some(exercise_id) = input.exercise_id else {
+                continue;
+            };
+            let exercise = exercise_model.get_exercise_by_id(exercise_id).await?;
+            form.exercises.push(ExerciseForm {
+                index,
+                goal: goal_summary(&exercise.exercise_type),
+                plan: None,
+                exercise,
+                sets: input.sets.into_values().collect(),
+            });
+        }
+        Ok(form)
+    }
+
+    /// Checks the template the form was started from still exists, so a deleted one is reported
+    /// instead of failing on the foreign key. A missing template is dropped from the form.
+    pub async fn check_template(
+        &mut self,
+        template_model: &dyn WorkoutTemplateModel,
+    ) -> Result<Vec<String>, PageError> {
+        let Some(template_id) = self.template_id else {
+            return Ok(Vec::new());
+        };
+        match template_model.get_workout_template(template_id).await {
+            Ok(_) => Ok(Vec::new()),
+            Err(WorkoutTemplateModelError::NotFound) => {
+                self.template_id = None;
+                self.template_name.clear();
+                Ok(vec![
+                    "The template of this workout no longer exists, save again without it"
+                        .to_string(),
+                ])
+            }
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Checks the typed values, blank set rows are left out. Every problem is listed.
+    pub fn to_new_workout(&self) -> Result<NewWorkout, Vec<String>> {
+        let mut errors = Vec::new();
+        let date = match NaiveDate::parse_from_str(self.date.trim(), "%Y-%m-%d") {
+            Ok(date) => Some(date),
+            Err(_) => {
+                errors.push("Date is missing or invalid".to_string());
+                None
+            }
+        };
+        let mood = match self.mood.trim() {
+            "" => None,
+            mood => match mood.parse::<u8>() {
+                Ok(mood) if (1..=10).contains(&mood) => Some(mood),
+                _ => {
+                    errors.push("Mood must be between 1 and 10".to_string());
+                    None
+                }
+            },
+        };
+
+        let mut exercises = Vec::new();
+        for exercise in &self.exercises {
+            let mut sets = Vec::new();
+            for (index, set) in exercise.sets.iter().enumerate() {
+                if set.is_blank() {
+                    continue;
+                }
+                match parse_set(&exercise.exercise.exercise_type, set) {
+                    Ok(set) => sets.push(set),
+                    Err(message) => errors.push(format!(
+                        "{}, set {}: {message}",
+                        exercise.exercise.name,
+                        index + 1
+                    )),
+                }
+            }
+            if !sets.is_empty() {
+                exercises.push(NewWorkoutExercise {
+                    exercise_id: exercise.exercise.id,
+                    sets,
+                });
+            }
+        }
+        if exercises.is_empty() && errors.is_empty() {
+            errors.push("Log at least one set".to_string());
+        }
+
+        match date {
+            Some(date) if errors.is_empty() => Ok(NewWorkout {
+                date,
+                mood,
+                template_id: self.template_id,
+                exercises,
+            }),
+            _ => Err(errors),
+        }
+    }
+}
+
+/// Reads the `3` out of `e3` or `s3`.
+fn position(part: &str, prefix: char) -> Option<usize> {
+    part.strip_prefix(prefix)?.parse().ok()
+}
+
+/// Parses a typed number, `what` names the field in the error.
+pub fn positive<T: std::str::FromStr + PartialOrd + Default>(
+    value: &str,
+    what: &str,
+) -> Result<T, String> {
+    match value.trim().parse::<T>() {
+        Ok(value) if value > T::default() => Ok(value),
+        _ => Err(format!("{what} must be a number greater than 0")),
+    }
+}
+
+fn parse_set(exercise_type: &ExerciseType, set: &SetForm) -> Result<NewWorkoutSet, String> {
+    match exercise_type {
+        ExerciseType::Weighted { .. } => Ok(NewWorkoutSet::Weighted(NewWeightedSet {
+            weight: positive(&set.weight, "Weight")?,
+            reps: match set.reps.trim() {
+                "" => 1,
+                reps => positive(reps, "Reps")?,
+            },
+            failure: set.failure,
+        })),
+        ExerciseType::BodyweightReps { .. } => {
+            Ok(NewWorkoutSet::BodyweightReps(NewBodyweightRepSet {
+                reps: positive(&set.reps, "Reps")?,
+                failure: set.failure,
+            }))
+        }
+        ExerciseType::BodyweightTime { .. } => {
+            Ok(NewWorkoutSet::BodyweightTime(NewBodyweightTimeSet {
+                duration_seconds: positive(&set.duration, "Duration")?,
+                failure: set.failure,
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::types::workout::WeightedSet;
+
+    fn squat() -> Exercise {
+        Exercise {
+            id: 4,
+            name: "Squat".to_string(),
+            exercise_type: ExerciseType::Weighted {
+                goal_weight: 100.0,
+                goal_reps: 5,
+                goal_sets: 3,
+            },
+        }
+    }
+
+    fn form(date: &str, sets: Vec<SetForm>) -> WorkoutForm {
+        WorkoutForm {
+            template_id: None,
+            template_name: String::new(),
+            date: date.to_string(),
+            mood: String::new(),
+            exercises: vec![ExerciseForm {
+                sets,
+                ..ExerciseForm::blank(0, squat())
+            }],
+        }
+    }
+
+    fn set(weight: &str, reps: &str) -> SetForm {
+        SetForm {
+            weight: weight.to_string(),
+            reps: reps.to_string(),
+            ..SetForm::default()
+        }
+    }
+
+    #[test]
+    fn blank_has_a_row_per_goal_set() {
+        assert_eq!(ExerciseForm::blank(0, squat()).sets.len(), 3);
+    }
+
+    #[test]
+    fn prefilled_copies_last_sets_without_failure() {
+        let last_sets = [
+            WorkoutSet::Weighted(WeightedSet {
+                id: 1,
+                reps: 5,
+                weight: 82.5,
+                failure: false,
+            }),
+            WorkoutSet::Weighted(WeightedSet {
+                id: 2,
+                reps: 3,
+                weight: 85.0,
+                failure: true,
+            }),
+        ];
+
+        let exercise = ExerciseForm::prefilled(0, squat(), None, &last_sets);
+
+        assert_eq!(exercise.sets.len(), 2);
+        assert_eq!(exercise.sets[0].weight, "82.5");
+        assert_eq!(exercise.sets[1].reps, "3");
+        assert!(!exercise.sets[1].failure);
+        assert_eq!(ExerciseForm::prefilled(0, squat(), None, &[]).sets.len(), 3);
+    }
+
+    #[test]
+    fn prefilled_follows_the_set_scheme() {
+        let last_sets = [WorkoutSet::Weighted(WeightedSet {
+            id: 1,
+            reps: 3,
+            weight: 85.0,
+            failure: true,
+        })];
+        let five_by_five = SetScheme {
+            sets: 5,
+            target: Some(5),
+        };
+
+        let exercise = ExerciseForm::prefilled(0, squat(), Some(five_by_five), &last_sets);
+
+        assert_eq!(exercise.plan.as_deref(), Some("5×5"));
+        assert_eq!(exercise.sets.len(), 5);
+        assert!(
+            exercise
+                .sets
+                .iter()
+                .all(|set| set.weight == "85" && set.reps == "5" && !set.failure)
+        );
+        let amrap = SetScheme {
+            sets: 3,
+            target: None,
+        };
+        let exercise = ExerciseForm::prefilled(0, squat(), Some(amrap), &[]);
+        assert_eq!(exercise.sets.len(), 3);
+        assert!(exercise.sets.iter().all(|set| set.reps.is_empty()));
+    }
+
+    #[test]
+    fn to_new_workout_skips_blank_rows() {
+        let workout = form(
+            "2026-03-03",
+            vec![set("80", "5"), set("", ""), set("85", "")],
+        )
+        .to_new_workout()
+        .expect("form should be valid");
+
+        assert_eq!(workout.exercises[0].exercise_id, 4);
+        assert_eq!(workout.exercises[0].sets.len(), 2);
+        assert!(matches!(
+            workout.exercises[0].sets[1],
+            NewWorkoutSet::Weighted(NewWeightedSet { reps: 1, .. })
+        ));
+    }
+
+    #[test]
+    fn to_new_workout_lists_every_problem() {
+        let errors = form("", vec![set("heavy", "5"), set("80", "0")])
+            .to_new_workout()
+            .err()
+            .expect("form should be invalid");
+
+        assert_eq!(
+            errors,
+            [
+                "Date is missing or invalid",
+                "Squat, set 1: Weight must be a number greater than 0",
+                "Squat, set 2: Reps must be a number greater than 0",
+            ]
+        );
+    }
+
+    #[test]
+    fn to_new_workout_needs_a_set() {
+        let errors = form("2026-03-03", vec![set("", "")])
+            .to_new_workout()
+            .err()
+            .expect("form should be invalid");
+
+        assert_eq!(errors, ["Log at least one set"]);
+    }
+}
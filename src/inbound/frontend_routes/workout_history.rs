@@ -0,0 +1,379 @@
+use std::{collections::HashMap, sync::Arc};
+
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    extract::{Path, Query, State},
+    response::Redirect,
+};
+use serde::Deserialize;
+
+use crate::{
+    domain::{
+        traits::workout_template_model::WorkoutTemplateModelError,
+        types::workout::{Workout, WorkoutSet},
+    },
+    inbound::frontend_routes::{
+        display::{record_summary, sets_summary},
+        page_error::PageError,
+    },
+    state::AppState,
+};
+
+const PAGE_SIZE: u32 = 20;
+
+#[derive(Debug, Deserialize)]
+pub struct WorkoutHistoryQuery {
+    pub page: Option<u32>,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "workouts.html")]
+pub struct WorkoutHistoryTemplate {
+    workouts: Vec<WorkoutSummary>,
+    page: u32,
+    has_next_page: bool,
+}
+
+struct WorkoutSummary {
+    id: u64,
+    date: String,
+    mood: Option<u8>,
+    template_name: Option<String>,
+    exercises: Vec<String>,
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "workout.html")]
+pub struct WorkoutDetailTemplate {
+    id: u64,
+    date: String,
+    mood: Option<u8>,
+    template_name: Option<String>,
+    exercises: Vec<ExerciseDetail>,
+}
+
+struct ExerciseDetail {
+    name: String,
+    /// Personal records beaten on the exercise, listed under its first entry
+    records: Vec<String>,
+    sets: Vec<SetDetail>,
+}
+
+struct SetDetail {
+    number: usize,
+    weight: Option<f32>,
+    reps: Option<u16>,
+    duration_seconds: Option<u16>,
+    failure: bool,
+}
+
+impl SetDetail {
+    fn new(number: usize, set: &WorkoutSet) -> Self {
+        let (weight, reps, duration_seconds, failure) = match set {
+            WorkoutSet::Weighted(s) => (Some(s.weight), Some(s.reps), None, s.failure),
+            WorkoutSet::BodyweightReps(s) => (None, Some(s.reps), None, s.failure),
+            WorkoutSet::BodyweightTime(s) => (None, None, Some(s.duration_seconds), s.failure),
+        };
+        SetDetail {
+            number,
+            weight,
+            reps,
+            duration_seconds,
+            failure,
+        }
+    }
+}
+
+impl WorkoutSummary {
+    fn new(workout: Workout, template_names: &HashMap<u64, String>) -> Self {
+        WorkoutSummary {
+            id: workout.id,
+            date: workout.date.format("%A %-d %B %Y").to_string(),
+            mood: workout.mood,
+            template_name: workout
+                .template_id
+                .and_then(|id| template_names.get(&id).cloned()),
+            exercises: workout
+                .exercises
+                .iter()
+                .map(|done| format!("{} {}", done.exercise.name, sets_summary(&done.sets)))
+                .collect(),
+        }
+    }
+}
+
+/// Past workouts, newest first, `?page=` starting at 1.
+pub async fn workout_history_page(
+    State(core_logic): State<Arc<AppState>>,
+    Query(query): Query<WorkoutHistoryQuery>,
+) -> Result<WorkoutHistoryTemplate, PageError> {
+    let page = query.page.unwrap_or(1).max(1);
+    let offset = (page - 1)
+        .checked_mul(PAGE_SIZE)
+        .ok_or_else(|| PageError::not_found("Page not found"))?;
+    let mut workouts = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_latest_workouts(PAGE_SIZE + 1, offset)
+        .await?;
+    let has_next_page = workouts.len() > PAGE_SIZE as usize;
+    workouts.truncate(PAGE_SIZE as usize);
+
+    let template_names: HashMap<u64, String> = core_logic
+        .workout_plan_model
+        .read()
+        .await
+        .get_all_workout_templates()
+        .await?
+        .into_iter()
+        .map(|template| (template.id, template.name))
+        .collect();
+
+    Ok(WorkoutHistoryTemplate {
+        workouts: workouts
+            .into_iter()
+            .map(|workout| WorkoutSummary::new(workout, &template_names))
+            .collect(),
+        page,
+        has_next_page,
+    })
+}
+
+pub async fn workout_detail_page(
+    State(core_logic): State<Arc<AppState>>,
+    Path(workout_id): Path<u64>,
+) -> Result<WorkoutDetailTemplate, PageError> {
+    let workout = core_logic
+        .workout_model
+        .read()
+        .await
+        .get_workout(workout_id)
+        .await?;
+    let template_name = template_name(&core_logic, workout.template_id).await?;
+    let mut records: HashMap<u64, Vec<String>> = HashMap::new();
+    for record in core_logic
+        .record_model
+        .read()
+        .await
+        .get_workout_records(workout_id)
+        .await?
+    {
+        records
+            .entry(record.exercise.id)
+            .or_default()
+            .push(record_summary(&record));
+    }
+
+    Ok(WorkoutDetailTemplate {
+        id: workout.id,
+        date: workout.date.format("%A %-d %B %Y").to_string(),
+        mood: workout.mood,
+        template_name,
+        exercises: workout
+            .exercises
+            .into_iter()
+            .map(|done| ExerciseDetail {
+                records: records.remove(&done.exercise.id).unwrap_or_default(),
+                name: done.exercise.name,
+                sets: done
+                    .sets
+                    .iter()
+                    .enumerate()
+                    .map(|(index, set)| SetDetail::new(index + 1, set))
+                    .collect(),
+            })
+            .collect(),
+    })
+}
+
+/// Name of the template a workout was started from, `None` if there is none.
+pub async fn template_name(
+    core_logic: &AppState,
+    template_id: Option<u64>,
+) -> Result<Option<String>, PageError> {
+    let Some(template_id) = template_id else {
+        return Ok(None);
+    };
+    match core_logic
+        .workout_plan_model
+        .read()
+        .await
+        .get_workout_template(template_id)
+        .await
+    {
+        Ok(template) => Ok(Some(template.name)),
+        Err(WorkoutTemplateModelError::NotFound) => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub async fn delete_workout_action(
+    State(core_logic): State<Arc<AppState>>,
+    Path(workout_id): Path<u64>,
+) -> Result<Redirect, PageError> {
+    core_logic
+        .workout_model
+        .write()
+        .await
+        .delete_workout(workout_id)
+        .await?;
+    Ok(Redirect::to("/workouts"))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{app, empty_request, form_request, json_request, page_body};
+
+    async fn seed(app: &axum::Router, dates: &[&str]) {
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/exercises",
+                json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0 } } }),
+            ))
+            .await
+            .unwrap();
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/templates",
+                json!({ "name": "Legs", "exerciseIds": [1] }),
+            ))
+            .await
+            .unwrap();
+        for date in dates {
+            let response = app
+                .clone()
+                .oneshot(json_request(
+                    "POST",
+                    "/api/workouts",
+                    json!({
+                        "date": date,
+                        "mood": 8,
+                        "templateId": 1,
+                        "exercises": [{
+                            "name": "Squat",
+                            "sets": [
+                                { "weighted": { "reps": 5, "weight": 100.0 } },
+                                { "weighted": { "reps": 5, "weight": 100.0 } },
+                                { "weighted": { "reps": 5, "weight": 100.0, "failure": true } }
+                            ]
+                        }]
+                    }),
+                ))
+                .await
+                .unwrap();
+            assert_eq!(response.status(), StatusCode::CREATED);
+        }
+    }
+
+    #[sqlx::test]
+    async fn workout_history_page_summarises_workouts(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app, &["2026-03-03"]).await;
+
+        let response = app
+            .oneshot(empty_request("GET", "/workouts"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains(r#"href="/workouts/1""#));
+        assert!(page.contains("Tuesday 3 March 2026"));
+        assert!(page.contains("Legs"));
+        assert!(page.contains("Squat 3×5 @ 100 kg"));
+        assert!(!page.contains("page=2"));
+    }
+
+    #[sqlx::test]
+    async fn workout_history_page_is_paginated_newest_first(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        let dates: Vec<String> = (1..=21).map(|day| format!("2026-03-{day:02}")).collect();
+        let dates: Vec<&str> = dates.iter().map(String::as_str).collect();
+        seed(&app, &dates).await;
+
+        let first = page_body(
+            app.clone()
+                .oneshot(empty_request("GET", "/workouts"))
+                .await
+                .unwrap(),
+        )
+        .await;
+        let second = page_body(
+            app.oneshot(empty_request("GET", "/workouts?page=2"))
+                .await
+                .unwrap(),
+        )
+        .await;
+
+        assert!(first.contains("21 March 2026"));
+        assert!(!first.contains("Sunday 1 March 2026"));
+        assert!(first.contains("page=2"));
+        assert!(second.contains("Sunday 1 March 2026"));
+        assert!(!second.contains("page=3"));
+    }
+
+    #[sqlx::test]
+    async fn workout_history_page_past_the_last_offset_is_not_found(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/workouts?page=4294967295"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test]
+    async fn workout_detail_page_lists_sets_with_failure(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app, &["2026-03-03"]).await;
+
+        let response = app
+            .oneshot(empty_request("GET", "/workouts/1"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert_eq!(page.matches(r#"<td class="failure">✗</td>"#).count(), 1);
+        assert!(page.contains(r#"href="/workouts/1/edit""#));
+        assert!(page.contains(r#"action="/workouts/1/delete""#));
+    }
+
+    #[sqlx::test]
+    async fn workout_detail_page_returns_not_found(pool: sqlx::SqlitePool) {
+        let response = app(pool)
+            .oneshot(empty_request("GET", "/workouts/4"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[sqlx::test]
+    async fn delete_workout_action_removes_workout(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app, &["2026-03-03"]).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request("/workouts/1/delete", &[]))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers()["location"], "/workouts");
+        let response = app
+            .oneshot(empty_request("GET", "/workouts/1"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,544 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use askama::Template;
+use askama_web::WebTemplate;
+use axum::{
+    Form,
+    extract::{Path, State},
+    http::StatusCode,
+    response::{IntoResponse, Redirect, Response},
+};
+
+use crate::{
+    domain::{
+        traits::{
+            exercise_model::ExerciseModel, workout_template_model::WorkoutTemplateModelError,
+        },
+        types::{
+            exercise::Exercise,
+            workout_template::{
+                NewTemplateExercise, NewWorkoutTemplate, SetScheme, TemplateExercise,
+                WorkoutTemplate,
+            },
+        },
+    },
+    inbound::frontend_routes::{display::scheme_summary, page_error::PageError},
+    state::AppState,
+};
+
+#[derive(Template, WebTemplate)]
+#[template(path = "workout_templates.html")]
+pub struct TemplateListTemplate {
+    templates: Vec<TemplateSummary>,
+}
+
+struct TemplateSummary {
+    id: u64,
+    name: String,
+    /// Exercise names followed by their planned sets, if any
+    exercises: Vec<String>,
+}
+
+/// Values of the template form. Exercises are named `x{i}.exercise` for the id and
+/// `x{i}.scheme` for the planned sets, `i` being the position once the user is done
+/// reordering them.
+pub struct TemplateForm {
+    /// 0 for a template that isn't saved yet
+    pub id: u64,
+    pub name: String,
+    pub exercises: Vec<PlannedExerciseForm>,
+}
+
+pub struct PlannedExerciseForm {
+    pub exercise: Exercise,
+    pub set_scheme: String,
+}
+
+impl From<WorkoutTemplate> for TemplateForm {
+    fn from(template: WorkoutTemplate) -> Self {
+        TemplateForm {
+            id: template.id,
+            name: template.name,
+            exercises: template
+                .exercises
+                .into_iter()
+                .map(|planned| PlannedExerciseForm {
+                    set_scheme: planned
+                        .set_scheme
+                        .map(|scheme| scheme.notation(&planned.exercise.exercise_type))
+                        .unwrap_or_default(),
+                    exercise: planned.exercise,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl TemplateForm {
+    /// Rebuilds the form from the submitted fields, looking every exercise up again.
+    async fn from_fields(
+        id: u64,
+        fields: Vec<(String, String)>,
+        exercise_model: &dyn ExerciseModel,
+    ) -> Result<Self, PageError> {
+        let mut name = String::new();
+        let mut rows: BTreeMap<usize, (Option<u64>, String)> = BTreeMap::new();
+        for (field, value) in fields {
+            if field == "name" {
+                name = value;
+                continue;
+            }
+            let Some((row, field)) = field.split_once('.') else {
+                continue;
+            };
+            let Some(index) = row.strip_prefix('x').and_then(|i| i.parse().ok()) else {
+                continue;
+            };
+            let row = rows.entry(index).or_default();
+            match field {
+                "exercise" => row.0 = value.parse().ok(),
+                "scheme" => row.1 = value,
+                _ => {}
+            }
+        }
+
+        let mut exercises = Vec::with_capacity(rows.len());
+        for (exercise_id, set_scheme) in rows.into_values() {
+            let Some(exercise_id) = exercise_id else {
+                continue;
+            };
+            exercises.push(PlannedExerciseForm {
+                exercise: exercise_model.get_exercise_by_id(exercise_id).await?,
+                set_scheme,
+            });
+        }
+        Ok(TemplateForm {
+            id,
+            name,
+            exercises,
+        })
+    }
+
+    /// Checks the typed values, every problem is listed.
+    fn to_template(&self) -> Result<WorkoutTemplate, Vec<String>> {
+        let mut errors = Vec::new();
+        let name = self.name.trim();
+        if name.is_empty() {
+            errors.push("Name must not be empty".to_string());
+        }
+        if self.exercises.is_empty() {
+            errors.push("Pick at least one exercise".to_string());
+        }
+
+        let mut exercises: Vec<TemplateExercise> = Vec::with_capacity(self.exercises.len());
+        for planned in &self.exercises {
+            if exercises
+                .iter()
+                .any(|exercise| exercise.exercise.id == planned.exercise.id)
+            {
+                errors.push(format!("{} is listed twice", planned.exercise.name));
+                continue;
+            }
+            let set_scheme = match planned.set_scheme.trim() {
+                "" => None,
+                scheme => match scheme.parse::<SetScheme>() {
+                    Ok(scheme) => Some(scheme),
+                    Err(e) => {
+                        errors.push(format!("{}: {e}", planned.exercise.name));
+                        None
+                    }
+                },
+            };
+            exercises.push(TemplateExercise {
+                exercise: planned.exercise.clone(),
+                set_scheme,
+            });
+        }
+
+        if errors.is_empty() {
+            Ok(WorkoutTemplate {
+                id: self.id,
+                name: name.to_string(),
+                exercises,
+            })
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+#[derive(Template, WebTemplate)]
+#[template(path = "workout_template.html")]
+pub struct TemplateEditorTemplate {
+    form: TemplateForm,
+    /// Every exercise that can be added
+    catalogue: Vec<Exercise>,
+    errors: Vec<String>,
+}
+
+impl TemplateEditorTemplate {
+    async fn new(
+        core_logic: &AppState,
+        form: TemplateForm,
+        errors: Vec<String>,
+    ) -> Result<Self, PageError> {
+        let catalogue = core_logic
+            .exercise_model
+            .read()
+            .await
+            .get_all_exercises()
+            .await?;
+        Ok(TemplateEditorTemplate {
+            form,
+            catalogue,
+            errors,
+        })
+    }
+
+    async fn rejected(
+        core_logic: &AppState,
+        form: TemplateForm,
+        errors: Vec<String>,
+    ) -> Result<Response, PageError> {
+        let page = Self::new(core_logic, form, errors).await?;
+        Ok((StatusCode::UNPROCESSABLE_ENTITY, page).into_response())
+    }
+}
+
+fn duplicate_name_error(name: &str) -> Vec<String> {
+    vec![format!("A template called {} already exists", name.trim())]
+}
+
+pub async fn template_list_page(
+    State(core_logic): State<Arc<AppState>>,
+) -> Result<TemplateListTemplate, PageError> {
+    let templates = core_logic
+        .workout_plan_model
+        .read()
+        .await
+        .get_all_workout_templates()
+        .await?;
+    Ok(TemplateListTemplate {
+        templates: templates
+            .into_iter()
+            .map(|template| TemplateSummary {
+                id: template.id,
+                name: template.name,
+                exercises: template
+                    .exercises
+                    .iter()
+                    .map(|planned| match planned.set_scheme {
+                        Some(scheme) => format!(
+                            "{} {}",
+                            planned.exercise.name,
+                            scheme_summary(scheme, &planned.exercise.exercise_type)
+                        ),
+                        None => planned.exercise.name.clone(),
+                    })
+                    .collect(),
+            })
+            .collect(),
+    })
+}
+
+pub async fn new_template_page(
+    State(core_logic): State<Arc<AppState>>,
+) -> Result<TemplateEditorTemplate, PageError> {
+    let form = TemplateForm {
+        id: 0,
+        name: String::new(),
+        exercises: Vec::new(),
+    };
+    TemplateEditorTemplate::new(&core_logic, form, Vec::new()).await
+}
+
+pub async fn create_template_action(
+    State(core_logic): State<Arc<AppState>>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Response, PageError> {
+    let form =
+        TemplateForm::from_fields(0, fields, core_logic.exercise_model.read().await.as_ref())
+            .await?;
+    let template = match form.to_template() {
+        Ok(template) => template,
+        Err(errors) => return TemplateEditorTemplate::rejected(&core_logic, form, errors).await,
+    };
+    let result = core_logic
+        .workout_plan_model
+        .write()
+        .await
+        .create_workout_template(NewWorkoutTemplate {
+            name: template.name,
+            exercises: template
+                .exercises
+                .iter()
+                .map(|planned| NewTemplateExercise {
+                    exercise_id: planned.exercise.id,
+                    set_scheme: planned.set_scheme,
+                })
+                .collect(),
+        })
+        .await;
+    match result {
+        Ok(_) => Ok(Redirect::to("/templates").into_response()),
+        Err(WorkoutTemplateModelError::AlreadyExists) => {
+            let errors = duplicate_name_error(&form.name);
+            TemplateEditorTemplate::rejected(&core_logic, form, errors).await
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+pub async fn edit_template_page(
+    State(core_logic): State<Arc<AppState>>,
+    Path(template_id): Path<u64>,
+) -> Result<TemplateEditorTemplate, PageError> {
+    let template = core_logic
+        .workout_plan_model
+        .read()
+        .await
+        .get_workout_template(template_id)
+        .await?;
+    TemplateEditorTemplate::new(&core_logic, template.into(), Vec::new()).await
+}
+
+/// Renames the template and replaces its exercises with the submitted ones, in their order.
+pub async fn update_template_action(
+    State(core_logic): State<Arc<AppState>>,
+    Path(template_id): Path<u64>,
+    Form(fields): Form<Vec<(String, String)>>,
+) -> Result<Response, PageError> {
+    let form = TemplateForm::from_fields(
+        template_id,
+        fields,
+        core_logic.exercise_model.read().await.as_ref(),
+    )
+    .await?;
+    let template = match form.to_template() {
+        Ok(template) => template,
+        Err(errors) => return TemplateEditorTemplate::rejected(&core_logic, form, errors).await,
+    };
+    let result = core_logic
+        .workout_plan_model
+        .write()
+        .await
+        .update_workout_template(template)
+        .await;
+    match result {
+        Ok(()) => Ok(Redirect::to("/templates").into_response()),
+        Err(WorkoutTemplateModelError::AlreadyExists) => {
+            let errors = duplicate_name_error(&form.name);
+            TemplateEditorTemplate::rejected(&core_logic, form, errors).await
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// Deletes the template, the workouts started from it are kept.
+pub async fn delete_template_action(
+    State(core_logic): State<Arc<AppState>>,
+    Path(template_id): Path<u64>,
+) -> Result<Redirect, PageError> {
+    core_logic
+        .workout_plan_model
+        .write()
+        .await
+        .delete_workout_template(template_id)
+        .await?;
+    Ok(Redirect::to("/templates"))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::StatusCode;
+    use serde_json::json;
+    use tower::ServiceExt;
+
+    use crate::inbound::testing::{
+        app, empty_request, form_request, json_body, json_request, page_body,
+    };
+
+    async fn seed(app: &axum::Router) {
+        for exercise in [
+            json!({ "name": "Squat", "goal": { "weighted": { "goalWeight": 100.0, "goalReps": 5 } } }),
+            json!({ "name": "Handstand", "goal": { "bodyweightTime": { "goalDurationSeconds": 30 } } }),
+        ] {
+            app.clone()
+                .oneshot(json_request("POST", "/api/exercises", exercise))
+                .await
+                .unwrap();
+        }
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/templates",
+                json!({ "name": "Legs", "exerciseIds": [1, 2], "setSchemes": { "2": "3x30s" } }),
+            ))
+            .await
+            .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn template_list_page_shows_planned_sets(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(empty_request("GET", "/templates"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains(r#"href="/templates/1/edit""#));
+        assert!(page.contains("Handstand 3×30 s"));
+    }
+
+    #[sqlx::test]
+    async fn edit_template_page_lists_exercises_in_order(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(empty_request("GET", "/templates/1/edit"))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        let page = page_body(response).await;
+        assert!(page.contains(r#"name="x0.exercise" value="1""#));
+        assert!(page.contains(r#"name="x1.scheme" id="x1.scheme" value="3x30s""#));
+        assert!(page.contains(r#"<option value="2">Handstand</option>"#));
+    }
+
+    #[sqlx::test]
+    async fn create_template_action_saves_template(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/templates/new",
+                &[
+                    ("name", "Strength"),
+                    ("x0.exercise", "2"),
+                    ("x0.scheme", ""),
+                    ("x1.exercise", "1"),
+                    ("x1.scheme", "5x5"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        assert_eq!(response.headers()["location"], "/templates");
+        let response = app
+            .oneshot(empty_request("GET", "/api/templates/2"))
+            .await
+            .unwrap();
+        let body = json_body(response).await;
+        assert_eq!(body["exercises"][0]["name"], "Handstand");
+        assert_eq!(body["exercises"][0]["setScheme"], json!(null));
+        assert_eq!(body["exercises"][1]["setScheme"], "5x5");
+    }
+
+    #[sqlx::test]
+    async fn create_template_action_shows_problems_inline(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .oneshot(form_request(
+                "/templates/new",
+                &[
+                    ("name", "Legs"),
+                    ("x0.exercise", "1"),
+                    ("x0.scheme", "lots"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let page = page_body(response).await;
+        assert!(page.contains("Squat: Planned sets must look like 5x5, 3xAMRAP or 3x30s"));
+        assert!(page.contains(r#"value="lots""#));
+    }
+
+    #[sqlx::test]
+    async fn update_template_action_shows_duplicate_name_inline(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+        app.clone()
+            .oneshot(json_request(
+                "POST",
+                "/api/templates",
+                json!({ "name": "Arms", "exerciseIds": [1] }),
+            ))
+            .await
+            .unwrap();
+
+        let response = app
+            .oneshot(form_request(
+                "/templates/2/edit",
+                &[("name", "Legs"), ("x0.exercise", "1")],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let page = page_body(response).await;
+        assert!(page.contains("A template called Legs already exists"));
+    }
+
+    #[sqlx::test]
+    async fn update_template_action_renames_and_reorders(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request(
+                "/templates/1/edit",
+                &[
+                    ("name", "Legs and balance"),
+                    ("x0.exercise", "2"),
+                    ("x0.scheme", "3x30s"),
+                    ("x1.exercise", "1"),
+                    ("x1.scheme", "3xAMRAP"),
+                ],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        let response = app
+            .oneshot(empty_request("GET", "/api/templates/1"))
+            .await
+            .unwrap();
+        let body = json_body(response).await;
+        assert_eq!(body["name"], "Legs and balance");
+        assert_eq!(body["exercises"][0]["name"], "Handstand");
+        assert_eq!(body["exercises"][1]["setScheme"], "3xAMRAP");
+    }
+
+    #[sqlx::test]
+    async fn delete_template_action_removes_template(pool: sqlx::SqlitePool) {
+        let app = app(pool);
+        seed(&app).await;
+
+        let response = app
+            .clone()
+            .oneshot(form_request("/templates/1/delete", &[]))
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::SEE_OTHER);
+        let response = app
+            .oneshot(empty_request("GET", "/templates/1/edit"))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+    }
+}
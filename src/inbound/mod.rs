@@ -1,5 +1,13 @@
 pub mod backend_routes;
+pub mod cli;
 pub mod frontend_routes;
 mod server;
+#[cfg(test)]
+mod testing;
 
-pub use server::server;
\ No newline at end of file
+pub use server::server;
+
+/// Reports an error answered with a generic 500 on stderr, the only place its details go.
+pub(crate) fn log_internal_error(error: &dyn std::fmt::Debug) {
+    eprintln!("internal error: {error:?}");
+}
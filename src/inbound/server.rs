@@ -1,19 +1,180 @@
-use axum::{Router, response::Redirect, routing::get};
+use std::sync::Arc;
+
+use axum::{
+    Router,
+    routing::{delete, get, post, put},
+};
 use tower_http::services::ServeDir;
 
-use crate::inbound::frontend_routes::new_workout::new_workout_page;
+use crate::{
+    inbound::{
+        backend_routes::{
+            exercise::{
+                exercise_create::create_exercise,
+                exercise_delete::delete_exercise,
+                exercise_one_rep_max::get_exercise_one_rep_max,
+                exercise_read::{get_exercise, list_exercises},
+                exercise_update::update_exercise,
+            },
+            goal_file::{goal_file_export::export_goal_file, goal_file_import::import_goal_file},
+            mood::mood_read::get_mood_report,
+            personal_record::personal_record_read::list_personal_records,
+            progression::{
+                progression_create::create_progression,
+                progression_delete::delete_progression,
+                progression_read::{get_progression, list_progressions},
+                progression_status::{get_progression_status, list_progression_statuses},
+                progression_update::update_progression,
+            },
+            stats::stats_read::get_training_stats,
+            workout::{
+                workout_create::create_workout,
+                workout_delete::delete_workout,
+                workout_read::{get_workout, list_workouts},
+                workout_update::update_workout,
+            },
+            workout_template::{
+                workout_template_create::create_workout_template,
+                workout_template_delete::delete_workout_template,
+                workout_template_read::{get_workout_template, list_workout_templates},
+                workout_template_update::{
+                    add_workout_template_exercise, remove_workout_template_exercise,
+                    reorder_workout_template, update_workout_template,
+                },
+            },
+        },
+        frontend_routes::{
+            calendar::calendar_page,
+            dashboard::dashboard_page,
+            exercises::{
+                create_exercise_action, delete_exercise_action, edit_exercise_page,
+                exercise_list_page, new_exercise_page, update_exercise_action,
+            },
+            mood::mood_page,
+            new_workout::{new_workout_page, save_new_workout},
+            progressions::{
+                create_progression_action, delete_progression_action, edit_progression_page,
+                new_progression_page, update_progression_action,
+            },
+            records::records_page,
+            stats::stats_page,
+            workout_edit::{edit_workout_page, save_workout_edit},
+            workout_history::{delete_workout_action, workout_detail_page, workout_history_page},
+            workout_templates::{
+                create_template_action, delete_template_action, edit_template_page,
+                new_template_page, template_list_page, update_template_action,
+            },
+        },
+    },
+    state::AppState,
+};
 
-pub fn server() -> Router {
+pub fn server(state: Arc<AppState>) -> Router {
     Router::new()
         .nest_service("/static", ServeDir::new("static"))
         .nest("/api", backend_routes())
         .merge(frontend_routes())
+        .with_state(state)
 }
 
-pub fn backend_routes() -> Router {
+pub fn backend_routes() -> Router<Arc<AppState>> {
     Router::new()
+        .route("/exercises", get(list_exercises).post(create_exercise))
+        .route(
+            "/exercises/{id}",
+            get(get_exercise)
+                .put(update_exercise)
+                .delete(delete_exercise),
+        )
+        .route("/exercises/{id}/one-rep-max", get(get_exercise_one_rep_max))
+        .route("/goals/export", get(export_goal_file))
+        .route("/goals/import", post(import_goal_file))
+        .route("/mood", get(get_mood_report))
+        .route(
+            "/progressions",
+            get(list_progressions).post(create_progression),
+        )
+        .route("/progressions/status", get(list_progression_statuses))
+        .route(
+            "/progressions/{name}",
+            get(get_progression)
+                .put(update_progression)
+                .delete(delete_progression),
+        )
+        .route("/progressions/{name}/status", get(get_progression_status))
+        .route("/records", get(list_personal_records))
+        .route("/stats", get(get_training_stats))
+        .route(
+            "/templates",
+            get(list_workout_templates).post(create_workout_template),
+        )
+        .route(
+            "/templates/{id}",
+            get(get_workout_template)
+                .put(update_workout_template)
+                .delete(delete_workout_template),
+        )
+        .route("/templates/{id}/order", put(reorder_workout_template))
+        .route(
+            "/templates/{id}/exercises",
+            post(add_workout_template_exercise),
+        )
+        .route(
+            "/templates/{id}/exercises/{exercise_id}",
+            delete(remove_workout_template_exercise),
+        )
+        .route("/workouts", get(list_workouts).post(create_workout))
+        .route(
+            "/workouts/{id}",
+            get(get_workout).put(update_workout).delete(delete_workout),
+        )
 }
 
-pub fn frontend_routes() -> Router {
-    Router::new().route("/new", get(new_workout_page).post(Redirect::to("/new")))
+pub fn frontend_routes() -> Router<Arc<AppState>> {
+    Router::new()
+        .route("/", get(dashboard_page))
+        .route("/calendar", get(calendar_page))
+        .route("/records", get(records_page))
+        .route("/stats", get(stats_page))
+        .route("/mood", get(mood_page))
+        .route("/new", get(new_workout_page).post(save_new_workout))
+        .route("/workouts", get(workout_history_page))
+        .route("/workouts/{id}", get(workout_detail_page))
+        .route(
+            "/workouts/{id}/edit",
+            get(edit_workout_page).post(save_workout_edit),
+        )
+        .route("/workouts/{id}/delete", post(delete_workout_action))
+        .route("/exercises", get(exercise_list_page))
+        .route(
+            "/exercises/new",
+            get(new_exercise_page).post(create_exercise_action),
+        )
+        .route(
+            "/exercises/{id}/edit",
+            get(edit_exercise_page).post(update_exercise_action),
+        )
+        .route("/exercises/{id}/delete", post(delete_exercise_action))
+        .route(
+            "/progressions/new",
+            get(new_progression_page).post(create_progression_action),
+        )
+        .route(
+            "/progressions/{name}/edit",
+            get(edit_progression_page).post(update_progression_action),
+        )
+        .route(
+            "/progressions/{name}/delete",
+            post(delete_progression_action),
+        )
+        .route("/templates", get(template_list_page))
+        .route(
+            "/templates/new",
+            get(new_template_page).post(create_template_action),
+        )
+        .route(
+            "/templates/{id}/edit",
+            get(edit_template_page).post(update_template_action),
+        )
+        .route("/templates/{id}/delete", post(delete_template_action))
 }
@@ -1,8 +1,54 @@
-use sport_tracker::inbound::server;
+use std::sync::Arc;
+
+use sport_tracker::{
+    inbound::{
+        cli::{self, Command, USAGE},
+        server,
+    },
+    outbound::database::{self, DEFAULT_DATABASE_URL},
+    state::AppState,
+};
 use tokio::net::TcpListener;
 
 #[tokio::main]
 async fn main() {
-    let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
-    axum::serve(listener, server()).await.unwrap();
+    let command = Command::parse(std::env::args().skip(1)).unwrap_or_else(|e| {
+        eprintln!("{e}\n{USAGE}");
+        std::process::exit(2);
+    });
+
+    let db_url = std::env::var("DATABASE_URL").unwrap_or_else(|_| DEFAULT_DATABASE_URL.to_string());
+    let db_pool = database::connect(&db_url).await.unwrap();
+    let state = Arc::new(AppState::new(db_pool));
+
+    match command {
+        Command::Serve => {
+            let listener = TcpListener::bind("0.0.0.0:3000").await.unwrap();
+            axum::serve(listener, server(state)).await.unwrap();
+        }
+        Command::ImportGoals { path, dry_run } => {
+            match cli::import_goal_file(&state, &path, dry_run).await {
+                Ok(report) => print!("{report}"),
+                Err(e) => {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::RefreshRecords => {
+            if let Err(e) = cli::refresh_records(&state).await {
+                eprintln!("{e}");
+                std::process::exit(1);
+            }
+        }
+        Command::ExportGoals { path } => {
+            match cli::export_goal_file(&state, path.as_deref()).await {
+                Ok(yaml) => print!("{yaml}"),
+                Err(e) => {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            }
+        }
+    }
 }
@@ -0,0 +1,183 @@
+use std::str::FromStr;
+
+use sqlx::{
+    SqlitePool,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+};
+
+pub const DEFAULT_DATABASE_URL: &str = "sqlite://./db.sqlite3";
+
+/// Opens the SQLite database at `db_url`, creating the file if needed, and
+/// brings its schema up to date with the `migrations/` folder.
+pub async fn connect(db_url: &str) -> Result<SqlitePool, sqlx::Error> {
+    let options = SqliteConnectOptions::from_str(db_url)?.create_if_missing(true);
+    let db_pool = SqlitePoolOptions::new()
+        .max_connections(5)
+        .connect_with(options)
+        .await?;
+    sqlx::migrate!().run(&db_pool).await?;
+    Ok(db_pool)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Applies the migrations from `first` to `last` version, both included, so a test can
+    /// insert rows in the shape an older schema had before migrating them.
+    async fn migrate_between(db_pool: &SqlitePool, first: i64, last: i64) {
+        for migration in sqlx::migrate!().iter() {
+            if (first..=last).contains(&migration.version) {
+                sqlx::raw_sql(&migration.sql)
+                    .execute(db_pool)
+                    .await
+                    .unwrap_or_else(|e| panic!("migration {} failed: {e}", migration.version));
+            }
+        }
+    }
+
+    /// Workout 1 with squat (1) and bench (2), sets not yet attached to exercise entries
+    async fn seed_legacy_workout(db_pool: &SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES
+             ('squat', 'weighted', 100.0), ('bench', 'weighted', 80.0);
+             INSERT INTO workout (date) VALUES ('2026-03-03');",
+        )
+        .execute(db_pool)
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn connect_runs_migrations() {
+        // Every connection to `sqlite::memory:` opens its own empty database, so the test uses
+        // a file and reads it back through a second pool.
+        let path = std::env::temp_dir().join(format!(
+            "sport_tracker_connect_{}.sqlite3",
+            std::process::id()
+        ));
+        let db_url = format!("sqlite://{}", path.display());
+        connect(&db_url)
+            .await
+            .expect("connect should succeed")
+            .close()
+            .await;
+        let db_pool = SqlitePool::connect(&db_url).await.unwrap();
+
+        let tables: Vec<(String,)> = sqlx::query_as(
+            "SELECT name FROM sqlite_master WHERE type = 'table' AND name NOT LIKE '\\_%' ESCAPE '\\' ORDER BY name",
+        )
+        .fetch_all(&db_pool)
+        .await
+        .unwrap();
+        let tables: Vec<&str> = tables.iter().map(|(name,)| name.as_str()).collect();
+
+        assert!(tables.contains(&"exercise"));
+        assert!(tables.contains(&"workout"));
+        assert!(tables.contains(&"workout_exercise"));
+        assert!(tables.contains(&"workout_set"));
+        assert!(tables.contains(&"workout_template"));
+        db_pool.close().await;
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[sqlx::test(migrations = false)]
+    async fn exercise_positions_migration_splits_set_order(db_pool: SqlitePool) {
+        migrate_between(&db_pool, 0, 20261018100200).await;
+        seed_legacy_workout(&db_pool).await;
+        sqlx::query(
+            "INSERT INTO workout_set (workout_id, exercise_id, set_order, reps, weight) VALUES
+             (1, 1, 1, 5, 90.0), (1, 1, 2, 5, 95.0), (1, 2, 1001, 8, 60.0), (1, 1, 2001, 10, 60.0)",
+        )
+        .execute(&db_pool)
+        .await
+        .unwrap();
+
+        migrate_between(&db_pool, 20261018100300, 20261018100300).await;
+
+        let sets: Vec<(i64, i64, i64)> = sqlx::query_as(
+            "SELECT exercise_id, exercise_position, set_order FROM workout_set ORDER BY id",
+        )
+        .fetch_all(&db_pool)
+        .await
+        .unwrap();
+        assert_eq!(sets, [(1, 0, 1), (1, 0, 2), (2, 1, 1), (1, 2, 1)]);
+    }
+
+    async fn migrated_entries(
+        db_pool: &SqlitePool,
+    ) -> (Vec<(i64, i64, i64)>, Vec<(i64, i64, i64)>) {
+        let entries = sqlx::query_as(
+            "SELECT id, exercise_id, position FROM workout_exercise ORDER BY position",
+        )
+        .fetch_all(db_pool)
+        .await
+        .unwrap();
+        let sets = sqlx::query_as(
+            "SELECT id, workout_exercise_id, set_order FROM workout_set ORDER BY id",
+        )
+        .fetch_all(db_pool)
+        .await
+        .unwrap();
+        (entries, sets)
+    }
+
+    #[sqlx::test(migrations = false)]
+    async fn workout_exercise_migration_gives_each_position_an_entry(db_pool: SqlitePool) {
+        migrate_between(&db_pool, 0, 20261018100300).await;
+        seed_legacy_workout(&db_pool).await;
+        sqlx::query(
+            "INSERT INTO workout_set (workout_id, exercise_id, exercise_position, set_order, reps, weight) VALUES
+             (1, 1, 0, 1, 5, 90.0), (1, 1, 0, 2, 5, 95.0), (1, 2, 1, 1, 8, 60.0), (1, 1, 2, 1, 10, 60.0)",
+        )
+        .execute(&db_pool)
+        .await
+        .unwrap();
+
+        migrate_between(&db_pool, 20261018100400, 20261018100400).await;
+
+        let (entries, sets) = migrated_entries(&db_pool).await;
+        assert_eq!(entries, [(1, 1, 0), (2, 2, 1), (3, 1, 2)]);
+        assert_eq!(sets, [(1, 1, 1), (2, 1, 2), (3, 2, 1), (4, 3, 1)]);
+    }
+
+    #[sqlx::test(migrations = false)]
+    async fn workout_exercise_migration_splits_exercises_sharing_a_position(db_pool: SqlitePool) {
+        migrate_between(&db_pool, 0, 20261018100300).await;
+        seed_legacy_workout(&db_pool).await;
+        sqlx::query(
+            "INSERT INTO workout_set (workout_id, exercise_id, exercise_position, set_order, reps, weight) VALUES
+             (1, 2, 0, 1, 8, 60.0), (1, 1, 0, 1, 5, 90.0), (1, 1, 0, 2, 5, 95.0)",
+        )
+        .execute(&db_pool)
+        .await
+        .unwrap();
+
+        migrate_between(&db_pool, 20261018100400, 20261018100400).await;
+
+        let (entries, sets) = migrated_entries(&db_pool).await;
+        assert_eq!(entries, [(1, 1, 0), (2, 2, 1)]);
+        assert_eq!(sets, [(1, 2, 1), (2, 1, 1), (3, 1, 2)]);
+    }
+
+    #[sqlx::test(migrations = false)]
+    async fn exercise_name_migration_renames_names_clashing_by_case(db_pool: SqlitePool) {
+        migrate_between(&db_pool, 0, 20261018100500).await;
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES
+             ('Squat', 'weighted', 100.0), ('bench', 'weighted', 80.0), ('squat', 'weighted', 90.0)",
+        )
+        .execute(&db_pool)
+        .await
+        .unwrap();
+
+        migrate_between(&db_pool, 20261018100600, 20261018100600).await;
+
+        let names: Vec<(String,)> = sqlx::query_as("SELECT name FROM exercise ORDER BY id")
+            .fetch_all(&db_pool)
+            .await
+            .unwrap();
+        let names: Vec<&str> = names.iter().map(|(name,)| name.as_str()).collect();
+        assert_eq!(names, ["Squat", "bench", "squat (3)"]);
+    }
+}
@@ -1,8 +1,10 @@
-use sqlx::{QueryBuilder, SqlitePool, sqlite::Sqlite, sqlite::SqlitePoolOptions};
+use std::collections::HashMap;
+
+use sqlx::{QueryBuilder, SqliteConnection, SqlitePool, sqlite::Sqlite, sqlite::SqlitePoolOptions};
 
 use crate::domain::{
     traits::exercise_model::{ExerciseModel, ExerciseModelError},
-    types::exercise::{Exercise, ExerciseProgression, ExerciseType},
+    types::exercise::{Exercise, ExerciseChange, ExerciseProgression, ExerciseType},
 };
 
 #[derive(Debug, Clone)]
@@ -26,10 +28,10 @@ struct SqliteExercise {
     pub name: String,
     pub exercise_type: SqliteExerciseType,
     pub progression_name: Option<String>,
-    pub progression_order: Option<u8>,
     pub goal_reps: Option<u16>,
     pub goal_weight: Option<f32>,
     pub goal_duration_seconds: Option<u16>,
+    pub goal_sets: Option<u16>,
 }
 
 struct SqliteExerciseInsert {
@@ -40,6 +42,7 @@ struct SqliteExerciseInsert {
     pub goal_reps: Option<u16>,
     pub goal_weight: Option<f32>,
     pub goal_duration_seconds: Option<u16>,
+    pub goal_sets: Option<u16>,
 }
 
 struct SqliteExerciseUpdate {
@@ -51,28 +54,49 @@ struct SqliteExerciseUpdate {
     pub goal_reps: Option<u16>,
     pub goal_weight: Option<f32>,
     pub goal_duration_seconds: Option<u16>,
+    pub goal_sets: Option<u16>,
 }
 
 fn exercise_goals_to_sqlite(
     exercise_type: &ExerciseType,
-) -> (SqliteExerciseType, Option<u16>, Option<f32>, Option<u16>) {
+) -> (
+    SqliteExerciseType,
+    Option<u16>,
+    Option<f32>,
+    Option<u16>,
+    Option<u16>,
+) {
     match exercise_type {
-        ExerciseType::Weighted { goal_weight } => {
-            (SqliteExerciseType::Weighted, None, Some(*goal_weight), None)
-        }
-        ExerciseType::BodyweightReps { goal_reps } => (
+        ExerciseType::Weighted {
+            goal_weight,
+            goal_reps,
+            goal_sets,
+        } => (
+            SqliteExerciseType::Weighted,
+            Some(*goal_reps),
+            Some(*goal_weight),
+            None,
+            Some(*goal_sets),
+        ),
+        ExerciseType::BodyweightReps {
+            goal_reps,
+            goal_sets,
+        } => (
             SqliteExerciseType::BodyweightReps,
             Some(*goal_reps),
             None,
             None,
+            Some(*goal_sets),
         ),
         ExerciseType::BodyweightTime {
             goal_duration_seconds,
+            goal_sets,
         } => (
             SqliteExerciseType::BodyweightTime,
             None,
             None,
             Some(*goal_duration_seconds),
+            Some(*goal_sets),
         ),
     }
 }
@@ -82,12 +106,16 @@ impl From<SqliteExercise> for Exercise {
         let exercise_type = match row.exercise_type {
             SqliteExerciseType::Weighted => ExerciseType::Weighted {
                 goal_weight: row.goal_weight.unwrap_or(0.0),
+                goal_reps: row.goal_reps.unwrap_or(1),
+                goal_sets: row.goal_sets.unwrap_or(1),
             },
             SqliteExerciseType::BodyweightReps => ExerciseType::BodyweightReps {
                 goal_reps: row.goal_reps.unwrap_or(0),
+                goal_sets: row.goal_sets.unwrap_or(1),
             },
             SqliteExerciseType::BodyweightTime => ExerciseType::BodyweightTime {
                 goal_duration_seconds: row.goal_duration_seconds.unwrap_or(0),
+                goal_sets: row.goal_sets.unwrap_or(1),
             },
         };
 
@@ -104,7 +132,7 @@ fn from_exercise_to_sqlite_insert(
     progression_name: Option<String>,
     progression_order: Option<u8>,
 ) -> SqliteExerciseInsert {
-    let (exercise_type, goal_reps, goal_weight, goal_duration_seconds) =
+    let (exercise_type, goal_reps, goal_weight, goal_duration_seconds, goal_sets) =
         exercise_goals_to_sqlite(&exercise.exercise_type);
 
     SqliteExerciseInsert {
@@ -115,6 +143,7 @@ fn from_exercise_to_sqlite_insert(
         goal_reps,
         goal_weight,
         goal_duration_seconds,
+        goal_sets,
     }
 }
 
@@ -123,7 +152,7 @@ fn from_exercise_to_sqlite_update(
     progression_name: Option<String>,
     progression_order: Option<u8>,
 ) -> SqliteExerciseUpdate {
-    let (exercise_type, goal_reps, goal_weight, goal_duration_seconds) =
+    let (exercise_type, goal_reps, goal_weight, goal_duration_seconds, goal_sets) =
         exercise_goals_to_sqlite(&exercise.exercise_type);
 
     SqliteExerciseUpdate {
@@ -135,6 +164,18 @@ fn from_exercise_to_sqlite_update(
         goal_reps,
         goal_weight,
         goal_duration_seconds,
+        goal_sets,
+    }
+}
+
+fn transaction_error(error: sqlx::Error) -> ExerciseModelError {
+    ExerciseModelError::DatabaseError(error.to_string())
+}
+
+fn map_write_error(error: sqlx::Error) -> ExerciseModelError {
+    match error.as_database_error() {
+        Some(db_error) if db_error.is_unique_violation() => ExerciseModelError::AlreadyExists,
+        _ => ExerciseModelError::DatabaseError(error.to_string()),
     }
 }
 
@@ -147,7 +188,6 @@ impl ExerciseRepository {
         Ok(Self { db_pool })
     }
 
-    #[cfg(test)]
     pub fn from_pool(db_pool: SqlitePool) -> Self {
         Self { db_pool }
     }
@@ -158,7 +198,7 @@ impl ExerciseRepository {
     ) -> Result<SqliteExercise, ExerciseModelError> {
         sqlx::query_as(
             r#"
-            SELECT id, name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds
+            SELECT id, name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds, goal_sets
             FROM exercise
             WHERE id = $1
             "#,
@@ -172,83 +212,182 @@ impl ExerciseRepository {
         })
     }
 
-    async fn add_exercise(
-        &mut self,
+    async fn insert_exercise(
+        conn: &mut SqliteConnection,
         exercise: SqliteExerciseInsert,
-    ) -> Result<(), ExerciseModelError> {
-        sqlx::query(
+    ) -> Result<u64, ExerciseModelError> {
+        let result = sqlx::query(
             r#"
-            INSERT INTO exercise (name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds)
-            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            INSERT INTO exercise (name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds, goal_sets)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
             "#,
         )
         .bind(&exercise.name)
-        .bind(&exercise.exercise_type)
+        .bind(exercise.exercise_type)
         .bind(&exercise.progression_name)
-        .bind(&exercise.progression_order)
-        .bind(&exercise.goal_reps)
-        .bind(&exercise.goal_weight)
-        .bind(&exercise.goal_duration_seconds)
-        .execute(&self.db_pool)
+        .bind(exercise.progression_order)
+        .bind(exercise.goal_reps)
+        .bind(exercise.goal_weight)
+        .bind(exercise.goal_duration_seconds)
+        .bind(exercise.goal_sets)
+        .execute(&mut *conn)
         .await
-        .map_err(|e| ExerciseModelError::DatabaseError(e.to_string()))?;
-        Ok(())
+        .map_err(map_write_error)?;
+        Ok(result.last_insert_rowid() as u64)
     }
 
-    async fn add_multiple_exercises(
-        &mut self,
+    async fn insert_multiple_exercises(
+        conn: &mut SqliteConnection,
         exercises: Vec<SqliteExerciseInsert>,
     ) -> Result<(), ExerciseModelError> {
         if exercises.is_empty() {
             return Ok(());
         }
         let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(
-            "INSERT INTO exercise (name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds) ",
+            "INSERT INTO exercise (name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds, goal_sets) ",
         );
         query_builder.push_values(exercises.iter(), |mut b, exercise| {
             b.push_bind(&exercise.name)
-                .push_bind(&exercise.exercise_type)
+                .push_bind(exercise.exercise_type)
                 .push_bind(&exercise.progression_name)
-                .push_bind(&exercise.progression_order)
-                .push_bind(&exercise.goal_reps)
-                .push_bind(&exercise.goal_weight)
-                .push_bind(&exercise.goal_duration_seconds);
+                .push_bind(exercise.progression_order)
+                .push_bind(exercise.goal_reps)
+                .push_bind(exercise.goal_weight)
+                .push_bind(exercise.goal_duration_seconds)
+                .push_bind(exercise.goal_sets);
         });
         let query = query_builder.build();
-        query
-            .execute(&self.db_pool)
-            .await
-            .map_err(|e| ExerciseModelError::DatabaseError(e.to_string()))?;
+        query.execute(&mut *conn).await.map_err(map_write_error)?;
+        Ok(())
+    }
+
+    /// Updates the name and goal of an exercise, leaving its progression alone.
+    async fn update_exercise_goal(
+        conn: &mut SqliteConnection,
+        exercise: Exercise,
+    ) -> Result<(), ExerciseModelError> {
+        let (exercise_type, goal_reps, goal_weight, goal_duration_seconds, goal_sets) =
+            exercise_goals_to_sqlite(&exercise.exercise_type);
+
+        let result = sqlx::query(
+            r#"
+            UPDATE exercise
+            SET name = $1, exercise_type = $2, goal_reps = $3, goal_weight = $4, goal_duration_seconds = $5, goal_sets = $6
+            WHERE id = $7
+            "#,
+        )
+        .bind(&exercise.name)
+        .bind(exercise_type)
+        .bind(goal_reps)
+        .bind(goal_weight)
+        .bind(goal_duration_seconds)
+        .bind(goal_sets)
+        .bind(exercise.id as i64)
+        .execute(&mut *conn)
+        .await
+        .map_err(map_write_error)?;
+
+        if result.rows_affected() == 0 {
+            return Err(ExerciseModelError::NotFound);
+        }
         Ok(())
     }
 
     async fn update_multiple_exercises(
-        &mut self,
+        conn: &mut SqliteConnection,
         exercises: Vec<SqliteExerciseUpdate>,
     ) -> Result<(), ExerciseModelError> {
         for exercise in exercises {
-            sqlx::query(
+            let result = sqlx::query(
                 r#"
                 UPDATE exercise
-                SET name = $1, exercise_type = $2, progression_name = $3, progression_order = $4, goal_reps = $5, goal_weight = $6, goal_duration_seconds = $7
-                WHERE id = $8
+                SET name = $1, exercise_type = $2, progression_name = $3, progression_order = $4, goal_reps = $5, goal_weight = $6, goal_duration_seconds = $7, goal_sets = $8
+                WHERE id = $9
                 "#,
             )
             .bind(&exercise.name)
-            .bind(&exercise.exercise_type)
+            .bind(exercise.exercise_type)
             .bind(&exercise.progression_name)
             .bind(exercise.progression_order.map(|o| o as i64))
-            .bind(&exercise.goal_reps)
-            .bind(&exercise.goal_weight)
-            .bind(&exercise.goal_duration_seconds)
+            .bind(exercise.goal_reps)
+            .bind(exercise.goal_weight)
+            .bind(exercise.goal_duration_seconds)
+            .bind(exercise.goal_sets)
             .bind(exercise.id as i64)
-            .execute(&self.db_pool)
+            .execute(&mut *conn)
             .await
-            .map_err(|e| ExerciseModelError::DatabaseError(e.to_string()))?;
+            .map_err(map_write_error)?;
+
+            if result.rows_affected() == 0 {
+                return Err(ExerciseModelError::NotFound);
+            }
         }
         Ok(())
     }
 
+    /// Removes every exercise of `progression_name` not listed in `kept_ids` from the
+    /// progression. The exercises themselves are kept so their logged sets survive.
+    async fn detach_exercises_from_progression(
+        conn: &mut SqliteConnection,
+        progression_name: &str,
+        kept_ids: &[u64],
+    ) -> Result<(), ExerciseModelError> {
+        let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+            "UPDATE exercise SET progression_name = NULL, progression_order = NULL WHERE progression_name = ",
+        );
+        query_builder.push_bind(progression_name);
+        if !kept_ids.is_empty() {
+            query_builder.push(" AND id NOT IN (");
+            let mut separated = query_builder.separated(", ");
+            for id in kept_ids {
+                separated.push_bind(*id as i64);
+            }
+            separated.push_unseparated(")");
+        }
+        query_builder
+            .build()
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| ExerciseModelError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Detaches the exercises no longer listed, then updates the listed ones and creates the
+    /// ones with an `id` of 0.
+    async fn save_progression(
+        conn: &mut SqliteConnection,
+        progression: ExerciseProgression,
+    ) -> Result<(), ExerciseModelError> {
+        let kept_ids: Vec<u64> = progression
+            .progression
+            .iter()
+            .map(|exercise| exercise.id)
+            .filter(|id| *id != 0)
+            .collect();
+        Self::detach_exercises_from_progression(conn, &progression.name, &kept_ids).await?;
+
+        let mut updated_exercises = Vec::new();
+        let mut new_exercises = Vec::new();
+        for (index, exercise) in progression.progression.into_iter().enumerate() {
+            let order = Some((index + 1) as u8);
+            if exercise.id == 0 {
+                new_exercises.push(from_exercise_to_sqlite_insert(
+                    exercise,
+                    Some(progression.name.clone()),
+                    order,
+                ));
+            } else {
+                updated_exercises.push(from_exercise_to_sqlite_update(
+                    exercise,
+                    Some(progression.name.clone()),
+                    order,
+                ));
+            }
+        }
+        Self::update_multiple_exercises(conn, updated_exercises).await?;
+        Self::insert_multiple_exercises(conn, new_exercises).await
+    }
+
     async fn delete_multiple_exercises_by_progression_name(
         &mut self,
         progression_name: &str,
@@ -272,11 +411,30 @@ impl ExerciseModel for ExerciseRepository {
         self.get_repository_exercise_by_id(id).await.map(Into::into)
     }
 
+    async fn get_exercise_by_name(&self, name: &str) -> Result<Exercise, ExerciseModelError> {
+        let row: SqliteExercise = sqlx::query_as(
+            r#"
+            SELECT id, name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds, goal_sets
+            FROM exercise
+            WHERE name = $1 COLLATE NOCASE
+            "#,
+        )
+        .bind(name)
+        .fetch_one(&self.db_pool)
+        .await
+        .map_err(|e| match e {
+            sqlx::Error::RowNotFound => ExerciseModelError::NotFound,
+            other => ExerciseModelError::DatabaseError(other.to_string()),
+        })?;
+        Ok(row.into())
+    }
+
     async fn get_all_exercises(&self) -> Result<Vec<Exercise>, ExerciseModelError> {
         let rows: Vec<SqliteExercise> = sqlx::query_as(
             r#"
-            SELECT id, name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds
+            SELECT id, name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds, goal_sets
             FROM exercise
+            ORDER BY id
             "#,
         )
         .fetch_all(&self.db_pool)
@@ -287,36 +445,41 @@ impl ExerciseModel for ExerciseRepository {
         Ok(rows.into_iter().map(Into::into).collect())
     }
 
-    async fn add_exercise(&mut self, exercise: Exercise) -> Result<(), ExerciseModelError> {
-        self.add_exercise(from_exercise_to_sqlite_insert(exercise, None, None))
+    async fn get_exercises_by_ids(&self, ids: &[u64]) -> Result<Vec<Exercise>, ExerciseModelError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+            r#"
+            SELECT id, name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds, goal_sets
+            FROM exercise
+            WHERE id IN ("#,
+        );
+        let mut separated = query_builder.separated(", ");
+        for id in ids {
+            separated.push_bind(*id as i64);
+        }
+        separated.push_unseparated(")");
+        let rows: Vec<SqliteExercise> = query_builder
+            .build_query_as()
+            .fetch_all(&self.db_pool)
             .await
+            .map_err(|e| ExerciseModelError::DatabaseError(e.to_string()))?;
+        Ok(rows.into_iter().map(Into::into).collect())
     }
 
-    async fn update_exercise(&mut self, exercise: Exercise) -> Result<(), ExerciseModelError> {
-        let (exercise_type, goal_reps, goal_weight, goal_duration_seconds) =
-            exercise_goals_to_sqlite(&exercise.exercise_type);
-
-        let result = sqlx::query(
-            r#"
-            UPDATE exercise
-            SET name = $1, exercise_type = $2, goal_reps = $3, goal_weight = $4, goal_duration_seconds = $5
-            WHERE id = $6
-            "#,
+    async fn add_exercise(&mut self, exercise: Exercise) -> Result<u64, ExerciseModelError> {
+        let mut conn = self.db_pool.acquire().await.map_err(transaction_error)?;
+        Self::insert_exercise(
+            &mut conn,
+            from_exercise_to_sqlite_insert(exercise, None, None),
         )
-        .bind(&exercise.name)
-        .bind(exercise_type)
-        .bind(goal_reps)
-        .bind(goal_weight)
-        .bind(goal_duration_seconds)
-        .bind(exercise.id as i64)
-        .execute(&self.db_pool)
         .await
-        .map_err(|e| ExerciseModelError::DatabaseError( e.to_string()))?;
+    }
 
-        if result.rows_affected() == 0 {
-            return Err(ExerciseModelError::NotFound);
-        }
-        Ok(())
+    async fn update_exercise(&mut self, exercise: Exercise) -> Result<(), ExerciseModelError> {
+        let mut conn = self.db_pool.acquire().await.map_err(transaction_error)?;
+        Self::update_exercise_goal(&mut conn, exercise).await
     }
 
     async fn delete_exercise(&mut self, id: u64) -> Result<(), ExerciseModelError> {
@@ -337,7 +500,7 @@ impl ExerciseModel for ExerciseRepository {
     ) -> Result<Vec<ExerciseProgression>, ExerciseModelError> {
         let exercises: Vec<SqliteExercise> = sqlx::query_as(
             r#"
-            SELECT id, name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds
+            SELECT id, name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds, goal_sets
             FROM exercise
             WHERE progression_name IS NOT NULL
             ORDER BY progression_name, progression_order
@@ -351,7 +514,7 @@ impl ExerciseModel for ExerciseRepository {
 
         let result = exercises
             .into_iter()
-            .fold(std::collections::HashMap::new(), |mut acc, exercise| {
+            .fold(std::collections::BTreeMap::new(), |mut acc, exercise| {
                 acc.entry(exercise.progression_name.clone().unwrap())
                     .or_insert_with(Vec::new)
                     .push(exercise.into());
@@ -387,7 +550,7 @@ impl ExerciseModel for ExerciseRepository {
     ) -> Result<ExerciseProgression, ExerciseModelError> {
         let exercises: Vec<SqliteExercise> = sqlx::query_as(
             r#"
-            SELECT id, name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds
+            SELECT id, name, exercise_type, progression_name, progression_order, goal_reps, goal_weight, goal_duration_seconds, goal_sets
             FROM exercise
             WHERE progression_name = $1
             ORDER BY progression_order
@@ -424,26 +587,17 @@ impl ExerciseModel for ExerciseRepository {
                 )
             })
             .collect();
-        self.add_multiple_exercises(exercises).await
+        let mut conn = self.db_pool.acquire().await.map_err(transaction_error)?;
+        Self::insert_multiple_exercises(&mut conn, exercises).await
     }
 
     async fn update_exercise_progression(
         &mut self,
         progression: ExerciseProgression,
     ) -> Result<(), ExerciseModelError> {
-        let exercises: Vec<SqliteExerciseUpdate> = progression
-            .progression
-            .into_iter()
-            .enumerate()
-            .map(|(index, exercise)| {
-                from_exercise_to_sqlite_update(
-                    exercise,
-                    Some(progression.name.clone()),
-                    Some((index + 1) as u8),
-                )
-            })
-            .collect();
-        self.update_multiple_exercises(exercises).await
+        let mut tx = self.db_pool.begin().await.map_err(transaction_error)?;
+        Self::save_progression(&mut tx, progression).await?;
+        tx.commit().await.map_err(transaction_error)
     }
 
     async fn delete_exercise_progression(
@@ -453,6 +607,41 @@ impl ExerciseModel for ExerciseRepository {
         self.delete_multiple_exercises_by_progression_name(&progression.name)
             .await
     }
+
+    async fn save_exercise_changes(
+        &mut self,
+        changes: Vec<ExerciseChange>,
+    ) -> Result<(), ExerciseModelError> {
+        let mut tx = self.db_pool.begin().await.map_err(transaction_error)?;
+        let mut added: HashMap<String, u64> = HashMap::new();
+        for change in changes {
+            match change {
+                ExerciseChange::Add(exercise) => {
+                    let name = exercise.name.to_lowercase();
+                    let id = Self::insert_exercise(
+                        &mut tx,
+                        from_exercise_to_sqlite_insert(exercise, None, None),
+                    )
+                    .await?;
+                    added.insert(name, id);
+                }
+                ExerciseChange::Update(exercise) => {
+                    Self::update_exercise_goal(&mut tx, exercise).await?;
+                }
+                ExerciseChange::SaveProgression(mut progression) => {
+                    for step in &mut progression.progression {
+                        if step.id == 0
+                            && let Some(id) = added.get(&step.name.to_lowercase())
+                        {
+                            step.id = *id;
+                        }
+                    }
+                    Self::save_progression(&mut tx, progression).await?;
+                }
+            }
+        }
+        tx.commit().await.map_err(transaction_error)
+    }
 }
 
 #[cfg(test)]
@@ -483,7 +672,7 @@ mod tests {
         assert_eq!(exercise.id, 1);
         assert_eq!(exercise.name, "squat");
         match &exercise.exercise_type {
-            ExerciseType::Weighted { goal_weight } => assert_eq!(*goal_weight, 60.0),
+            ExerciseType::Weighted { goal_weight, .. } => assert_eq!(*goal_weight, 60.0),
             _ => panic!("expected Weighted exercise type"),
         }
     }
@@ -495,6 +684,52 @@ mod tests {
         assert!(matches!(result, Err(ExerciseModelError::NotFound)));
     }
 
+    #[sqlx::test]
+    async fn get_exercise_by_name_ignores_case(pool: sqlx::SqlitePool) {
+        sqlx::query("INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('Squat', 'weighted', 60.0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let repo = make_repo(pool);
+        let exercise = repo
+            .get_exercise_by_name("sQUAT")
+            .await
+            .expect("fetch should succeed");
+
+        assert_eq!(exercise.id, 1);
+        assert_eq!(exercise.name, "Squat");
+    }
+
+    #[sqlx::test]
+    async fn add_exercise_rejects_name_differing_only_by_case(pool: sqlx::SqlitePool) {
+        sqlx::query("INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('Squat', 'weighted', 60.0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let mut repo = make_repo(pool);
+        let exercise = Exercise {
+            id: 0,
+            name: "SQUAT".to_string(),
+            exercise_type: ExerciseType::Weighted {
+                goal_weight: 80.0,
+                goal_reps: 1,
+                goal_sets: 1,
+            },
+        };
+
+        let result = ExerciseModel::add_exercise(&mut repo, exercise).await;
+        assert!(matches!(result, Err(ExerciseModelError::AlreadyExists)));
+    }
+
+    #[sqlx::test]
+    async fn get_exercise_by_name_returns_not_found_when_missing(pool: sqlx::SqlitePool) {
+        let repo = make_repo(pool);
+        let result = repo.get_exercise_by_name("phantom").await;
+        assert!(matches!(result, Err(ExerciseModelError::NotFound)));
+    }
+
     #[sqlx::test]
     async fn get_all_exercises_returns_empty_when_no_exercises(pool: sqlx::SqlitePool) {
         let repo = make_repo(pool);
@@ -533,7 +768,11 @@ mod tests {
         let exercise = Exercise {
             id: 0,
             name: "bench press".to_string(),
-            exercise_type: ExerciseType::Weighted { goal_weight: 80.0 },
+            exercise_type: ExerciseType::Weighted {
+                goal_weight: 80.0,
+                goal_reps: 1,
+                goal_sets: 1,
+            },
         };
 
         ExerciseModel::add_exercise(&mut repo, exercise)
@@ -543,7 +782,7 @@ mod tests {
         let fetched = repo.get_exercise_by_id(1).await.expect("should exist");
         assert_eq!(fetched.name, "bench press");
         match &fetched.exercise_type {
-            ExerciseType::Weighted { goal_weight } => assert_eq!(*goal_weight, 80.0),
+            ExerciseType::Weighted { goal_weight, .. } => assert_eq!(*goal_weight, 80.0),
             _ => panic!("expected Weighted"),
         }
     }
@@ -554,7 +793,10 @@ mod tests {
         let exercise = Exercise {
             id: 0,
             name: "pullup".to_string(),
-            exercise_type: ExerciseType::BodyweightReps { goal_reps: 8 },
+            exercise_type: ExerciseType::BodyweightReps {
+                goal_reps: 8,
+                goal_sets: 1,
+            },
         };
 
         ExerciseModel::add_exercise(&mut repo, exercise)
@@ -563,7 +805,7 @@ mod tests {
 
         let fetched = repo.get_exercise_by_id(1).await.expect("should exist");
         match &fetched.exercise_type {
-            ExerciseType::BodyweightReps { goal_reps } => assert_eq!(*goal_reps, 8),
+            ExerciseType::BodyweightReps { goal_reps, .. } => assert_eq!(*goal_reps, 8),
             _ => panic!("expected BodyweightReps"),
         }
     }
@@ -576,6 +818,7 @@ mod tests {
             name: "plank".to_string(),
             exercise_type: ExerciseType::BodyweightTime {
                 goal_duration_seconds: 60,
+                goal_sets: 1,
             },
         };
 
@@ -587,6 +830,7 @@ mod tests {
         match &fetched.exercise_type {
             ExerciseType::BodyweightTime {
                 goal_duration_seconds,
+                ..
             } => {
                 assert_eq!(*goal_duration_seconds, 60)
             }
@@ -594,6 +838,98 @@ mod tests {
         }
     }
 
+    #[sqlx::test]
+    async fn add_exercise_stores_sets_and_reps_goal(pool: sqlx::SqlitePool) {
+        let mut repo = make_repo(pool);
+        let goal = ExerciseType::Weighted {
+            goal_weight: 100.0,
+            goal_reps: 5,
+            goal_sets: 3,
+        };
+        let exercise = Exercise {
+            id: 0,
+            name: "squat".to_string(),
+            exercise_type: goal.clone(),
+        };
+
+        ExerciseModel::add_exercise(&mut repo, exercise)
+            .await
+            .expect("add should succeed");
+
+        let fetched = repo.get_exercise_by_id(1).await.expect("should exist");
+        assert_eq!(fetched.exercise_type, goal);
+    }
+
+    #[sqlx::test]
+    async fn get_exercise_by_id_defaults_missing_goal_sets_to_one(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_reps) VALUES ('pushup', 'bodyweight_reps', 10)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let repo = make_repo(pool);
+        let fetched = repo.get_exercise_by_id(1).await.expect("should exist");
+        assert_eq!(
+            fetched.exercise_type,
+            ExerciseType::BodyweightReps {
+                goal_reps: 10,
+                goal_sets: 1,
+            }
+        );
+    }
+
+    #[sqlx::test]
+    async fn add_exercise_returns_new_id(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 60.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let mut repo = make_repo(pool);
+        let exercise = Exercise {
+            id: 0,
+            name: "deadlift".to_string(),
+            exercise_type: ExerciseType::Weighted {
+                goal_weight: 140.0,
+                goal_reps: 1,
+                goal_sets: 1,
+            },
+        };
+
+        let id = ExerciseModel::add_exercise(&mut repo, exercise)
+            .await
+            .expect("add should succeed");
+        assert_eq!(id, 2);
+    }
+
+    #[sqlx::test]
+    async fn add_exercise_returns_already_exists_on_duplicate_name(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 60.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let mut repo = make_repo(pool);
+        let exercise = Exercise {
+            id: 0,
+            name: "squat".to_string(),
+            exercise_type: ExerciseType::Weighted {
+                goal_weight: 80.0,
+                goal_reps: 1,
+                goal_sets: 1,
+            },
+        };
+
+        let result = ExerciseModel::add_exercise(&mut repo, exercise).await;
+        assert!(matches!(result, Err(ExerciseModelError::AlreadyExists)));
+    }
+
     #[sqlx::test]
     async fn update_exercise_modifies_existing(pool: sqlx::SqlitePool) {
         sqlx::query(
@@ -607,7 +943,11 @@ mod tests {
         let exercise = Exercise {
             id: 1,
             name: "front squat".to_string(),
-            exercise_type: ExerciseType::Weighted { goal_weight: 70.0 },
+            exercise_type: ExerciseType::Weighted {
+                goal_weight: 70.0,
+                goal_reps: 1,
+                goal_sets: 1,
+            },
         };
 
         repo.update_exercise(exercise)
@@ -617,7 +957,7 @@ mod tests {
         let fetched = repo.get_exercise_by_id(1).await.expect("should exist");
         assert_eq!(fetched.name, "front squat");
         match &fetched.exercise_type {
-            ExerciseType::Weighted { goal_weight } => assert_eq!(*goal_weight, 70.0),
+            ExerciseType::Weighted { goal_weight, .. } => assert_eq!(*goal_weight, 70.0),
             _ => panic!("expected Weighted"),
         }
     }
@@ -628,7 +968,11 @@ mod tests {
         let exercise = Exercise {
             id: 999,
             name: "phantom".to_string(),
-            exercise_type: ExerciseType::Weighted { goal_weight: 50.0 },
+            exercise_type: ExerciseType::Weighted {
+                goal_weight: 50.0,
+                goal_reps: 1,
+                goal_sets: 1,
+            },
         };
 
         let result = repo.update_exercise(exercise).await;
@@ -778,4 +1122,70 @@ mod tests {
         let result = repo.get_exercise_progression_from_name("nonexistent").await;
         assert!(matches!(result, Err(ExerciseModelError::NotFound)));
     }
+
+    #[sqlx::test]
+    async fn update_exercise_progression_reorders_adds_and_detaches_steps(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (id, name, exercise_type, progression_name, progression_order, goal_reps) VALUES
+             (1, 'pushup incline', 'bodyweight_reps', 'pushup', 1, 10),
+             (2, 'pushup knees', 'bodyweight_reps', 'pushup', 2, 15),
+             (3, 'pushup', 'bodyweight_reps', 'pushup', 3, 20)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let mut repo = make_repo(pool);
+        let progression = ExerciseProgression {
+            name: "pushup".to_string(),
+            progression: vec![
+                Exercise {
+                    id: 2,
+                    name: "pushup knees".to_string(),
+                    exercise_type: ExerciseType::BodyweightReps {
+                        goal_reps: 15,
+                        goal_sets: 1,
+                    },
+                },
+                Exercise {
+                    id: 0,
+                    name: "diamond pushup".to_string(),
+                    exercise_type: ExerciseType::BodyweightReps {
+                        goal_reps: 12,
+                        goal_sets: 1,
+                    },
+                },
+                Exercise {
+                    id: 3,
+                    name: "pushup".to_string(),
+                    exercise_type: ExerciseType::BodyweightReps {
+                        goal_reps: 25,
+                        goal_sets: 1,
+                    },
+                },
+            ],
+        };
+
+        repo.update_exercise_progression(progression)
+            .await
+            .expect("update should succeed");
+
+        let fetched = repo
+            .get_exercise_progression_from_name("pushup")
+            .await
+            .expect("should exist");
+        let names: Vec<&str> = fetched
+            .progression
+            .iter()
+            .map(|e| e.name.as_str())
+            .collect();
+        assert_eq!(names, ["pushup knees", "diamond pushup", "pushup"]);
+
+        let detached = repo.get_exercise_by_id(1).await.expect("should be kept");
+        assert_eq!(detached.name, "pushup incline");
+        assert!(matches!(
+            repo.get_exercise_progression(1).await,
+            Err(ExerciseModelError::NotFound)
+        ));
+    }
 }
@@ -1,7 +1,12 @@
+pub mod database;
 mod exercise_repository;
+mod personal_record_repository;
+#[cfg(test)]
+mod testing;
 mod workout_repository;
 mod workout_template_repository;
 
 pub use exercise_repository::ExerciseRepository;
+pub use personal_record_repository::PersonalRecordRepository;
 pub use workout_repository::WorkoutRepository;
 pub use workout_template_repository::WorkoutTemplateRepository;
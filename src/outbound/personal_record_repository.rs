@@ -0,0 +1,231 @@
+use std::{collections::HashMap, sync::Arc};
+
+use sqlx::SqlitePool;
+
+use crate::domain::{
+    traits::{
+        exercise_model::ExerciseModel,
+        personal_record_model::{PersonalRecordModel, PersonalRecordModelError},
+    },
+    types::{
+        exercise::Exercise,
+        personal_record::{PersonalRecord, RecordKind},
+    },
+};
+
+#[derive(Clone)]
+pub struct PersonalRecordRepository {
+    db_pool: SqlitePool,
+    exercise_model: Arc<dyn ExerciseModel>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, sqlx::Type)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+enum SqliteRecordKind {
+    HeaviestWeight,
+    MostReps,
+    LongestHold,
+    BestOneRepMax,
+    SessionVolume,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqlitePersonalRecord {
+    pub id: u64,
+    pub workout_id: u64,
+    pub date: chrono::NaiveDate,
+    pub exercise_id: u64,
+    pub kind: SqliteRecordKind,
+    pub reps: Option<u16>,
+    pub weight: Option<f32>,
+    pub value: f32,
+    pub previous: f32,
+}
+
+impl SqlitePersonalRecord {
+    fn record_kind(&self) -> RecordKind {
+        match self.kind {
+            SqliteRecordKind::HeaviestWeight => RecordKind::HeaviestWeight {
+                reps: self.reps.unwrap_or(0),
+            },
+            SqliteRecordKind::MostReps => RecordKind::MostReps {
+                weight: self.weight,
+            },
+            SqliteRecordKind::LongestHold => RecordKind::LongestHold,
+            SqliteRecordKind::BestOneRepMax => RecordKind::BestOneRepMax,
+            SqliteRecordKind::SessionVolume => RecordKind::SessionVolume,
+        }
+    }
+}
+
+fn database_error(error: sqlx::Error) -> PersonalRecordModelError {
+    PersonalRecordModelError::DatabaseError(error.to_string())
+}
+
+impl PersonalRecordRepository {
+    pub fn new(db_pool: SqlitePool, exercise_model: Arc<dyn ExerciseModel>) -> Self {
+        Self {
+            db_pool,
+            exercise_model,
+        }
+    }
+
+    /// Fills in the exercise of each row, loading them all at once.
+    async fn load_records(
+        &self,
+        rows: Vec<SqlitePersonalRecord>,
+    ) -> Result<Vec<PersonalRecord>, PersonalRecordModelError> {
+        let mut exercise_ids: Vec<u64> = rows.iter().map(|row| row.exercise_id).collect();
+        exercise_ids.sort_unstable();
+        exercise_ids.dedup();
+        let exercises: HashMap<u64, Exercise> = self
+            .exercise_model
+            .get_exercises_by_ids(&exercise_ids)
+            .await
+            .map_err(|e| PersonalRecordModelError::DatabaseError(format!("{:?}", e)))?
+            .into_iter()
+            .map(|exercise| (exercise.id, exercise))
+            .collect();
+
+        rows.into_iter()
+            .map(|row| {
+                let exercise = exercises.get(&row.exercise_id).cloned().ok_or_else(|| {
+                    PersonalRecordModelError::DatabaseError(format!(
+                        "exercise {} of record {} not found",
+                        row.exercise_id, row.id
+                    ))
+                })?;
+                Ok(PersonalRecord {
+                    id: row.id,
+                    workout_id: row.workout_id,
+                    date: row.date,
+                    kind: row.record_kind(),
+                    exercise,
+                    value: row.value,
+                    previous: row.previous,
+                })
+            })
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl PersonalRecordModel for PersonalRecordRepository {
+    async fn get_latest_records(
+        &self,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<PersonalRecord>, PersonalRecordModelError> {
+        let rows: Vec<SqlitePersonalRecord> = sqlx::query_as(
+            r#"
+            SELECT personal_record.id, workout_id, workout.date, exercise_id, kind, reps, weight, value, previous
+            FROM personal_record
+            JOIN workout ON workout.id = personal_record.workout_id
+            ORDER BY workout.date DESC, workout.id DESC, personal_record.id
+            LIMIT $1 OFFSET $2
+            "#,
+        )
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(database_error)?;
+
+        self.load_records(rows).await
+    }
+
+    async fn get_workout_records(
+        &self,
+        workout_id: u64,
+    ) -> Result<Vec<PersonalRecord>, PersonalRecordModelError> {
+        let rows: Vec<SqlitePersonalRecord> = sqlx::query_as(
+            r#"
+            SELECT personal_record.id, workout_id, workout.date, exercise_id, kind, reps, weight, value, previous
+            FROM personal_record
+            JOIN workout ON workout.id = personal_record.workout_id
+            WHERE workout_id = $1
+            ORDER BY personal_record.id
+            "#,
+        )
+        .bind(workout_id as i64)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(database_error)?;
+
+        self.load_records(rows).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outbound::ExerciseRepository;
+
+    fn make_repo(pool: SqlitePool) -> PersonalRecordRepository {
+        let exercise_model = Arc::new(ExerciseRepository::from_pool(pool.clone()));
+        PersonalRecordRepository::new(pool, exercise_model)
+    }
+
+    /// Squat (1) and bench (2), workouts 1 and 2, and records set in them.
+    async fn seed(pool: &SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 100.0), ('bench', 'weighted', 80.0)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        sqlx::query("INSERT INTO workout (date) VALUES ('2026-03-01'), ('2026-03-08')")
+            .execute(pool)
+            .await
+            .unwrap();
+        sqlx::query(
+            "INSERT INTO personal_record (workout_id, exercise_id, kind, reps, weight, value, previous) VALUES
+             (1, 1, 'session_volume', NULL, NULL, 1200.0, 1000.0),
+             (1, 2, 'longest_hold', NULL, NULL, 60.0, 55.0),
+             (2, 1, 'heaviest_weight', 5, NULL, 100.0, 95.0),
+             (2, 1, 'most_reps', NULL, 80.0, 8.0, 6.0)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    #[sqlx::test]
+    async fn get_workout_records_reads_kind_columns(pool: sqlx::SqlitePool) {
+        seed(&pool).await;
+        let repo = make_repo(pool);
+
+        let first = repo.get_workout_records(1).await.expect("should succeed");
+        let second = repo.get_workout_records(2).await.expect("should succeed");
+
+        let exercises: Vec<&str> = first
+            .iter()
+            .map(|record| record.exercise.name.as_str())
+            .collect();
+        assert_eq!(exercises, ["squat", "bench"]);
+        let kinds: Vec<RecordKind> = second.iter().map(|record| record.kind).collect();
+        assert_eq!(
+            kinds,
+            [
+                RecordKind::HeaviestWeight { reps: 5 },
+                RecordKind::MostReps { weight: Some(80.0) },
+            ]
+        );
+        assert_eq!(second[0].date.to_string(), "2026-03-08");
+        assert_eq!(second[0].previous, 95.0);
+    }
+
+    #[sqlx::test]
+    async fn get_latest_records_pages_newest_workout_first(pool: sqlx::SqlitePool) {
+        seed(&pool).await;
+        let repo = make_repo(pool);
+
+        let first_page = repo.get_latest_records(3, 0).await.expect("should succeed");
+        let second_page = repo.get_latest_records(3, 3).await.expect("should succeed");
+
+        let values: Vec<f32> = first_page.iter().map(|record| record.value).collect();
+        assert_eq!(values, [100.0, 8.0, 1200.0]);
+        let values: Vec<f32> = second_page.iter().map(|record| record.value).collect();
+        assert_eq!(values, [60.0]);
+    }
+}
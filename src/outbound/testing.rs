@@ -0,0 +1,40 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use sqlx::{SqlitePool, sqlite::SqlitePoolOptions};
+
+/// Number of queries run on a pool opened by [`counting_pool`].
+pub struct QueryCounter(Arc<AtomicUsize>);
+
+impl QueryCounter {
+    pub fn reset(&self) {
+        self.0.store(0, Ordering::SeqCst);
+    }
+
+    pub fn count(&self) -> usize {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Opens a single-connection pool on the database behind `pool` that counts every query
+/// run on it. Each query acquires the connection once, so acquisitions are round trips.
+pub async fn counting_pool(pool: &SqlitePool) -> (SqlitePool, QueryCounter) {
+    let count = Arc::new(AtomicUsize::new(0));
+    let hook_count = count.clone();
+    let counting = SqlitePoolOptions::new()
+        .max_connections(1)
+        .before_acquire(move |_, _| {
+            hook_count.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async { Ok(true) })
+        })
+        .connect_with((*pool.connect_options()).clone())
+        .await
+        .unwrap();
+    // The hook only runs when an idle connection is handed out, so open it up front.
+    sqlx::query("SELECT 1").execute(&counting).await.unwrap();
+    let counter = QueryCounter(count);
+    counter.reset();
+    (counting, counter)
+}
@@ -1,16 +1,22 @@
-use std::sync::Arc;
+use std::{
+    collections::{BTreeSet, HashMap},
+    sync::Arc,
+};
 
-use sqlx::SqlitePool;
+use sqlx::{QueryBuilder, Sqlite, SqliteConnection, SqlitePool};
 
 use crate::domain::{
+    services::personal_records::find_records,
     traits::{
         exercise_model::ExerciseModel,
         workout_model::{WorkoutModel, WorkoutModelError},
     },
+    types::exercise::Exercise,
+    types::personal_record::{NewPersonalRecord, RecordKind},
     types::workout::{
-        BodyweightRepSet, BodyweightTimeSet, NewWorkout, NewWorkoutExercise, NewWorkoutSet,
-        NewBodyweightRepSet, NewBodyweightTimeSet, NewWeightedSet, WeightedSet, Workout,
-        WorkoutExercise, WorkoutSet,
+        BodyweightRepSet, BodyweightTimeSet, ExerciseHistorySet, NewBodyweightRepSet,
+        NewBodyweightTimeSet, NewWeightedSet, NewWorkout, NewWorkoutExercise, NewWorkoutSet,
+        WeightedSet, Workout, WorkoutExercise, WorkoutSet,
     },
 };
 
@@ -25,20 +31,105 @@ struct SqliteWorkout {
     pub id: u64,
     pub date: chrono::NaiveDate,
     pub mood: Option<i64>,
+    pub workout_template_id: Option<i64>,
 }
 
+/// An exercise as done at one point of a workout
 #[derive(Debug, Clone, sqlx::FromRow)]
-struct SqliteWorkoutSet {
+struct SqliteWorkoutExercise {
     pub id: u64,
     pub workout_id: u64,
     pub exercise_id: u64,
-    pub set_order: u32,
+}
+
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqliteWorkoutSet {
+    pub id: u64,
+    pub workout_exercise_id: u64,
     pub reps: Option<i64>,
     pub weight: Option<f64>,
     pub duration_seconds: Option<i64>,
     pub failure: i64,
 }
 
+/// A set joined with the date of its workout
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqliteExerciseHistorySet {
+    pub workout_id: u64,
+    pub date: chrono::NaiveDate,
+    #[sqlx(flatten)]
+    pub set: SqliteWorkoutSet,
+}
+
+/// A set joined with its exercise and the date of its workout
+#[derive(Debug, Clone, sqlx::FromRow)]
+struct SqliteExerciseSet {
+    pub exercise_id: u64,
+    #[sqlx(flatten)]
+    pub history: SqliteExerciseHistorySet,
+}
+
+impl From<SqliteExerciseHistorySet> for ExerciseHistorySet {
+    fn from(row: SqliteExerciseHistorySet) -> Self {
+        ExerciseHistorySet {
+            workout_id: row.workout_id,
+            date: row.date,
+            set: row.set.into(),
+        }
+    }
+}
+
+impl From<SqliteWorkoutSet> for WorkoutSet {
+    fn from(s: SqliteWorkoutSet) -> Self {
+        let failure = s.failure != 0;
+        if s.weight.is_some() {
+            WorkoutSet::Weighted(WeightedSet {
+                id: s.id,
+                reps: s.reps.unwrap_or(0) as u16,
+                weight: s.weight.unwrap_or(0.0) as f32,
+                failure,
+            })
+        } else if s.duration_seconds.is_some() {
+            WorkoutSet::BodyweightTime(BodyweightTimeSet {
+                id: s.id,
+                duration_seconds: s.duration_seconds.unwrap_or(0) as u16,
+                failure,
+            })
+        } else {
+            WorkoutSet::BodyweightReps(BodyweightRepSet {
+                id: s.id,
+                reps: s.reps.unwrap_or(0) as u16,
+                failure,
+            })
+        }
+    }
+}
+
+/// Appends `(id, id, ...)` to `query_builder`, binding every id.
+fn push_id_list(query_builder: &mut QueryBuilder<Sqlite>, ids: &[u64]) {
+    query_builder.push("(");
+    let mut separated = query_builder.separated(", ");
+    for id in ids {
+        separated.push_bind(*id as i64);
+    }
+    separated.push_unseparated(")");
+}
+
+/// Kind column of a personal record, with its rep count and weight columns.
+fn record_kind_columns(kind: RecordKind) -> (&'static str, Option<u16>, Option<f32>) {
+    match kind {
+        RecordKind::HeaviestWeight { reps } => ("heaviest_weight", Some(reps), None),
+        RecordKind::MostReps { weight } => ("most_reps", None, weight),
+        RecordKind::LongestHold => ("longest_hold", None, None),
+        RecordKind::BestOneRepMax => ("best_one_rep_max", None, None),
+        RecordKind::SessionVolume => ("session_volume", None, None),
+    }
+}
+
+fn transaction_error(error: sqlx::Error) -> WorkoutModelError {
+    WorkoutModelError::DatabaseError(error.to_string())
+}
+
 impl WorkoutRepository {
     pub fn new(db_pool: SqlitePool, exercise_model: Arc<dyn ExerciseModel>) -> Self {
         Self {
@@ -47,26 +138,41 @@ impl WorkoutRepository {
         }
     }
 
-    async fn insert_workout(&self, date: chrono::NaiveDate, mood: Option<u8>) -> Result<u64, WorkoutModelError> {
+    async fn insert_workout(
+        conn: &mut SqliteConnection,
+        workout: &NewWorkout,
+    ) -> Result<u64, WorkoutModelError> {
         let result = sqlx::query(
-            "INSERT INTO workout (date, mood) VALUES ($1, $2)",
+            "INSERT INTO workout (date, mood, workout_template_id) VALUES ($1, $2, $3)",
         )
-        .bind(date)
-        .bind(mood.map(|m| m as i64))
-        .execute(&self.db_pool)
+        .bind(workout.date)
+        .bind(workout.mood.map(|m| m as i64))
+        .bind(workout.template_id.map(|id| id as i64))
+        .execute(&mut *conn)
         .await
         .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
         Ok(result.last_insert_rowid() as u64)
     }
 
     async fn insert_workout_sets(
-        &self,
+        conn: &mut SqliteConnection,
         workout_id: u64,
         exercises: &[NewWorkoutExercise],
     ) -> Result<(), WorkoutModelError> {
-        for (exercise_idx, exercise) in exercises.iter().enumerate() {
+        for (position, exercise) in exercises.iter().enumerate() {
+            let workout_exercise_id = sqlx::query(
+                "INSERT INTO workout_exercise (workout_id, exercise_id, position) VALUES ($1, $2, $3)",
+            )
+            .bind(workout_id as i64)
+            .bind(exercise.exercise_id as i64)
+            .bind(position as i64)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?
+            .last_insert_rowid();
+
             for (set_idx, set) in exercise.sets.iter().enumerate() {
-                let set_order = (exercise_idx * 1000 + set_idx + 1) as i64;
+                let set_order = (set_idx + 1) as i64;
                 let (reps, weight, duration_seconds, failure) = match set {
                     NewWorkoutSet::Weighted(s) => (
                         Some(s.reps as i64),
@@ -74,12 +180,9 @@ impl WorkoutRepository {
                         None,
                         s.failure as i64,
                     ),
-                    NewWorkoutSet::BodyweightReps(s) => (
-                        Some(s.reps as i64),
-                        None,
-                        None,
-                        s.failure as i64,
-                    ),
+                    NewWorkoutSet::BodyweightReps(s) => {
+                        (Some(s.reps as i64), None, None, s.failure as i64)
+                    }
                     NewWorkoutSet::BodyweightTime(s) => (
                         None,
                         None,
@@ -89,145 +192,390 @@ impl WorkoutRepository {
                 };
                 sqlx::query(
                     r#"
-                    INSERT INTO workout_set (workout_id, exercise_id, set_order, reps, weight, duration_seconds, failure)
-                    VALUES ($1, $2, $3, $4, $5, $6, $7)
+                    INSERT INTO workout_set (workout_exercise_id, set_order, reps, weight, duration_seconds, failure)
+                    VALUES ($1, $2, $3, $4, $5, $6)
                     "#,
                 )
-                .bind(workout_id as i64)
-                .bind(exercise.exercise_id as i64)
+                .bind(workout_exercise_id)
                 .bind(set_order)
                 .bind(reps)
                 .bind(weight)
                 .bind(duration_seconds)
                 .bind(failure)
-                .execute(&self.db_pool)
+                .execute(&mut *conn)
                 .await
                 .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
             }
         }
         Ok(())
     }
+
+    /// Exercises done in the workout, to refresh their records once it changed.
+    async fn workout_exercise_ids(
+        conn: &mut SqliteConnection,
+        workout_id: u64,
+    ) -> Result<Vec<u64>, WorkoutModelError> {
+        sqlx::query_scalar("SELECT exercise_id FROM workout_exercise WHERE workout_id = $1")
+            .bind(workout_id as i64)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))
+    }
+
+    /// Recomputes the personal records of the exercises from their sets, loaded with a single
+    /// query. Runs on the connection of the write that changed the sets, so the records are
+    /// kept or rolled back together with it. Reads every set of the exercises, since a record
+    /// depends on the best values of all earlier workouts.
+    async fn refresh_records(
+        conn: &mut SqliteConnection,
+        exercise_ids: BTreeSet<u64>,
+    ) -> Result<(), WorkoutModelError> {
+        if exercise_ids.is_empty() {
+            return Ok(());
+        }
+        let exercise_ids: Vec<u64> = exercise_ids.into_iter().collect();
+        let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+            r#"
+            SELECT workout_exercise.exercise_id, workout.id AS workout_id, workout.date,
+                   workout_set.id, workout_exercise_id, reps, weight, duration_seconds, failure
+            FROM workout_set
+            JOIN workout_exercise ON workout_exercise.id = workout_set.workout_exercise_id
+            JOIN workout ON workout.id = workout_exercise.workout_id
+            WHERE workout_exercise.exercise_id IN "#,
+        );
+        push_id_list(&mut query_builder, &exercise_ids);
+        query_builder
+            .push(" ORDER BY workout.date, workout.id, workout_exercise.position, set_order");
+        let rows: Vec<SqliteExerciseSet> = query_builder
+            .build_query_as()
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
+
+        let mut history_by_exercise: HashMap<u64, Vec<ExerciseHistorySet>> = HashMap::new();
+        for row in rows {
+            history_by_exercise
+                .entry(row.exercise_id)
+                .or_default()
+                .push(row.history.into());
+        }
+        for exercise_id in exercise_ids {
+            let history = history_by_exercise.remove(&exercise_id).unwrap_or_default();
+            Self::replace_records(conn, exercise_id, find_records(exercise_id, &history)).await?;
+        }
+        Ok(())
+    }
+
+    async fn replace_records(
+        conn: &mut SqliteConnection,
+        exercise_id: u64,
+        records: Vec<NewPersonalRecord>,
+    ) -> Result<(), WorkoutModelError> {
+        sqlx::query("DELETE FROM personal_record WHERE exercise_id = $1")
+            .bind(exercise_id as i64)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
+        if records.is_empty() {
+            return Ok(());
+        }
+
+        let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+            "INSERT INTO personal_record (workout_id, exercise_id, kind, reps, weight, value, previous) ",
+        );
+        query_builder.push_values(records, |mut b, record| {
+            let (kind, reps, weight) = record_kind_columns(record.kind);
+            b.push_bind(record.workout_id as i64)
+                .push_bind(record.exercise_id as i64)
+                .push_bind(kind)
+                .push_bind(reps)
+                .push_bind(weight)
+                .push_bind(record.value)
+                .push_bind(record.previous);
+        });
+        query_builder
+            .build()
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
+        Ok(())
+    }
+
+    /// Fills in the exercises and sets of `rows` with a fixed number of queries, however many
+    /// workouts there are.
+    async fn load_workouts(
+        &self,
+        rows: Vec<SqliteWorkout>,
+    ) -> Result<Vec<Workout>, WorkoutModelError> {
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+        let workout_ids: Vec<u64> = rows.iter().map(|row| row.id).collect();
+
+        let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+            "SELECT id, workout_id, exercise_id FROM workout_exercise WHERE workout_id IN ",
+        );
+        push_id_list(&mut query_builder, &workout_ids);
+        query_builder.push(" ORDER BY workout_id, position");
+        let entries: Vec<SqliteWorkoutExercise> = query_builder
+            .build_query_as()
+            .fetch_all(&self.db_pool)
+            .await
+            .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
+
+        let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+            r#"
+            SELECT workout_set.id, workout_exercise_id, reps, weight, duration_seconds, failure
+            FROM workout_set
+            JOIN workout_exercise ON workout_exercise.id = workout_set.workout_exercise_id
+            WHERE workout_exercise.workout_id IN "#,
+        );
+        push_id_list(&mut query_builder, &workout_ids);
+        query_builder.push(" ORDER BY set_order");
+        let sets: Vec<SqliteWorkoutSet> = query_builder
+            .build_query_as()
+            .fetch_all(&self.db_pool)
+            .await
+            .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
+
+        let mut exercise_ids: Vec<u64> = entries.iter().map(|entry| entry.exercise_id).collect();
+        exercise_ids.sort_unstable();
+        exercise_ids.dedup();
+        let exercises: HashMap<u64, Exercise> = self
+            .exercise_model
+            .get_exercises_by_ids(&exercise_ids)
+            .await
+            .map_err(|e| WorkoutModelError::DatabaseError(format!("{:?}", e)))?
+            .into_iter()
+            .map(|exercise| (exercise.id, exercise))
+            .collect();
+
+        let mut sets_by_entry: HashMap<u64, Vec<WorkoutSet>> = HashMap::new();
+        for set in sets {
+            sets_by_entry
+                .entry(set.workout_exercise_id)
+                .or_default()
+                .push(set.into());
+        }
+
+        let mut entries_by_workout: HashMap<u64, Vec<WorkoutExercise>> = HashMap::new();
+        for entry in entries {
+            let exercise = exercises.get(&entry.exercise_id).cloned().ok_or_else(|| {
+                WorkoutModelError::DatabaseError(format!(
+                    "exercise {} of workout {} not found",
+                    entry.exercise_id, entry.workout_id
+                ))
+            })?;
+            entries_by_workout
+                .entry(entry.workout_id)
+                .or_default()
+                .push(WorkoutExercise {
+                    exercise,
+                    sets: sets_by_entry.remove(&entry.id).unwrap_or_default(),
+                });
+        }
+
+        Ok(rows
+            .into_iter()
+            .map(|row| Workout {
+                id: row.id,
+                date: row.date,
+                mood: row.mood.map(|m| m as u8),
+                template_id: row.workout_template_id.map(|id| id as u64),
+                exercises: entries_by_workout.remove(&row.id).unwrap_or_default(),
+            })
+            .collect())
+    }
 }
 
 #[async_trait::async_trait]
 impl WorkoutModel for WorkoutRepository {
     async fn create_workout(&mut self, workout: NewWorkout) -> Result<u64, WorkoutModelError> {
-        let workout_id = self.insert_workout(workout.date, workout.mood).await?;
-        self.insert_workout_sets(workout_id, &workout.exercises)
-            .await?;
+        let mut tx = self.db_pool.begin().await.map_err(transaction_error)?;
+        let workout_id = Self::insert_workout(&mut tx, &workout).await?;
+        Self::insert_workout_sets(&mut tx, workout_id, &workout.exercises).await?;
+        let exercise_ids = workout
+            .exercises
+            .iter()
+            .map(|done| done.exercise_id)
+            .collect();
+        Self::refresh_records(&mut tx, exercise_ids).await?;
+        tx.commit().await.map_err(transaction_error)?;
         Ok(workout_id)
     }
 
     async fn get_workout(&self, workout_id: u64) -> Result<Workout, WorkoutModelError> {
-        let workout_row: SqliteWorkout = sqlx::query_as(
-            "SELECT id, date, mood FROM workout WHERE id = $1",
-        )
-        .bind(workout_id as i64)
-        .fetch_one(&self.db_pool)
-        .await
-        .map_err(|e| match e {
-            sqlx::Error::RowNotFound => WorkoutModelError::NotFound,
-            other => WorkoutModelError::DatabaseError(other.to_string()),
-        })?;
+        let workout_row: SqliteWorkout =
+            sqlx::query_as("SELECT id, date, mood, workout_template_id FROM workout WHERE id = $1")
+                .bind(workout_id as i64)
+                .fetch_one(&self.db_pool)
+                .await
+                .map_err(|e| match e {
+                    sqlx::Error::RowNotFound => WorkoutModelError::NotFound,
+                    other => WorkoutModelError::DatabaseError(other.to_string()),
+                })?;
 
-        let sets: Vec<SqliteWorkoutSet> = sqlx::query_as(
+        self.load_workouts(vec![workout_row])
+            .await?
+            .pop()
+            .ok_or(WorkoutModelError::NotFound)
+    }
+
+    async fn get_all_workouts(&self) -> Result<Vec<Workout>, WorkoutModelError> {
+        let rows: Vec<SqliteWorkout> =
+            sqlx::query_as("SELECT id, date, mood, workout_template_id FROM workout")
+                .fetch_all(&self.db_pool)
+                .await
+                .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
+
+        self.load_workouts(rows).await
+    }
+
+    async fn get_latest_workouts(
+        &self,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<Workout>, WorkoutModelError> {
+        let rows: Vec<SqliteWorkout> = sqlx::query_as(
             r#"
-            SELECT id, workout_id, exercise_id, set_order, reps, weight, duration_seconds, failure
-            FROM workout_set
-            WHERE workout_id = $1
-            ORDER BY set_order
+            SELECT id, date, mood, workout_template_id
+            FROM workout
+            ORDER BY date DESC, id DESC
+            LIMIT $1 OFFSET $2
             "#,
         )
-        .bind(workout_id as i64)
+        .bind(limit)
+        .bind(offset)
         .fetch_all(&self.db_pool)
         .await
         .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
 
-        let mut exercises_by_id: std::collections::HashMap<u64, Vec<SqliteWorkoutSet>> =
-            std::collections::HashMap::new();
-        for set in sets {
-            exercises_by_id
-                .entry(set.exercise_id)
-                .or_default()
-                .push(set);
-        }
+        self.load_workouts(rows).await
+    }
 
-        let mut workout_exercises = Vec::new();
-        for (exercise_id, sets) in exercises_by_id {
-            let exercise = self
-                .exercise_model
-                .get_exercise_by_id(exercise_id)
-                .await
-                .map_err(|e| WorkoutModelError::DatabaseError(format!("{:?}", e)))?;
-            let workout_sets: Vec<WorkoutSet> = sets
-                .into_iter()
-                .map(|s| {
-                    let failure = s.failure != 0;
-                    if s.weight.is_some() {
-                        WorkoutSet::Weighted(WeightedSet {
-                            id: s.id,
-                            reps: s.reps.unwrap_or(0) as u16,
-                            weight: s.weight.unwrap_or(0.0) as f32,
-                            failure,
-                        })
-                    } else if s.duration_seconds.is_some() {
-                        WorkoutSet::BodyweightTime(BodyweightTimeSet {
-                            id: s.id,
-                            duration_seconds: s.duration_seconds.unwrap_or(0) as u16,
-                            failure,
-                        })
-                    } else {
-                        WorkoutSet::BodyweightReps(BodyweightRepSet {
-                            id: s.id,
-                            reps: s.reps.unwrap_or(0) as u16,
-                            failure,
-                        })
-                    }
-                })
-                .collect();
-            workout_exercises.push(WorkoutExercise {
-                exercise,
-                sets: workout_sets,
-            });
-        }
+    async fn get_workouts_between(
+        &self,
+        from: chrono::NaiveDate,
+        to: chrono::NaiveDate,
+    ) -> Result<Vec<Workout>, WorkoutModelError> {
+        let rows: Vec<SqliteWorkout> = sqlx::query_as(
+            "SELECT id, date, mood, workout_template_id FROM workout WHERE date BETWEEN $1 AND $2 ORDER BY date, id",
+        )
+        .bind(from)
+        .bind(to)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
 
-        Ok(Workout {
-            id: workout_row.id,
-            date: workout_row.date,
-            mood: workout_row.mood.map(|m| m as u8),
-            exercises: workout_exercises,
-        })
+        self.load_workouts(rows).await
     }
 
-    async fn get_all_workouts(&self) -> Result<Vec<Workout>, WorkoutModelError> {
-        let rows: Vec<SqliteWorkout> = sqlx::query_as("SELECT id, date, mood FROM workout")
+    async fn get_workouts_with_exercises(
+        &self,
+        exercise_ids: &[u64],
+    ) -> Result<Vec<Workout>, WorkoutModelError> {
+        if exercise_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+            r#"
+            SELECT id, date, mood, workout_template_id
+            FROM workout
+            WHERE id IN (SELECT workout_id FROM workout_exercise WHERE exercise_id IN "#,
+        );
+        push_id_list(&mut query_builder, exercise_ids);
+        query_builder.push(") ORDER BY date, id");
+        let rows: Vec<SqliteWorkout> = query_builder
+            .build_query_as()
             .fetch_all(&self.db_pool)
             .await
             .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
 
-        let mut workouts = Vec::new();
-        for row in rows {
-            workouts.push(self.get_workout(row.id).await?);
-        }
-        Ok(workouts)
+        self.load_workouts(rows).await
+    }
+
+    async fn get_exercise_history(
+        &self,
+        exercise_id: u64,
+        limit: u32,
+        offset: u32,
+    ) -> Result<Vec<ExerciseHistorySet>, WorkoutModelError> {
+        let rows: Vec<SqliteExerciseHistorySet> = sqlx::query_as(
+            r#"
+            SELECT workout.id AS workout_id, workout.date,
+                   workout_set.id, workout_exercise_id, reps, weight, duration_seconds, failure
+            FROM workout_set
+            JOIN workout_exercise ON workout_exercise.id = workout_set.workout_exercise_id
+            JOIN workout ON workout.id = workout_exercise.workout_id
+            WHERE workout_exercise.exercise_id = $1
+            ORDER BY workout.date DESC, workout.id DESC, workout_exercise.position, set_order
+            LIMIT $2 OFFSET $3
+            "#,
+        )
+        .bind(exercise_id as i64)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Into::into).collect())
+    }
+
+    async fn get_last_sets_for_exercise(
+        &self,
+        exercise_id: u64,
+        before: chrono::NaiveDate,
+    ) -> Result<Vec<WorkoutSet>, WorkoutModelError> {
+        let sets: Vec<SqliteWorkoutSet> = sqlx::query_as(
+            r#"
+            SELECT workout_set.id, workout_exercise_id, reps, weight, duration_seconds, failure
+            FROM workout_set
+            JOIN workout_exercise ON workout_exercise.id = workout_set.workout_exercise_id
+            WHERE workout_exercise.exercise_id = $1
+              AND workout_exercise.workout_id = (
+                SELECT workout.id
+                FROM workout
+                JOIN workout_exercise ON workout_exercise.workout_id = workout.id
+                WHERE workout_exercise.exercise_id = $1 AND workout.date < $2
+                ORDER BY workout.date DESC, workout.id DESC
+                LIMIT 1
+              )
+            ORDER BY workout_exercise.position, set_order
+            "#,
+        )
+        .bind(exercise_id as i64)
+        .bind(before)
+        .fetch_all(&self.db_pool)
+        .await
+        .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
+        Ok(sets.into_iter().map(Into::into).collect())
     }
 
     async fn update_workout(&mut self, workout: Workout) -> Result<(), WorkoutModelError> {
-        let result = sqlx::query("UPDATE workout SET date = $1, mood = $2 WHERE id = $3")
-            .bind(workout.date)
-            .bind(workout.mood.map(|m| m as i64))
-            .bind(workout.id as i64)
-            .execute(&self.db_pool)
-            .await
-            .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
+        let mut tx = self.db_pool.begin().await.map_err(transaction_error)?;
+        let result = sqlx::query(
+            "UPDATE workout SET date = $1, mood = $2, workout_template_id = $3 WHERE id = $4",
+        )
+        .bind(workout.date)
+        .bind(workout.mood.map(|m| m as i64))
+        .bind(workout.template_id.map(|id| id as i64))
+        .bind(workout.id as i64)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
 
         if result.rows_affected() == 0 {
             return Err(WorkoutModelError::NotFound);
         }
+        let mut exercise_ids: BTreeSet<u64> = Self::workout_exercise_ids(&mut tx, workout.id)
+            .await?
+            .into_iter()
+            .collect();
 
-        sqlx::query("DELETE FROM workout_set WHERE workout_id = $1")
+        sqlx::query("DELETE FROM workout_exercise WHERE workout_id = $1")
             .bind(workout.id as i64)
-            .execute(&self.db_pool)
+            .execute(&mut *tx)
             .await
             .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
 
@@ -239,13 +587,11 @@ impl WorkoutModel for WorkoutRepository {
                     .sets
                     .into_iter()
                     .map(|s| match s {
-                        WorkoutSet::Weighted(ws) => {
-                            NewWorkoutSet::Weighted(NewWeightedSet {
-                                reps: ws.reps,
-                                weight: ws.weight,
-                                failure: ws.failure,
-                            })
-                        }
+                        WorkoutSet::Weighted(ws) => NewWorkoutSet::Weighted(NewWeightedSet {
+                            reps: ws.reps,
+                            weight: ws.weight,
+                            failure: ws.failure,
+                        }),
                         WorkoutSet::BodyweightReps(ws) => {
                             NewWorkoutSet::BodyweightReps(NewBodyweightRepSet {
                                 reps: ws.reps,
@@ -266,21 +612,568 @@ impl WorkoutModel for WorkoutRepository {
                 }
             })
             .collect();
-        self.insert_workout_sets(workout.id, &new_exercises)
-            .await?;
+        Self::insert_workout_sets(&mut tx, workout.id, &new_exercises).await?;
+        exercise_ids.extend(new_exercises.iter().map(|done| done.exercise_id));
+        Self::refresh_records(&mut tx, exercise_ids).await?;
+        tx.commit().await.map_err(transaction_error)?;
         Ok(())
     }
 
     async fn delete_workout(&mut self, workout_id: u64) -> Result<(), WorkoutModelError> {
+        let mut tx = self.db_pool.begin().await.map_err(transaction_error)?;
+        let exercise_ids = Self::workout_exercise_ids(&mut tx, workout_id).await?;
         let result = sqlx::query("DELETE FROM workout WHERE id = $1")
             .bind(workout_id as i64)
-            .execute(&self.db_pool)
+            .execute(&mut *tx)
             .await
             .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
 
         if result.rows_affected() == 0 {
             return Err(WorkoutModelError::NotFound);
         }
-        Ok(())
+        Self::refresh_records(&mut tx, exercise_ids.into_iter().collect()).await?;
+        tx.commit().await.map_err(transaction_error)
+    }
+
+    async fn refresh_all_records(&mut self) -> Result<(), WorkoutModelError> {
+        let mut tx = self.db_pool.begin().await.map_err(transaction_error)?;
+        sqlx::query("DELETE FROM personal_record")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
+        let exercise_ids: Vec<u64> =
+            sqlx::query_scalar("SELECT DISTINCT exercise_id FROM workout_exercise")
+                .fetch_all(&mut *tx)
+                .await
+                .map_err(|e| WorkoutModelError::DatabaseError(e.to_string()))?;
+        Self::refresh_records(&mut tx, exercise_ids.into_iter().collect()).await?;
+        tx.commit().await.map_err(transaction_error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outbound::{ExerciseRepository, testing::counting_pool};
+
+    fn make_repo(pool: SqlitePool) -> WorkoutRepository {
+        let exercise_model = Arc::new(ExerciseRepository::from_pool(pool.clone()));
+        WorkoutRepository::new(pool, exercise_model)
+    }
+
+    #[sqlx::test]
+    async fn get_workouts_between_includes_both_bounds_in_date_order(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO workout (date, mood) VALUES
+             ('2026-03-31', NULL), ('2026-03-01', NULL), ('2026-02-28', NULL), ('2026-04-01', 5)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let repo = make_repo(pool);
+
+        let workouts = repo
+            .get_workouts_between("2026-03-01".parse().unwrap(), "2026-03-31".parse().unwrap())
+            .await
+            .expect("should succeed");
+
+        let dates: Vec<String> = workouts.iter().map(|w| w.date.to_string()).collect();
+        assert_eq!(dates, ["2026-03-01", "2026-03-31"]);
+    }
+
+    #[sqlx::test]
+    async fn get_latest_workouts_pages_newest_first(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO workout (date) VALUES ('2026-03-01'), ('2026-03-09'), ('2026-03-05'), ('2026-03-09')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let repo = make_repo(pool);
+
+        let first_page = repo
+            .get_latest_workouts(3, 0)
+            .await
+            .expect("should succeed");
+        let second_page = repo
+            .get_latest_workouts(3, 3)
+            .await
+            .expect("should succeed");
+
+        let ids: Vec<u64> = first_page.iter().map(|w| w.id).collect();
+        assert_eq!(ids, [4, 2, 3]);
+        let ids: Vec<u64> = second_page.iter().map(|w| w.id).collect();
+        assert_eq!(ids, [1]);
+    }
+
+    #[sqlx::test]
+    async fn get_last_sets_for_exercise_reads_latest_workout_before_date(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 100.0), ('bench', 'weighted', 80.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO workout (date) VALUES ('2026-03-01'), ('2026-03-05'), ('2026-03-08'), ('2026-03-10')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO workout_exercise (workout_id, exercise_id, position) VALUES
+             (1, 1, 0), (2, 1, 0), (3, 2, 0), (4, 1, 0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        sqlx::query(
+            "INSERT INTO workout_set (workout_exercise_id, set_order, reps, weight) VALUES
+             (1, 1, 5, 70.0),
+             (2, 2, 3, 80.0), (2, 1, 5, 75.0),
+             (3, 1, 5, 60.0),
+             (4, 1, 5, 90.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let repo = make_repo(pool);
+
+        let sets = repo
+            .get_last_sets_for_exercise(1, "2026-03-10".parse().unwrap())
+            .await
+            .expect("should succeed");
+
+        let weights: Vec<f32> = sets
+            .iter()
+            .map(|set| match set {
+                WorkoutSet::Weighted(set) => set.weight,
+                _ => panic!("expected weighted sets"),
+            })
+            .collect();
+        assert_eq!(weights, [75.0, 80.0]);
+    }
+
+    #[sqlx::test]
+    async fn get_last_sets_for_exercise_is_empty_when_never_done(pool: sqlx::SqlitePool) {
+        let repo = make_repo(pool);
+
+        let sets = repo
+            .get_last_sets_for_exercise(1, "2026-03-10".parse().unwrap())
+            .await
+            .expect("should succeed");
+
+        assert!(sets.is_empty());
+    }
+
+    fn weighted(reps: u16, weight: f32) -> NewWorkoutSet {
+        NewWorkoutSet::Weighted(NewWeightedSet {
+            reps,
+            weight,
+            failure: false,
+        })
+    }
+
+    #[sqlx::test]
+    async fn get_workout_returns_exercises_in_the_order_performed(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES
+             ('squat', 'weighted', 100.0), ('bench', 'weighted', 80.0), ('deadlift', 'weighted', 140.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let mut repo = make_repo(pool);
+        let exercise = |exercise_id, sets| NewWorkoutExercise { exercise_id, sets };
+
+        let workout_id = repo
+            .create_workout(NewWorkout {
+                date: "2026-03-03".parse().unwrap(),
+                mood: None,
+                template_id: None,
+                exercises: vec![
+                    exercise(3, vec![weighted(5, 120.0)]),
+                    exercise(1, vec![weighted(5, 90.0), weighted(5, 95.0)]),
+                    exercise(2, vec![weighted(8, 60.0)]),
+                    exercise(1, vec![weighted(10, 60.0)]),
+                ],
+            })
+            .await
+            .expect("create should succeed");
+        let workout = repo.get_workout(workout_id).await.expect("should exist");
+
+        let order: Vec<(&str, usize)> = workout
+            .exercises
+            .iter()
+            .map(|done| (done.exercise.name.as_str(), done.sets.len()))
+            .collect();
+        assert_eq!(
+            order,
+            [("deadlift", 1), ("squat", 2), ("bench", 1), ("squat", 1)]
+        );
+    }
+
+    #[sqlx::test]
+    async fn delete_workout_removes_its_exercise_entries_and_sets(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 100.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let mut repo = make_repo(pool.clone());
+        let workout_id = repo
+            .create_workout(NewWorkout {
+                date: "2026-03-03".parse().unwrap(),
+                mood: None,
+                template_id: None,
+                exercises: vec![NewWorkoutExercise {
+                    exercise_id: 1,
+                    sets: vec![weighted(5, 90.0), weighted(5, 95.0)],
+                }],
+            })
+            .await
+            .expect("create should succeed");
+
+        repo.delete_workout(workout_id)
+            .await
+            .expect("delete should succeed");
+
+        let (entries, sets): (i64, i64) = sqlx::query_as(
+            "SELECT (SELECT COUNT(*) FROM workout_exercise), (SELECT COUNT(*) FROM workout_set)",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!((entries, sets), (0, 0));
+    }
+
+    async fn persisted_rows(pool: &SqlitePool) -> (i64, i64, i64) {
+        sqlx::query_as(
+            "SELECT (SELECT COUNT(*) FROM workout), (SELECT COUNT(*) FROM workout_exercise), (SELECT COUNT(*) FROM workout_set)",
+        )
+        .fetch_one(pool)
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test]
+    async fn create_workout_persists_nothing_when_an_insert_fails(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 100.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let mut repo = make_repo(pool.clone());
+
+        // The second entry points at a missing exercise, so its insert fails.
+        let result = repo
+            .create_workout(NewWorkout {
+                date: "2026-03-03".parse().unwrap(),
+                mood: None,
+                template_id: None,
+                exercises: vec![
+                    NewWorkoutExercise {
+                        exercise_id: 1,
+                        sets: vec![weighted(5, 90.0)],
+                    },
+                    NewWorkoutExercise {
+                        exercise_id: 999,
+                        sets: vec![weighted(5, 90.0)],
+                    },
+                ],
+            })
+            .await;
+
+        assert!(matches!(result, Err(WorkoutModelError::DatabaseError(_))));
+        assert_eq!(persisted_rows(&pool).await, (0, 0, 0));
+    }
+
+    #[sqlx::test]
+    async fn update_workout_keeps_previous_version_when_an_insert_fails(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 100.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let mut repo = make_repo(pool.clone());
+        let workout_id = repo
+            .create_workout(NewWorkout {
+                date: "2026-03-03".parse().unwrap(),
+                mood: Some(6),
+                template_id: None,
+                exercises: vec![NewWorkoutExercise {
+                    exercise_id: 1,
+                    sets: vec![weighted(5, 90.0), weighted(5, 95.0)],
+                }],
+            })
+            .await
+            .expect("create should succeed");
+        let mut workout = repo.get_workout(workout_id).await.expect("should exist");
+        workout.date = "2026-03-04".parse().unwrap();
+        workout.exercises[0].exercise.id = 999;
+
+        let result = repo.update_workout(workout).await;
+
+        assert!(matches!(result, Err(WorkoutModelError::DatabaseError(_))));
+        let stored = repo.get_workout(workout_id).await.expect("should exist");
+        assert_eq!(stored.date.to_string(), "2026-03-03");
+        assert_eq!(stored.exercises.len(), 1);
+        assert_eq!(stored.exercises[0].sets.len(), 2);
+    }
+
+    /// Creates a workout on 2026-03-01 and a heavier one for squat only on 2026-03-08.
+    async fn seed_squat_progress(pool: &SqlitePool, repo: &mut WorkoutRepository) -> (u64, u64) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES
+             ('squat', 'weighted', 100.0), ('bench', 'weighted', 80.0)",
+        )
+        .execute(pool)
+        .await
+        .unwrap();
+        let mut workout_ids = Vec::new();
+        for (date, squat, bench) in [("2026-03-01", 80.0, 60.0), ("2026-03-08", 85.0, 50.0)] {
+            let workout_id = repo
+                .create_workout(NewWorkout {
+                    date: date.parse().unwrap(),
+                    mood: None,
+                    template_id: None,
+                    exercises: vec![
+                        NewWorkoutExercise {
+                            exercise_id: 1,
+                            sets: vec![weighted(5, squat)],
+                        },
+                        NewWorkoutExercise {
+                            exercise_id: 2,
+                            sets: vec![weighted(5, bench)],
+                        },
+                    ],
+                })
+                .await
+                .expect("create should succeed");
+            workout_ids.push(workout_id);
+        }
+        (workout_ids[0], workout_ids[1])
+    }
+
+    async fn record_rows(pool: &SqlitePool) -> Vec<(i64, i64, String)> {
+        sqlx::query_as(
+            "SELECT workout_id, exercise_id, kind FROM personal_record ORDER BY exercise_id, kind",
+        )
+        .fetch_all(pool)
+        .await
+        .unwrap()
+    }
+
+    #[sqlx::test]
+    async fn saving_and_deleting_workouts_refreshes_records(pool: sqlx::SqlitePool) {
+        let mut repo = make_repo(pool.clone());
+        let (first, second) = seed_squat_progress(&pool, &mut repo).await;
+
+        let second = second as i64;
+        assert_eq!(
+            record_rows(&pool).await,
+            [
+                (second, 1, "best_one_rep_max".to_string()),
+                (second, 1, "heaviest_weight".to_string()),
+                (second, 1, "session_volume".to_string()),
+            ]
+        );
+
+        repo.delete_workout(first)
+            .await
+            .expect("delete should succeed");
+
+        assert!(record_rows(&pool).await.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn refresh_all_records_rebuilds_records_of_every_exercise(pool: sqlx::SqlitePool) {
+        let mut repo = make_repo(pool.clone());
+        seed_squat_progress(&pool, &mut repo).await;
+        let records = record_rows(&pool).await;
+        sqlx::query("DELETE FROM personal_record")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        repo.refresh_all_records()
+            .await
+            .expect("refresh should succeed");
+
+        assert_eq!(record_rows(&pool).await, records);
+    }
+
+    #[sqlx::test]
+    async fn update_workout_keeps_previous_records_when_an_insert_fails(pool: sqlx::SqlitePool) {
+        let mut repo = make_repo(pool.clone());
+        let (first, _) = seed_squat_progress(&pool, &mut repo).await;
+        let records = record_rows(&pool).await;
+        let mut workout = repo.get_workout(first).await.expect("should exist");
+        workout.exercises[0].sets = vec![WorkoutSet::Weighted(WeightedSet {
+            id: 0,
+            reps: 5,
+            weight: 100.0,
+            failure: false,
+        })];
+        workout.exercises[1].exercise.id = 999;
+
+        let result = repo.update_workout(workout).await;
+
+        assert!(matches!(result, Err(WorkoutModelError::DatabaseError(_))));
+        assert_eq!(record_rows(&pool).await, records);
+    }
+
+    #[sqlx::test]
+    async fn get_all_workouts_runs_the_same_queries_for_any_number_of_workouts(
+        pool: sqlx::SqlitePool,
+    ) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 100.0), ('bench', 'weighted', 80.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let mut repo = make_repo(pool.clone());
+        let exercise = |exercise_id| NewWorkoutExercise {
+            exercise_id,
+            sets: vec![weighted(5, 60.0), weighted(5, 65.0)],
+        };
+        for day in 1..=50 {
+            repo.create_workout(NewWorkout {
+                date: chrono::NaiveDate::from_ymd_opt(2026, 1, 1).unwrap() + chrono::Days::new(day),
+                mood: None,
+                template_id: None,
+                exercises: vec![exercise(1), exercise(2), exercise(1)],
+            })
+            .await
+            .expect("create should succeed");
+        }
+        let (counting, queries) = counting_pool(&pool).await;
+        let repo = make_repo(counting);
+
+        let workouts = repo.get_all_workouts().await.expect("should succeed");
+
+        // The workout list, their exercises, their sets and the exercise details.
+        assert_eq!(queries.count(), 4);
+        assert_eq!(workouts.len(), 50);
+        for workout in &workouts {
+            let names: Vec<&str> = workout
+                .exercises
+                .iter()
+                .map(|done| done.exercise.name.as_str())
+                .collect();
+            assert_eq!(names, ["squat", "bench", "squat"]);
+            assert!(workout.exercises.iter().all(|done| done.sets.len() == 2));
+        }
+
+        queries.reset();
+        let page = repo
+            .get_latest_workouts(20, 0)
+            .await
+            .expect("should succeed");
+        assert_eq!(queries.count(), 4);
+        assert_eq!(page.len(), 20);
+    }
+
+    async fn seed_squat_and_bench_workouts(repo: &mut WorkoutRepository) {
+        let exercise = |exercise_id, sets| NewWorkoutExercise { exercise_id, sets };
+        for (date, exercises) in [
+            (
+                "2026-03-05",
+                vec![
+                    exercise(1, vec![weighted(5, 80.0), weighted(5, 85.0)]),
+                    exercise(2, vec![weighted(8, 60.0)]),
+                    exercise(1, vec![weighted(10, 60.0)]),
+                ],
+            ),
+            ("2026-03-01", vec![exercise(1, vec![weighted(5, 75.0)])]),
+            ("2026-03-03", vec![exercise(2, vec![weighted(8, 55.0)])]),
+        ] {
+            repo.create_workout(NewWorkout {
+                date: date.parse().unwrap(),
+                mood: None,
+                template_id: None,
+                exercises,
+            })
+            .await
+            .expect("create should succeed");
+        }
+    }
+
+    #[sqlx::test]
+    async fn get_workouts_with_exercises_skips_workouts_without_them(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 100.0), ('bench', 'weighted', 80.0), ('deadlift', 'weighted', 140.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let mut repo = make_repo(pool);
+        seed_squat_and_bench_workouts(&mut repo).await;
+
+        let workouts = repo
+            .get_workouts_with_exercises(&[1])
+            .await
+            .expect("should succeed");
+
+        let dates: Vec<String> = workouts.iter().map(|w| w.date.to_string()).collect();
+        assert_eq!(dates, ["2026-03-01", "2026-03-05"]);
+        assert_eq!(workouts[1].exercises.len(), 3);
+
+        let workouts = repo
+            .get_workouts_with_exercises(&[1, 2])
+            .await
+            .expect("should succeed");
+        assert_eq!(workouts.len(), 3);
+
+        let workouts = repo
+            .get_workouts_with_exercises(&[3])
+            .await
+            .expect("should succeed");
+        assert!(workouts.is_empty());
+    }
+
+    #[sqlx::test]
+    async fn get_exercise_history_pages_sets_newest_workout_first(pool: sqlx::SqlitePool) {
+        sqlx::query(
+            "INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 100.0), ('bench', 'weighted', 80.0)",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        let mut repo = make_repo(pool);
+        seed_squat_and_bench_workouts(&mut repo).await;
+        let weights = |history: &[ExerciseHistorySet]| -> Vec<(String, f32)> {
+            history
+                .iter()
+                .map(|entry| match &entry.set {
+                    WorkoutSet::Weighted(set) => (entry.date.to_string(), set.weight),
+                    _ => panic!("squat sets are weighted"),
+                })
+                .collect()
+        };
+
+        let first_page = repo
+            .get_exercise_history(1, 3, 0)
+            .await
+            .expect("should succeed");
+        let second_page = repo
+            .get_exercise_history(1, 3, 3)
+            .await
+            .expect("should succeed");
+
+        assert_eq!(
+            weights(&first_page),
+            [
+                ("2026-03-05".to_string(), 80.0),
+                ("2026-03-05".to_string(), 85.0),
+                ("2026-03-05".to_string(), 60.0),
+            ]
+        );
+        assert_eq!(weights(&second_page), [("2026-03-01".to_string(), 75.0)]);
+        assert_eq!(second_page[0].workout_id, 2);
     }
 }
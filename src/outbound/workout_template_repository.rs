@@ -1,13 +1,16 @@
-use std::sync::Arc;
+use std::{collections::HashMap, sync::Arc};
 
-use sqlx::{QueryBuilder, Sqlite, SqlitePool};
+use sqlx::{QueryBuilder, Sqlite, SqliteConnection, SqlitePool};
 
 use crate::domain::{
     traits::{
         exercise_model::ExerciseModel,
         workout_template_model::{WorkoutTemplateModel, WorkoutTemplateModelError},
     },
-    types::workout_template::{NewWorkoutTemplate, WorkoutTemplate},
+    types::exercise::Exercise,
+    types::workout_template::{
+        NewTemplateExercise, NewWorkoutTemplate, SetScheme, TemplateExercise, WorkoutTemplate,
+    },
 };
 
 #[derive(Clone)]
@@ -26,13 +29,34 @@ struct SqliteWorkoutTemplate {
 struct SqliteWorkoutTemplateExercise {
     pub workout_template_id: u64,
     pub exercise_id: u64,
+    pub planned_sets: Option<u16>,
+    pub planned_target: Option<u16>,
+}
+
+impl SqliteWorkoutTemplateExercise {
+    fn set_scheme(&self) -> Option<SetScheme> {
+        self.planned_sets.map(|sets| SetScheme {
+            sets,
+            target: self.planned_target,
+        })
+    }
+}
+
+fn map_write_error(error: sqlx::Error) -> WorkoutTemplateModelError {
+    match error.as_database_error() {
+        Some(db_error) if db_error.is_unique_violation() => {
+            WorkoutTemplateModelError::AlreadyExists
+        }
+        _ => WorkoutTemplateModelError::DatabaseError(error.to_string()),
+    }
+}
+
+fn transaction_error(error: sqlx::Error) -> WorkoutTemplateModelError {
+    WorkoutTemplateModelError::DatabaseError(error.to_string())
 }
 
 impl WorkoutTemplateRepository {
-    pub fn new(
-        db_pool: SqlitePool,
-        exercise_model: Arc<dyn ExerciseModel>,
-    ) -> Self {
+    pub fn new(db_pool: SqlitePool, exercise_model: Arc<dyn ExerciseModel>) -> Self {
         Self {
             db_pool,
             exercise_model,
@@ -40,25 +64,94 @@ impl WorkoutTemplateRepository {
     }
 
     async fn insert_template_exercises(
-        &self,
+        conn: &mut SqliteConnection,
         template_id: u64,
-        exercise_ids: &[u64],
+        exercises: &[NewTemplateExercise],
     ) -> Result<(), WorkoutTemplateModelError> {
-        if exercise_ids.is_empty() {
+        if exercises.is_empty() {
             return Ok(());
         }
         let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(
-            "INSERT INTO workout_template_exercise (workout_template_id, exercise_id) ",
+            "INSERT INTO workout_template_exercise (workout_template_id, exercise_id, position, planned_sets, planned_target) ",
+        );
+        query_builder.push_values(
+            exercises.iter().enumerate(),
+            |mut b, (position, exercise)| {
+                b.push_bind(template_id as i64)
+                    .push_bind(exercise.exercise_id as i64)
+                    .push_bind(position as i64)
+                    .push_bind(exercise.set_scheme.map(|scheme| scheme.sets))
+                    .push_bind(exercise.set_scheme.and_then(|scheme| scheme.target));
+            },
         );
-        query_builder.push_values(exercise_ids.iter(), |mut b, &exercise_id| {
-            b.push_bind(template_id as i64).push_bind(exercise_id as i64);
-        });
         let query = query_builder.build();
-        query
-            .execute(&self.db_pool)
+        query.execute(&mut *conn).await.map_err(map_write_error)?;
+        Ok(())
+    }
+
+    /// Fills in the planned exercises of `rows` with a fixed number of queries, however many
+    /// templates there are.
+    async fn load_templates(
+        &self,
+        rows: Vec<SqliteWorkoutTemplate>,
+    ) -> Result<Vec<WorkoutTemplate>, WorkoutTemplateModelError> {
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut query_builder: QueryBuilder<Sqlite> = QueryBuilder::new(
+            r#"
+            SELECT workout_template_id, exercise_id, planned_sets, planned_target
+            FROM workout_template_exercise
+            WHERE workout_template_id IN ("#,
+        );
+        let mut separated = query_builder.separated(", ");
+        for row in &rows {
+            separated.push_bind(row.id as i64);
+        }
+        separated.push_unseparated(") ORDER BY workout_template_id, position");
+        let links: Vec<SqliteWorkoutTemplateExercise> = query_builder
+            .build_query_as()
+            .fetch_all(&self.db_pool)
             .await
             .map_err(|e| WorkoutTemplateModelError::DatabaseError(e.to_string()))?;
-        Ok(())
+
+        let mut exercise_ids: Vec<u64> = links.iter().map(|link| link.exercise_id).collect();
+        exercise_ids.sort_unstable();
+        exercise_ids.dedup();
+        let exercises: HashMap<u64, Exercise> = self
+            .exercise_model
+            .get_exercises_by_ids(&exercise_ids)
+            .await
+            .map_err(|e| WorkoutTemplateModelError::DatabaseError(format!("{:?}", e)))?
+            .into_iter()
+            .map(|exercise| (exercise.id, exercise))
+            .collect();
+
+        let mut exercises_by_template: HashMap<u64, Vec<TemplateExercise>> = HashMap::new();
+        for link in links {
+            let exercise = exercises.get(&link.exercise_id).cloned().ok_or_else(|| {
+                WorkoutTemplateModelError::DatabaseError(format!(
+                    "exercise {} of template {} not found",
+                    link.exercise_id, link.workout_template_id
+                ))
+            })?;
+            exercises_by_template
+                .entry(link.workout_template_id)
+                .or_default()
+                .push(TemplateExercise {
+                    exercise,
+                    set_scheme: link.set_scheme(),
+                });
+        }
+
+        Ok(rows
+            .into_iter()
+            .map(|row| WorkoutTemplate {
+                id: row.id,
+                exercises: exercises_by_template.remove(&row.id).unwrap_or_default(),
+                name: row.name,
+            })
+            .collect())
     }
 }
 
@@ -68,14 +161,15 @@ impl WorkoutTemplateModel for WorkoutTemplateRepository {
         &mut self,
         template: NewWorkoutTemplate,
     ) -> Result<u64, WorkoutTemplateModelError> {
+        let mut tx = self.db_pool.begin().await.map_err(transaction_error)?;
         let result = sqlx::query("INSERT INTO workout_template (name) VALUES ($1)")
             .bind(&template.name)
-            .execute(&self.db_pool)
+            .execute(&mut *tx)
             .await
-            .map_err(|e| WorkoutTemplateModelError::DatabaseError(e.to_string()))?;
+            .map_err(map_write_error)?;
         let template_id = result.last_insert_rowid() as u64;
-        self.insert_template_exercises(template_id, &template.exercise_ids)
-            .await?;
+        Self::insert_template_exercises(&mut tx, template_id, &template.exercises).await?;
+        tx.commit().await.map_err(transaction_error)?;
         Ok(template_id)
     }
 
@@ -83,45 +177,20 @@ impl WorkoutTemplateModel for WorkoutTemplateRepository {
         &self,
         template_id: u64,
     ) -> Result<WorkoutTemplate, WorkoutTemplateModelError> {
-        let row: SqliteWorkoutTemplate = sqlx::query_as(
-            "SELECT id, name FROM workout_template WHERE id = $1",
-        )
-        .bind(template_id as i64)
-        .fetch_one(&self.db_pool)
-        .await
-        .map_err(|e| match e {
-            sqlx::Error::RowNotFound => WorkoutTemplateModelError::NotFound,
-            other => WorkoutTemplateModelError::DatabaseError(other.to_string()),
-        })?;
-
-        let links: Vec<SqliteWorkoutTemplateExercise> = sqlx::query_as(
-            r#"
-            SELECT workout_template_id, exercise_id
-            FROM workout_template_exercise
-            WHERE workout_template_id = $1
-            ORDER BY id
-            "#,
-        )
-        .bind(template_id as i64)
-        .fetch_all(&self.db_pool)
-        .await
-        .map_err(|e| WorkoutTemplateModelError::DatabaseError(e.to_string()))?;
-
-        let mut exercises = Vec::new();
-        for link in links {
-            let exercise = self
-                .exercise_model
-                .get_exercise_by_id(link.exercise_id)
+        let row: SqliteWorkoutTemplate =
+            sqlx::query_as("SELECT id, name FROM workout_template WHERE id = $1")
+                .bind(template_id as i64)
+                .fetch_one(&self.db_pool)
                 .await
-                .map_err(|e| WorkoutTemplateModelError::DatabaseError(format!("{:?}", e)))?;
-            exercises.push(exercise);
-        }
-
-        Ok(WorkoutTemplate {
-            id: row.id,
-            name: row.name,
-            exercises,
-        })
+                .map_err(|e| match e {
+                    sqlx::Error::RowNotFound => WorkoutTemplateModelError::NotFound,
+                    other => WorkoutTemplateModelError::DatabaseError(other.to_string()),
+                })?;
+
+        self.load_templates(vec![row])
+            .await?
+            .pop()
+            .ok_or(WorkoutTemplateModelError::NotFound)
     }
 
     async fn get_all_workout_templates(
@@ -132,24 +201,20 @@ impl WorkoutTemplateModel for WorkoutTemplateRepository {
                 .fetch_all(&self.db_pool)
                 .await
                 .map_err(|e| WorkoutTemplateModelError::DatabaseError(e.to_string()))?;
-
-        let mut templates = Vec::new();
-        for row in rows {
-            templates.push(self.get_workout_template(row.id).await?);
-        }
-        Ok(templates)
+        self.load_templates(rows).await
     }
 
     async fn update_workout_template(
         &mut self,
         template: WorkoutTemplate,
     ) -> Result<(), WorkoutTemplateModelError> {
+        let mut tx = self.db_pool.begin().await.map_err(transaction_error)?;
         let result = sqlx::query("UPDATE workout_template SET name = $1 WHERE id = $2")
             .bind(&template.name)
             .bind(template.id as i64)
-            .execute(&self.db_pool)
+            .execute(&mut *tx)
             .await
-            .map_err(|e| WorkoutTemplateModelError::DatabaseError(e.to_string()))?;
+            .map_err(map_write_error)?;
 
         if result.rows_affected() == 0 {
             return Err(WorkoutTemplateModelError::NotFound);
@@ -157,13 +222,20 @@ impl WorkoutTemplateModel for WorkoutTemplateRepository {
 
         sqlx::query("DELETE FROM workout_template_exercise WHERE workout_template_id = $1")
             .bind(template.id as i64)
-            .execute(&self.db_pool)
+            .execute(&mut *tx)
             .await
             .map_err(|e| WorkoutTemplateModelError::DatabaseError(e.to_string()))?;
 
-        let exercise_ids: Vec<u64> = template.exercises.iter().map(|e| e.id).collect();
-        self.insert_template_exercises(template.id, &exercise_ids)
-            .await?;
+        let exercises: Vec<NewTemplateExercise> = template
+            .exercises
+            .iter()
+            .map(|planned| NewTemplateExercise {
+                exercise_id: planned.exercise.id,
+                set_scheme: planned.set_scheme,
+            })
+            .collect();
+        Self::insert_template_exercises(&mut tx, template.id, &exercises).await?;
+        tx.commit().await.map_err(transaction_error)?;
         Ok(())
     }
 
@@ -189,7 +261,7 @@ mod tests {
     use super::*;
     use crate::{
         domain::traits::workout_template_model::WorkoutTemplateModel,
-        outbound::exercise_repository::ExerciseRepository,
+        outbound::{exercise_repository::ExerciseRepository, testing::counting_pool},
     };
     use std::sync::Arc;
 
@@ -215,12 +287,16 @@ mod tests {
             .unwrap();
 
         let (repo, _) = make_repo(pool);
-        let template = repo.get_workout_template(1).await.expect("fetch should succeed");
+        let template = repo
+            .get_workout_template(1)
+            .await
+            .expect("fetch should succeed");
 
         assert_eq!(template.id, 1);
         assert_eq!(template.name, "Legs");
         assert_eq!(template.exercises.len(), 1);
-        assert_eq!(template.exercises[0].name, "squat");
+        assert_eq!(template.exercises[0].exercise.name, "squat");
+        assert_eq!(template.exercises[0].set_scheme, None);
     }
 
     #[sqlx::test]
@@ -233,7 +309,10 @@ mod tests {
     #[sqlx::test]
     async fn get_all_workout_templates_returns_empty_when_none(pool: sqlx::SqlitePool) {
         let (repo, _) = make_repo(pool);
-        let templates = repo.get_all_workout_templates().await.expect("should succeed");
+        let templates = repo
+            .get_all_workout_templates()
+            .await
+            .expect("should succeed");
         assert!(templates.is_empty());
     }
 
@@ -251,7 +330,19 @@ mod tests {
         let (mut repo, _) = make_repo(pool);
         let template = NewWorkoutTemplate {
             name: "Full body".to_string(),
-            exercise_ids: vec![1, 2],
+            exercises: vec![
+                NewTemplateExercise {
+                    exercise_id: 1,
+                    set_scheme: Some(SetScheme {
+                        sets: 3,
+                        target: None,
+                    }),
+                },
+                NewTemplateExercise {
+                    exercise_id: 2,
+                    set_scheme: None,
+                },
+            ],
         };
 
         let id = WorkoutTemplateModel::create_workout_template(&mut repo, template)
@@ -262,6 +353,36 @@ mod tests {
         let fetched = repo.get_workout_template(1).await.expect("should exist");
         assert_eq!(fetched.name, "Full body");
         assert_eq!(fetched.exercises.len(), 2);
+        assert_eq!(
+            fetched.exercises[0].set_scheme,
+            Some(SetScheme {
+                sets: 3,
+                target: None
+            })
+        );
+        assert_eq!(fetched.exercises[1].set_scheme, None);
+    }
+
+    #[sqlx::test]
+    async fn create_workout_template_returns_already_exists_on_duplicate_name(
+        pool: sqlx::SqlitePool,
+    ) {
+        sqlx::query("INSERT INTO workout_template (name) VALUES ('Legs')")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (mut repo, _) = make_repo(pool);
+        let template = NewWorkoutTemplate {
+            name: "Legs".to_string(),
+            exercises: vec![],
+        };
+
+        let result = WorkoutTemplateModel::create_workout_template(&mut repo, template).await;
+        assert!(matches!(
+            result,
+            Err(WorkoutTemplateModelError::AlreadyExists)
+        ));
     }
 
     #[sqlx::test]
@@ -283,12 +404,20 @@ mod tests {
         let template = WorkoutTemplate {
             id: 1,
             name: "Lower body".to_string(),
-            exercises: vec![crate::domain::types::exercise::Exercise {
-                id: 1,
-                name: "squat".to_string(),
-                exercise_type: crate::domain::types::exercise::ExerciseType::Weighted {
-                    goal_weight: 60.0,
+            exercises: vec![TemplateExercise {
+                exercise: crate::domain::types::exercise::Exercise {
+                    id: 1,
+                    name: "squat".to_string(),
+                    exercise_type: crate::domain::types::exercise::ExerciseType::Weighted {
+                        goal_weight: 60.0,
+                        goal_reps: 1,
+                        goal_sets: 1,
+                    },
                 },
+                set_scheme: Some(SetScheme {
+                    sets: 5,
+                    target: Some(5),
+                }),
             }],
         };
 
@@ -298,6 +427,39 @@ mod tests {
 
         let fetched = repo.get_workout_template(1).await.expect("should exist");
         assert_eq!(fetched.name, "Lower body");
+        assert_eq!(
+            fetched.exercises[0].set_scheme,
+            Some(SetScheme {
+                sets: 5,
+                target: Some(5)
+            })
+        );
+    }
+
+    #[sqlx::test]
+    async fn get_workout_template_returns_exercises_in_planned_order(pool: sqlx::SqlitePool) {
+        sqlx::query("INSERT INTO workout_template (name) VALUES ('Legs')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 60.0), ('deadlift', 'weighted', 80.0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO workout_template_exercise (workout_template_id, exercise_id, position) VALUES (1, 1, 1), (1, 2, 0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+
+        let (repo, _) = make_repo(pool);
+        let template = repo.get_workout_template(1).await.expect("should exist");
+
+        let names: Vec<&str> = template
+            .exercises
+            .iter()
+            .map(|planned| planned.exercise.name.as_str())
+            .collect();
+        assert_eq!(names, ["deadlift", "squat"]);
     }
 
     #[sqlx::test]
@@ -335,4 +497,130 @@ mod tests {
         let result = WorkoutTemplateModel::delete_workout_template(&mut repo, 999).await;
         assert!(matches!(result, Err(WorkoutTemplateModelError::NotFound)));
     }
+
+    #[sqlx::test]
+    async fn create_workout_template_persists_nothing_when_an_insert_fails(pool: sqlx::SqlitePool) {
+        sqlx::query("INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 60.0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let (mut repo, _) = make_repo(pool.clone());
+
+        // The second exercise does not exist, so inserting the rows fails.
+        let result = WorkoutTemplateModel::create_workout_template(
+            &mut repo,
+            NewWorkoutTemplate {
+                name: "Legs".to_string(),
+                exercises: vec![
+                    NewTemplateExercise {
+                        exercise_id: 1,
+                        set_scheme: None,
+                    },
+                    NewTemplateExercise {
+                        exercise_id: 999,
+                        set_scheme: None,
+                    },
+                ],
+            },
+        )
+        .await;
+
+        assert!(matches!(
+            result,
+            Err(WorkoutTemplateModelError::DatabaseError(_))
+        ));
+        let (templates, rows): (i64, i64) = sqlx::query_as(
+            "SELECT (SELECT COUNT(*) FROM workout_template), (SELECT COUNT(*) FROM workout_template_exercise)",
+        )
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+        assert_eq!((templates, rows), (0, 0));
+    }
+
+    #[sqlx::test]
+    async fn update_workout_template_keeps_previous_version_when_an_insert_fails(
+        pool: sqlx::SqlitePool,
+    ) {
+        sqlx::query("INSERT INTO workout_template (name) VALUES ('Legs')")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 60.0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        sqlx::query("INSERT INTO workout_template_exercise (workout_template_id, exercise_id) VALUES (1, 1)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let (mut repo, _) = make_repo(pool);
+        let mut template = repo.get_workout_template(1).await.expect("should exist");
+        template.name = "Lower body".to_string();
+        template.exercises[0].exercise.id = 999;
+
+        let result = WorkoutTemplateModel::update_workout_template(&mut repo, template).await;
+
+        assert!(matches!(
+            result,
+            Err(WorkoutTemplateModelError::DatabaseError(_))
+        ));
+        let stored = repo.get_workout_template(1).await.expect("should exist");
+        assert_eq!(stored.name, "Legs");
+        assert_eq!(stored.exercises.len(), 1);
+        assert_eq!(stored.exercises[0].exercise.name, "squat");
+    }
+
+    #[sqlx::test]
+    async fn get_all_workout_templates_runs_the_same_queries_for_any_number_of_templates(
+        pool: sqlx::SqlitePool,
+    ) {
+        sqlx::query("INSERT INTO exercise (name, exercise_type, goal_weight) VALUES ('squat', 'weighted', 60.0), ('deadlift', 'weighted', 80.0)")
+            .execute(&pool)
+            .await
+            .unwrap();
+        let (mut repo, _) = make_repo(pool.clone());
+        for index in 0..30 {
+            WorkoutTemplateModel::create_workout_template(
+                &mut repo,
+                NewWorkoutTemplate {
+                    name: format!("Legs {index}"),
+                    exercises: vec![
+                        NewTemplateExercise {
+                            exercise_id: 2,
+                            set_scheme: None,
+                        },
+                        NewTemplateExercise {
+                            exercise_id: 1,
+                            set_scheme: Some(SetScheme {
+                                sets: 5,
+                                target: Some(5),
+                            }),
+                        },
+                    ],
+                },
+            )
+            .await
+            .expect("create should succeed");
+        }
+        let (counting, queries) = counting_pool(&pool).await;
+        let (repo, _) = make_repo(counting);
+
+        let templates = repo
+            .get_all_workout_templates()
+            .await
+            .expect("should succeed");
+
+        // The template list, their planned exercises and the exercise details.
+        assert_eq!(queries.count(), 3);
+        assert_eq!(templates.len(), 30);
+        for template in &templates {
+            let names: Vec<&str> = template
+                .exercises
+                .iter()
+                .map(|planned| planned.exercise.name.as_str())
+                .collect();
+            assert_eq!(names, ["deadlift", "squat"]);
+        }
+    }
 }
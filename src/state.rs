@@ -1,10 +1,45 @@
-use crate::domain::traits::{
-    exercise_model::ExerciseModel, workout_model::WorkoutModel,
-    workout_template_model::WorkoutTemplateModel,
+use std::sync::Arc;
+
+use sqlx::SqlitePool;
+use tokio::sync::RwLock;
+
+use crate::{
+    domain::traits::{
+        exercise_model::ExerciseModel, personal_record_model::PersonalRecordModel,
+        workout_model::WorkoutModel, workout_template_model::WorkoutTemplateModel,
+    },
+    outbound::{
+        ExerciseRepository, PersonalRecordRepository, WorkoutRepository, WorkoutTemplateRepository,
+    },
 };
 
 pub struct AppState {
-    pub exercise_model: Box<dyn ExerciseModel>,
-    pub workout_model: Box<dyn WorkoutModel>,
-    pub workout_plan_model: Box<dyn WorkoutTemplateModel>,
+    pub exercise_model: RwLock<Box<dyn ExerciseModel>>,
+    pub workout_model: RwLock<Box<dyn WorkoutModel>>,
+    pub workout_plan_model: RwLock<Box<dyn WorkoutTemplateModel>>,
+    pub record_model: RwLock<Box<dyn PersonalRecordModel>>,
+}
+
+impl AppState {
+    /// Builds the SQLite-backed repositories, all sharing `db_pool`.
+    pub fn new(db_pool: SqlitePool) -> Self {
+        let exercise_repository = ExerciseRepository::from_pool(db_pool.clone());
+        let shared_exercise_model: Arc<dyn ExerciseModel> = Arc::new(exercise_repository.clone());
+
+        Self {
+            exercise_model: RwLock::new(Box::new(exercise_repository)),
+            workout_model: RwLock::new(Box::new(WorkoutRepository::new(
+                db_pool.clone(),
+                shared_exercise_model.clone(),
+            ))),
+            workout_plan_model: RwLock::new(Box::new(WorkoutTemplateRepository::new(
+                db_pool.clone(),
+                shared_exercise_model.clone(),
+            ))),
+            record_model: RwLock::new(Box::new(PersonalRecordRepository::new(
+                db_pool,
+                shared_exercise_model,
+            ))),
+        }
+    }
 }
@@ -0,0 +1,10 @@
+// Only shows the goal inputs that apply to the kind of exercise picked.
+document.addEventListener("change", (event) => {
+  if (!event.target.classList.contains("exercise-kind")) {
+    return;
+  }
+  const fields = event.target.closest(".exercise-fields");
+  fields.querySelectorAll(".goal-input").forEach((input) => {
+    input.hidden = !input.dataset.kinds.split(" ").includes(event.target.value);
+  });
+});
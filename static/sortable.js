@@ -0,0 +1,46 @@
+// Lets the items of `list` be reordered by dragging them and removed with their ".remove-item"
+// button. Inputs are named `${prefix}${position}.field` and renamed after every change, so the
+// form is submitted in the order shown. Returns the renaming function, to call after adding an
+// item.
+function sortable(list, prefix) {
+  const pattern = new RegExp(`^${prefix}\\d+\\.`);
+  const renumber = () => {
+    [...list.children].forEach((item, index) => {
+      item.querySelectorAll("input, select").forEach((input) => {
+        input.name = input.name.replace(pattern, `${prefix}${index}.`);
+        input.id = input.id.replace(pattern, `${prefix}${index}.`);
+      });
+      item.querySelectorAll("label").forEach((label) => {
+        label.htmlFor = label.htmlFor.replace(pattern, `${prefix}${index}.`);
+      });
+    });
+  };
+
+  let dragged = null;
+  list.addEventListener("dragstart", (event) => {
+    dragged = event.target.closest("li");
+    dragged.classList.add("dragging");
+  });
+  list.addEventListener("dragend", () => {
+    dragged.classList.remove("dragging");
+    dragged = null;
+    renumber();
+  });
+  list.addEventListener("dragover", (event) => {
+    const target = event.target.closest("li");
+    if (!dragged || !target || target === dragged) {
+      return;
+    }
+    event.preventDefault();
+    const { top, height } = target.getBoundingClientRect();
+    const after = event.clientY > top + height / 2;
+    list.insertBefore(dragged, after ? target.nextSibling : target);
+  });
+  list.addEventListener("click", (event) => {
+    if (event.target.classList.contains("remove-item")) {
+      event.target.closest("li").remove();
+      renumber();
+    }
+  });
+  return renumber;
+}